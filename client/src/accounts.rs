@@ -0,0 +1,47 @@
+use meep::state::MeepSettings;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_program::{borsh::try_from_slice_unchecked, pubkey::Pubkey};
+
+/// Offset of `MeepSettings::authority` in the settings account data
+const SETTINGS_AUTHORITY_OFFSET: usize = 0;
+
+/// All Meep configurations created by `authority`, one per collection id
+pub fn get_settings_by_authority(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<(Pubkey, MeepSettings)>, ClientError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(MeepSettings::LEN as u64),
+            RpcFilterType::Memcmp(Memcmp {
+                offset: SETTINGS_AUTHORITY_OFFSET,
+                bytes: MemcmpEncodedBytes::Binary(authority.to_string()),
+                encoding: None,
+            }),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        with_context: None,
+    };
+
+    let accounts = client.get_program_accounts_with_config(program_id, config)?;
+    let mut settings: Vec<(Pubkey, MeepSettings)> = accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            let settings = try_from_slice_unchecked(&account.data).ok()?;
+            Some((pubkey, settings))
+        })
+        .collect();
+
+    settings.sort_by_key(|(_, settings)| settings.collection_id);
+    Ok(settings)
+}
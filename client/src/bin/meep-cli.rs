@@ -0,0 +1,126 @@
+use clap::{crate_version, App, AppSettings, Arg, ArgMatches, SubCommand};
+use meep_client::{
+    decoder::{decode_signature, decode_transaction, DecodedInstruction},
+    idl::generate_idl,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signature::Signature, transaction::Transaction};
+use std::{process::exit, str::FromStr};
+
+fn print_instruction(decoded: &DecodedInstruction) {
+    println!("Instruction #{}: {:?}", decoded.index, decoded.instruction);
+    for (index, account) in decoded.accounts.iter().enumerate() {
+        println!(
+            "  {:>2}. {}{} {}",
+            index,
+            account.role,
+            if account.is_signer { " (signer)" } else { "" },
+            account.pubkey
+        );
+    }
+    for mismatch in decoded.mismatches.iter() {
+        println!("  ! {}", mismatch);
+    }
+}
+
+fn decode(client: &RpcClient, program_id: &Pubkey, matches: &ArgMatches) -> Result<bool, String> {
+    let input = matches.value_of("transaction").unwrap();
+
+    let decoded = match Signature::from_str(input) {
+        Ok(signature) => {
+            decode_signature(client, program_id, &signature).map_err(|err| err.to_string())?
+        }
+        Err(_) => {
+            let data = base64::decode(input)
+                .map_err(|_| "Expected a signature or a base64 transaction".to_string())?;
+            let transaction: Transaction =
+                bincode::deserialize(&data).map_err(|err| err.to_string())?;
+            decode_transaction(client, program_id, &transaction)
+        }
+    };
+
+    let mut clean = true;
+    for instruction in decoded {
+        match instruction {
+            Ok(instruction) => {
+                clean &= instruction.mismatches.is_empty();
+                print_instruction(&instruction);
+            }
+            Err(err) => {
+                clean = false;
+                println!("Undecodable instruction: {}", err);
+            }
+        }
+    }
+
+    Ok(clean)
+}
+
+fn idl(program_id: &Pubkey) -> Result<bool, String> {
+    let idl =
+        serde_json::to_string_pretty(&generate_idl(program_id)).map_err(|err| err.to_string())?;
+    println!("{}", idl);
+    Ok(true)
+}
+
+fn main() {
+    let matches = App::new("meep-cli")
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .short("u")
+                .value_name("URL")
+                .takes_value(true)
+                .default_value("http://localhost:8899")
+                .help("JSON RPC URL of the cluster"),
+        )
+        .arg(
+            Arg::with_name("program_id")
+                .long("program-id")
+                .value_name("PUBKEY")
+                .takes_value(true)
+                .help("Meep program ID, the default program ID if not set"),
+        )
+        .subcommand(
+            SubCommand::with_name("decode")
+                .about("Decodes Meep instructions, labels their accounts and flags mismatches")
+                .arg(
+                    Arg::with_name("transaction")
+                        .value_name("SIGNATURE_OR_TRANSACTION")
+                        .required(true)
+                        .help("Confirmed transaction signature or base64 serialized transaction"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("idl")
+                .about("Prints the JSON interface description of the Meep program"),
+        )
+        .get_matches();
+
+    let client = RpcClient::new(matches.value_of("url").unwrap().to_string());
+    let program_id = match matches.value_of("program_id") {
+        Some(program_id) => Pubkey::from_str(program_id).unwrap_or_else(|_| {
+            eprintln!("Invalid program ID");
+            exit(1);
+        }),
+        None => meep::ID,
+    };
+
+    let result = match matches.subcommand() {
+        ("decode", Some(matches)) => decode(&client, &program_id, matches),
+        ("idl", Some(_)) => idl(&program_id),
+        _ => unreachable!(),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => exit(2),
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1);
+        }
+    }
+}
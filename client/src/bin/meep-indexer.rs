@@ -0,0 +1,145 @@
+use clap::{crate_version, App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
+use meep_client::indexer::{IndexedNft, Indexer, IndexerError};
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::{path::Path, process::exit, str::FromStr};
+
+fn parse_pubkey(matches: &ArgMatches, name: &str) -> Option<Pubkey> {
+    matches.value_of(name).map(|value| {
+        Pubkey::from_str(value).unwrap_or_else(|_| {
+            eprintln!("Invalid pubkey: {}", value);
+            exit(1);
+        })
+    })
+}
+
+fn print_nfts(nfts: &[IndexedNft]) {
+    for nft in nfts {
+        let owner = nft
+            .owner
+            .map(|owner| owner.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{} {} {:?} {} owner {}",
+            nft.mint, nft.settings, nft.name, nft.uri, owner
+        );
+    }
+}
+
+fn run(indexer: &mut Indexer, matches: &ArgMatches) -> Result<(), IndexerError> {
+    match matches.subcommand() {
+        ("refresh", Some(matches)) => {
+            let stats = indexer.refresh(matches.is_present("full"))?;
+            println!(
+                "{} settings, {} new NFTs, {} holders",
+                stats.settings, stats.new_nfts, stats.holders
+            );
+        }
+        ("settings", Some(matches)) => {
+            let authority = parse_pubkey(matches, "authority").unwrap();
+            for settings in indexer.settings_by_authority(&authority)? {
+                println!(
+                    "{} collection {} authority {}",
+                    settings.pubkey, settings.collection_id, settings.authority
+                );
+            }
+        }
+        ("nfts", Some(matches)) => {
+            let nfts = if let Some(authority) = parse_pubkey(matches, "authority") {
+                indexer.nfts_by_authority(&authority)?
+            } else if let Some(settings) = parse_pubkey(matches, "settings") {
+                indexer.nfts_by_settings(&settings)?
+            } else {
+                indexer.nfts_by_owner(&parse_pubkey(matches, "owner").unwrap())?
+            };
+            print_nfts(&nfts);
+        }
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+fn pubkey_arg(name: &'static str, help: &'static str) -> Arg<'static, 'static> {
+    Arg::with_name(name)
+        .long(name)
+        .value_name("PUBKEY")
+        .takes_value(true)
+        .help(help)
+}
+
+fn main() {
+    let matches = App::new("meep-indexer")
+        .version(crate_version!())
+        .about("Mirrors Meep settings, minted NFTs and their holders into SQLite")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .short("u")
+                .value_name("URL")
+                .takes_value(true)
+                .default_value("http://localhost:8899")
+                .help("JSON RPC URL of the cluster"),
+        )
+        .arg(
+            Arg::with_name("program_id")
+                .long("program-id")
+                .value_name("PUBKEY")
+                .takes_value(true)
+                .help("Meep program ID, the default program ID if not set"),
+        )
+        .arg(
+            Arg::with_name("db")
+                .long("db")
+                .value_name("PATH")
+                .takes_value(true)
+                .default_value("meep.sqlite")
+                .help("SQLite database file"),
+        )
+        .subcommand(
+            SubCommand::with_name("refresh")
+                .about("Indexes new settings and NFTs and reloads the holders")
+                .arg(
+                    Arg::with_name("full")
+                        .long("full")
+                        .help("Reloads the metadata of already indexed NFTs too"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("settings")
+                .about("Lists the indexed configurations of an authority")
+                .arg(pubkey_arg("authority", "Current or original authority").required(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("nfts")
+                .about("Lists indexed NFTs")
+                .arg(pubkey_arg(
+                    "authority",
+                    "NFTs of all configurations of the authority",
+                ))
+                .arg(pubkey_arg("settings", "NFTs of the settings account"))
+                .arg(pubkey_arg("owner", "NFTs held by the wallet"))
+                .group(
+                    ArgGroup::with_name("filter")
+                        .args(&["authority", "settings", "owner"])
+                        .required(true),
+                ),
+        )
+        .get_matches();
+
+    let client = RpcClient::new_with_commitment(
+        matches.value_of("url").unwrap().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let program_id = parse_pubkey(&matches, "program_id").unwrap_or(meep::ID);
+    let db = Path::new(matches.value_of("db").unwrap());
+
+    let result =
+        Indexer::open(client, &program_id, db).and_then(|mut indexer| run(&mut indexer, &matches));
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
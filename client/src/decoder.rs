@@ -0,0 +1,720 @@
+use borsh::BorshDeserialize;
+use meep::{instruction::MeepInstructions, state::MeepSettings, utils::Pda};
+use solana_client::{client_error::ClientError, rpc_client::RpcClient};
+use solana_program::{
+    borsh::try_from_slice_unchecked, message::Message, pubkey::Pubkey, system_program, sysvar,
+};
+use solana_sdk::{signature::Signature, transaction::Transaction};
+use solana_transaction_status::UiTransactionEncoding;
+
+pub const AUTHORITY: &str = "Authority";
+pub const SECONDARY_CREATOR: &str = "Secondary creator";
+pub const SECONDARY_SIGNER: &str = "Secondary creator or approval delegate";
+pub const SECONDARY_APPROVAL: &str = "Secondary approval PDA";
+pub const SETTINGS: &str = "Settings account";
+pub const MINTER: &str = "Minter";
+pub const MINT: &str = "Mint account";
+pub const TOKEN_ACCOUNT: &str = "Token account";
+pub const TOKEN_METADATA: &str = "TokenMetadata account";
+pub const MASTER_EDITION: &str = "MasterEdition account";
+pub const MINT_RECORD: &str = "Mint record account";
+pub const AUTHORITY_PDA: &str = "Authority PDA";
+pub const ROYALTY_VAULT: &str = "Royalty vault";
+pub const TREASURY: &str = "Treasury";
+pub const TREASURY_TOKEN_ACCOUNT: &str = "Treasury token account";
+pub const GOVERNANCE: &str = "Governance PDA";
+pub const PROPOSAL: &str = "Proposal account";
+pub const VOTE_RECORD: &str = "Vote record account";
+pub const PAYMENT_MINT: &str = "Payment mint";
+pub const PAYMENT_RECIPIENT: &str = "Payment recipient";
+pub const BUYER_TOKEN_ACCOUNT: &str = "Buyer payment token account";
+pub const DELEGATED_MINTER: &str = "Delegated minter";
+pub const DEPOSITOR: &str = "Depositor";
+pub const DEPOSITOR_TOKEN_ACCOUNT: &str = "Depositor token account";
+pub const RECIPIENT: &str = "Recipient";
+pub const HOLDER: &str = "NFT holder";
+pub const HOLDER_TOKEN_ACCOUNT: &str = "Holder token account";
+pub const MEMBER: &str = "Member";
+pub const MEMBER_TOKEN_ACCOUNT: &str = "Member token account";
+pub const STAKER: &str = "Staker";
+pub const STAKER_TOKEN_ACCOUNT: &str = "Staker token account";
+pub const STAKE_RECORD: &str = "Stake record account";
+pub const STAKE_ACCOUNT: &str = "Stake token account";
+pub const REWARD_MINT: &str = "Reward mint";
+pub const REWARD_TOKEN_ACCOUNT: &str = "Staker reward token account";
+pub const REWARD_VAULT: &str = "Reward vault";
+pub const SIGNER_SET_MEMBER: &str = "Signer set member";
+pub const SYSTEM_PROGRAM: &str = "System program";
+pub const TOKEN_PROGRAM: &str = "Token program";
+pub const RENT_PROGRAM: &str = "Rent program";
+pub const METAPLEX_PROGRAM: &str = "Metaplex program";
+pub const MEEP_PROGRAM: &str = "Meep program";
+pub const UNEXPECTED: &str = "Unexpected account";
+
+/// Name, argument type and accounts of an instruction in the order of the
+/// `MeepInstructions` docs
+pub struct InstructionLayout {
+    pub name: &'static str,
+    pub args: Option<&'static str>,
+    pub required: &'static [&'static str],
+
+    /// Passed only in some cases, e.g. for SPL token transfers
+    pub optional: &'static [&'static str],
+
+    /// Roles of any number of trailing accounts, repeated in this order
+    pub rest: &'static [&'static str],
+}
+
+/// Indexed by the `MeepInstructions` discriminant
+pub const INSTRUCTIONS: &[InstructionLayout] = &[
+    InstructionLayout {
+        name: "InitializeMeep",
+        args: Some("InitializeMeepArgs"),
+        required: &[
+            AUTHORITY,
+            SECONDARY_CREATOR,
+            SETTINGS,
+            SYSTEM_PROGRAM,
+            RENT_PROGRAM,
+        ],
+        optional: &[PAYMENT_MINT],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "UpdateSettings",
+        args: Some("SettingsArgs"),
+        required: &[AUTHORITY, SETTINGS],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "MintNft",
+        args: Some("MintNftArgs"),
+        required: &[
+            MINTER,
+            SECONDARY_CREATOR,
+            SETTINGS,
+            MINT,
+            TOKEN_ACCOUNT,
+            TOKEN_METADATA,
+            MASTER_EDITION,
+            SYSTEM_PROGRAM,
+            TOKEN_PROGRAM,
+            RENT_PROGRAM,
+            METAPLEX_PROGRAM,
+            MINT_RECORD,
+            AUTHORITY_PDA,
+            ROYALTY_VAULT,
+            TREASURY,
+        ],
+        optional: &[
+            PAYMENT_RECIPIENT,
+            BUYER_TOKEN_ACCOUNT,
+            TREASURY_TOKEN_ACCOUNT,
+        ],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "EnableRevealMode",
+        args: Some("RevealModeArgs"),
+        required: &[AUTHORITY, SETTINGS],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "Reveal",
+        args: Some("RevealArgs"),
+        required: &[
+            AUTHORITY,
+            SETTINGS,
+            MINT,
+            TOKEN_METADATA,
+            MINT_RECORD,
+            METAPLEX_PROGRAM,
+            AUTHORITY_PDA,
+        ],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "AddDelegatedMinter",
+        args: None,
+        required: &[AUTHORITY, SETTINGS, DELEGATED_MINTER],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "RevokeDelegatedMinter",
+        args: None,
+        required: &[AUTHORITY, SETTINGS, DELEGATED_MINTER],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "GrantMintApproval",
+        args: Some("MintApprovalArgs"),
+        required: &[SECONDARY_CREATOR, SETTINGS],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "RevokeMintApproval",
+        args: None,
+        required: &[SECONDARY_CREATOR, SETTINGS],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "SignAsSecondary",
+        args: None,
+        required: &[
+            SECONDARY_SIGNER,
+            SETTINGS,
+            METAPLEX_PROGRAM,
+            SECONDARY_APPROVAL,
+        ],
+        optional: &[],
+        rest: &[MINT, TOKEN_METADATA, MINT_RECORD],
+    },
+    InstructionLayout {
+        name: "UpdateNftMetadata",
+        args: Some("UpdateNftMetadataArgs"),
+        required: &[
+            AUTHORITY,
+            SETTINGS,
+            MINT,
+            TOKEN_METADATA,
+            MINT_RECORD,
+            METAPLEX_PROGRAM,
+            AUTHORITY_PDA,
+        ],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "DistributeRoyalties",
+        args: None,
+        required: &[
+            ROYALTY_VAULT,
+            SETTINGS,
+            AUTHORITY,
+            SECONDARY_CREATOR,
+            SYSTEM_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "Deposit",
+        args: Some("TreasuryTransferArgs"),
+        required: &[DEPOSITOR, SETTINGS, TREASURY, SYSTEM_PROGRAM],
+        optional: &[
+            DEPOSITOR_TOKEN_ACCOUNT,
+            TREASURY_TOKEN_ACCOUNT,
+            TOKEN_PROGRAM,
+        ],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "Withdraw",
+        args: Some("TreasuryTransferArgs"),
+        required: &[GOVERNANCE, SETTINGS, TREASURY, RECIPIENT, SYSTEM_PROGRAM],
+        optional: &[TREASURY_TOKEN_ACCOUNT, TOKEN_PROGRAM],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "ConfigureGovernance",
+        args: Some("GovernanceArgs"),
+        required: &[AUTHORITY, SETTINGS],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "CreateProposal",
+        args: Some("CreateProposalArgs"),
+        required: &[
+            HOLDER,
+            SETTINGS,
+            PROPOSAL,
+            HOLDER_TOKEN_ACCOUNT,
+            MINT,
+            TOKEN_METADATA,
+            MINT_RECORD,
+            SYSTEM_PROGRAM,
+            RENT_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "CastVote",
+        args: Some("CastVoteArgs"),
+        required: &[
+            HOLDER,
+            SETTINGS,
+            PROPOSAL,
+            VOTE_RECORD,
+            HOLDER_TOKEN_ACCOUNT,
+            MINT,
+            TOKEN_METADATA,
+            MINT_RECORD,
+            SYSTEM_PROGRAM,
+            RENT_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "FinalizeProposal",
+        args: None,
+        required: &[SETTINGS, PROPOSAL],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "ExecuteProposal",
+        args: None,
+        required: &[SETTINGS, PROPOSAL, GOVERNANCE],
+        optional: &[
+            TREASURY,
+            RECIPIENT,
+            SYSTEM_PROGRAM,
+            MEEP_PROGRAM,
+            TREASURY_TOKEN_ACCOUNT,
+            TOKEN_PROGRAM,
+        ],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "UpdateSigners",
+        args: Some("UpdateSignersArgs"),
+        required: &[AUTHORITY, SETTINGS],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "ApplyPendingSettings",
+        args: None,
+        required: &[SETTINGS],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "VetoPendingSettings",
+        args: None,
+        required: &[SECONDARY_CREATOR, SETTINGS],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "BurnNft",
+        args: None,
+        required: &[
+            HOLDER,
+            SETTINGS,
+            MINT,
+            HOLDER_TOKEN_ACCOUNT,
+            MINT_RECORD,
+            TOKEN_METADATA,
+            MASTER_EDITION,
+            TOKEN_PROGRAM,
+            METAPLEX_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "RevokeMembership",
+        args: None,
+        required: &[
+            AUTHORITY,
+            SETTINGS,
+            MINT,
+            TOKEN_ACCOUNT,
+            MINT_RECORD,
+            AUTHORITY_PDA,
+            TOKEN_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "RecoverMembership",
+        args: None,
+        required: &[
+            AUTHORITY,
+            SETTINGS,
+            MINT,
+            TOKEN_ACCOUNT,
+            MINT_RECORD,
+            MEMBER,
+            MEMBER_TOKEN_ACCOUNT,
+            AUTHORITY_PDA,
+            TOKEN_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "ConfigureStaking",
+        args: Some("StakingArgs"),
+        required: &[AUTHORITY, SETTINGS, REWARD_MINT],
+        optional: &[],
+        rest: &[SIGNER_SET_MEMBER],
+    },
+    InstructionLayout {
+        name: "Stake",
+        args: Some("StakeArgs"),
+        required: &[
+            STAKER,
+            SETTINGS,
+            STAKE_RECORD,
+            STAKE_ACCOUNT,
+            STAKER_TOKEN_ACCOUNT,
+            MINT,
+            TOKEN_METADATA,
+            MINT_RECORD,
+            SYSTEM_PROGRAM,
+            TOKEN_PROGRAM,
+            RENT_PROGRAM,
+        ],
+        optional: &[],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "Unstake",
+        args: Some("UnstakeArgs"),
+        required: &[
+            STAKER,
+            SETTINGS,
+            STAKE_RECORD,
+            STAKE_ACCOUNT,
+            STAKER_TOKEN_ACCOUNT,
+            MINT,
+            TOKEN_PROGRAM,
+            REWARD_TOKEN_ACCOUNT,
+            REWARD_MINT,
+            AUTHORITY_PDA,
+        ],
+        optional: &[REWARD_VAULT],
+        rest: &[],
+    },
+    InstructionLayout {
+        name: "ClaimRewards",
+        args: None,
+        required: &[
+            STAKER,
+            SETTINGS,
+            STAKE_RECORD,
+            REWARD_TOKEN_ACCOUNT,
+            REWARD_MINT,
+            AUTHORITY_PDA,
+            TOKEN_PROGRAM,
+        ],
+        optional: &[REWARD_VAULT],
+        rest: &[],
+    },
+];
+
+pub struct LabeledAccount {
+    pub pubkey: Pubkey,
+    pub role: &'static str,
+    pub is_signer: bool,
+}
+
+pub struct DecodedInstruction {
+    /// Position of the instruction in the transaction
+    pub index: usize,
+    pub instruction: MeepInstructions,
+    pub accounts: Vec<LabeledAccount>,
+
+    /// Accounts that don't match what the program derives or expects
+    pub mismatches: Vec<String>,
+}
+
+/// Labels the accounts of a Meep instruction and checks them against the PDAs and programs
+/// the processor expects. `get_settings` loads settings accounts to check the authority and
+/// settings PDA, decoding works offline when it returns `None`
+pub fn decode_instruction(
+    program_id: &Pubkey,
+    index: usize,
+    data: &[u8],
+    accounts: &[(Pubkey, bool)],
+    get_settings: &dyn Fn(&Pubkey) -> Option<MeepSettings>,
+) -> Result<DecodedInstruction, std::io::Error> {
+    let instruction = MeepInstructions::try_from_slice(data)?;
+    let layout = &INSTRUCTIONS[data[0] as usize];
+
+    // Optional accounts never sign, so a signer after the required accounts starts the rest
+    let optional = match accounts.get(layout.required.len()) {
+        Some((_, true)) if !layout.rest.is_empty() => &layout.optional[..0],
+        _ => layout.optional,
+    };
+    let roles = layout
+        .required
+        .iter()
+        .chain(optional.iter())
+        .copied()
+        .chain(layout.rest.iter().copied().cycle())
+        .chain(std::iter::repeat(UNEXPECTED));
+    let labeled: Vec<LabeledAccount> = accounts
+        .iter()
+        .zip(roles)
+        .map(|((pubkey, is_signer), role)| LabeledAccount {
+            pubkey: *pubkey,
+            role,
+            is_signer: *is_signer,
+        })
+        .collect();
+
+    let mut mismatches: Vec<String> = layout
+        .required
+        .iter()
+        .skip(accounts.len())
+        .map(|role| format!("{} is missing", role))
+        .collect();
+    mismatches.extend(check_accounts(
+        program_id,
+        &instruction,
+        &labeled,
+        get_settings,
+    ));
+
+    Ok(DecodedInstruction {
+        index,
+        instruction,
+        accounts: labeled,
+        mismatches,
+    })
+}
+
+fn find(accounts: &[LabeledAccount], role: &str) -> Option<Pubkey> {
+    accounts
+        .iter()
+        .find(|account| account.role == role)
+        .map(|account| account.pubkey)
+}
+
+fn check_accounts(
+    program_id: &Pubkey,
+    instruction: &MeepInstructions,
+    accounts: &[LabeledAccount],
+    get_settings: &dyn Fn(&Pubkey) -> Option<MeepSettings>,
+) -> Vec<String> {
+    let settings_pubkey = find(accounts, SETTINGS);
+    let settings = settings_pubkey.and_then(|settings| get_settings(&settings));
+    let mut mint = find(accounts, MINT);
+    let proposal = find(accounts, PROPOSAL);
+
+    let mut mismatches = Vec::new();
+    for account in accounts {
+        // Trailing NFTs of a batch are checked against their own mint
+        if account.role == MINT {
+            mint = Some(account.pubkey);
+        }
+
+        if account.role == UNEXPECTED {
+            mismatches.push(format!("{} {}", UNEXPECTED, account.pubkey));
+        }
+
+        if let Some(settings) = &settings {
+            let not_allowed = match account.role {
+                // Anyone else buys the NFT once a price is set
+                MINTER => {
+                    account.pubkey != settings.authority
+                        && !settings.delegated_minters.contains(&account.pubkey)
+                        && (settings.minimum_price == 0 || settings.membership)
+                }
+                SECONDARY_SIGNER => {
+                    account.pubkey != settings.secondary_creator
+                        && settings
+                            .mint_approval
+                            .as_ref()
+                            .and_then(|approval| approval.delegate)
+                            != Some(account.pubkey)
+                }
+                SIGNER_SET_MEMBER => !settings.signers.contains(&account.pubkey),
+                _ => false,
+            };
+            if not_allowed {
+                mismatches.push(format!(
+                    "{} {} isn't allowed by the settings",
+                    account.role, account.pubkey
+                ));
+            }
+        }
+
+        if account.role == SIGNER_SET_MEMBER && !account.is_signer {
+            mismatches.push(format!("{} {} doesn't sign", account.role, account.pubkey));
+        }
+
+        let expected = match account.role {
+            SETTINGS => match (instruction, &settings) {
+                (MeepInstructions::InitializeMeep(args), _) => {
+                    find(accounts, AUTHORITY).map(|authority| {
+                        Pda::settings_pubkey_with_bump(program_id, &authority, args.collection_id).0
+                    })
+                }
+                (_, Some(settings)) => Some(
+                    Pda::settings_pubkey_with_bump(
+                        program_id,
+                        &settings.original_authority,
+                        settings.collection_id,
+                    )
+                    .0,
+                ),
+                _ => None,
+            },
+            AUTHORITY if !matches!(instruction, MeepInstructions::InitializeMeep(_)) => {
+                settings.as_ref().map(|settings| settings.authority)
+            }
+            SECONDARY_CREATOR if !matches!(instruction, MeepInstructions::InitializeMeep(_)) => {
+                settings.as_ref().map(|settings| settings.secondary_creator)
+            }
+            AUTHORITY_PDA => settings_pubkey
+                .map(|settings| Pda::authority_pubkey_with_bump(program_id, &settings).0),
+            ROYALTY_VAULT => settings_pubkey
+                .map(|settings| Pda::royalty_vault_pubkey_with_bump(program_id, &settings).0),
+            TREASURY => settings_pubkey
+                .map(|settings| Pda::treasury_pubkey_with_bump(program_id, &settings).0),
+            SECONDARY_APPROVAL => settings_pubkey
+                .map(|settings| Pda::secondary_approval_pubkey_with_bump(program_id, &settings).0),
+            GOVERNANCE => settings_pubkey
+                .map(|settings| Pda::governance_pubkey_with_bump(program_id, &settings).0),
+            TOKEN_METADATA if mint.is_some() => mint.as_ref().map(Pda::metadata_pubkey),
+            MASTER_EDITION => mint.as_ref().map(Pda::master_edition_pubkey),
+            MINT_RECORD => mint.map(|mint| Pda::mint_record_pubkey_with_bump(program_id, &mint).0),
+            VOTE_RECORD => match (proposal, mint) {
+                (Some(proposal), Some(mint)) => {
+                    Some(Pda::vote_record_pubkey_with_bump(program_id, &proposal, &mint).0)
+                }
+                _ => None,
+            },
+            STAKE_RECORD => {
+                mint.map(|mint| Pda::stake_record_pubkey_with_bump(program_id, &mint).0)
+            }
+            STAKE_ACCOUNT => {
+                mint.map(|mint| Pda::stake_account_pubkey_with_bump(program_id, &mint).0)
+            }
+            REWARD_MINT if !matches!(instruction, MeepInstructions::ConfigureStaking(_)) => {
+                settings
+                    .as_ref()
+                    .and_then(|settings| settings.staking.as_ref())
+                    .map(|staking| staking.reward_mint)
+            }
+            PAYMENT_RECIPIENT => settings
+                .as_ref()
+                .filter(|settings| settings.payment_mint.is_none())
+                .map(|settings| settings.authority),
+            PAYMENT_MINT => match instruction {
+                MeepInstructions::InitializeMeep(args) => args.payment_mint,
+                _ => None,
+            },
+            SYSTEM_PROGRAM => Some(system_program::ID),
+            TOKEN_PROGRAM => Some(spl_token::ID),
+            RENT_PROGRAM => Some(sysvar::rent::ID),
+            METAPLEX_PROGRAM => Some(metaplex_token_metadata::ID),
+            MEEP_PROGRAM => Some(*program_id),
+            _ => None,
+        };
+
+        if let Some(expected) = expected {
+            if account.pubkey != expected {
+                mismatches.push(format!(
+                    "{} is {}, expected {}",
+                    account.role, account.pubkey, expected
+                ));
+            }
+        }
+    }
+
+    if let (Some(settings_pubkey), None) = (settings_pubkey, &settings) {
+        if !matches!(instruction, MeepInstructions::InitializeMeep(_)) {
+            mismatches.push(format!(
+                "{} {} wasn't loaded, settings checks are skipped",
+                SETTINGS, settings_pubkey
+            ));
+        }
+    }
+
+    mismatches
+}
+
+/// Decodes every Meep instruction of a message, instructions of other programs are skipped
+pub fn decode_message(
+    program_id: &Pubkey,
+    message: &Message,
+    get_settings: &dyn Fn(&Pubkey) -> Option<MeepSettings>,
+) -> Vec<Result<DecodedInstruction, std::io::Error>> {
+    message
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| {
+            message
+                .account_keys
+                .get(instruction.program_id_index as usize)
+                == Some(program_id)
+        })
+        .map(|(index, instruction)| {
+            let accounts: Vec<(Pubkey, bool)> = instruction
+                .accounts
+                .iter()
+                .map(|&account| {
+                    let account = account as usize;
+                    (message.account_keys[account], message.is_signer(account))
+                })
+                .collect();
+
+            decode_instruction(
+                program_id,
+                index,
+                &instruction.data,
+                &accounts,
+                get_settings,
+            )
+        })
+        .collect()
+}
+
+/// Loads settings accounts for `decode_message` through RPC
+pub fn rpc_settings_loader(client: &RpcClient) -> impl Fn(&Pubkey) -> Option<MeepSettings> + '_ {
+    move |settings| {
+        let data = client.get_account_data(settings).ok()?;
+        try_from_slice_unchecked(&data).ok()
+    }
+}
+
+/// Fetches a confirmed transaction and decodes its Meep instructions
+pub fn decode_signature(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    signature: &Signature,
+) -> Result<Vec<Result<DecodedInstruction, std::io::Error>>, ClientError> {
+    let transaction = client
+        .get_transaction(signature, UiTransactionEncoding::Base64)?
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| {
+            ClientError::from(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Transaction can't be decoded",
+            ))
+        })?;
+
+    Ok(decode_transaction(client, program_id, &transaction))
+}
+
+/// Decodes a transaction before it is sent, settings are loaded through RPC
+pub fn decode_transaction(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    transaction: &Transaction,
+) -> Vec<Result<DecodedInstruction, std::io::Error>> {
+    decode_message(
+        program_id,
+        &transaction.message,
+        &rpc_settings_loader(client),
+    )
+}
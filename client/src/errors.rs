@@ -0,0 +1,111 @@
+use meep::error::MeepError;
+use metaplex_token_metadata::error::MetadataError;
+use num_traits::FromPrimitive;
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
+use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use spl_token::error::TokenError;
+use std::str::FromStr;
+
+/// Custom program error of a failed transaction, decoded by the program that returned it
+#[derive(Clone, Debug, PartialEq)]
+pub enum CustomError {
+    Meep(MeepError),
+    TokenMetadata(MetadataError),
+    Token(TokenError),
+
+    /// Code unknown to the program, or returned by another program
+    Unknown {
+        program_id: Pubkey,
+        code: u32,
+    },
+}
+
+impl CustomError {
+    pub fn new(meep_program_id: &Pubkey, program_id: &Pubkey, code: u32) -> Self {
+        let error = if program_id == meep_program_id {
+            MeepError::from_u32(code).map(CustomError::Meep)
+        } else if *program_id == metaplex_token_metadata::ID {
+            MetadataError::from_u32(code).map(CustomError::TokenMetadata)
+        } else if *program_id == spl_token::ID {
+            TokenError::from_u32(code).map(CustomError::Token)
+        } else {
+            None
+        };
+
+        error.unwrap_or(CustomError::Unknown {
+            program_id: *program_id,
+            code,
+        })
+    }
+}
+
+/// Program that returned the error first. A failing CPI is logged before the programs
+/// above it on the stack, which fail with the same error
+pub fn failed_program(logs: &[String]) -> Option<Pubkey> {
+    logs.iter().find_map(|log| {
+        let mut words = log.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("Program"), Some(program), Some("failed:")) => Pubkey::from_str(program).ok(),
+            _ => None,
+        }
+    })
+}
+
+/// Decodes the custom error of a failed transaction. Without logs the error is attributed
+/// to the program of the failed instruction, i.e. CPI errors are decoded as its errors
+pub fn decode_transaction_error(
+    meep_program_id: &Pubkey,
+    transaction: &Transaction,
+    error: &TransactionError,
+    logs: Option<&[String]>,
+) -> Option<CustomError> {
+    let (index, code) = match error {
+        TransactionError::InstructionError(index, InstructionError::Custom(code)) => {
+            (*index, *code)
+        }
+        _ => return None,
+    };
+
+    let program_id = logs.and_then(failed_program).or_else(|| {
+        let message = &transaction.message;
+        message
+            .instructions
+            .get(index as usize)
+            .and_then(|instruction| {
+                message
+                    .account_keys
+                    .get(instruction.program_id_index as usize)
+            })
+            .copied()
+    })?;
+
+    Some(CustomError::new(meep_program_id, &program_id, code))
+}
+
+/// Decodes the custom error of `transaction` sent through `client`, preflight failures
+/// come with logs, so CPI errors are told apart
+pub fn decode_client_error(
+    meep_program_id: &Pubkey,
+    transaction: &Transaction,
+    error: &ClientError,
+) -> Option<CustomError> {
+    match error.kind() {
+        ClientErrorKind::TransactionError(error) => {
+            decode_transaction_error(meep_program_id, transaction, error, None)
+        }
+        ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => decode_transaction_error(
+            meep_program_id,
+            transaction,
+            result.err.as_ref()?,
+            result.logs.as_deref(),
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,64 @@
+use borsh::BorshDeserialize;
+use meep::event::MeepEvent;
+use solana_client::{client_error::ClientError, rpc_client::RpcClient};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Meep events in transaction logs. Program data is attributed to the program on top of the
+/// invocation stack, so data logged by other programs is skipped
+pub fn decode_events(program_id: &Pubkey, logs: &[String]) -> Vec<MeepEvent> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if invocations.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+
+            let event = data
+                .split_whitespace()
+                .next()
+                .and_then(|data| base64::decode(data).ok())
+                .and_then(|data| MeepEvent::try_from_slice(&data).ok());
+            if let Some(event) = event {
+                events.push(event);
+            }
+
+            continue;
+        }
+
+        let mut words = log.split_whitespace();
+        if let (Some("Program"), Some(program), Some(status)) =
+            (words.next(), words.next(), words.next())
+        {
+            if status == "invoke" {
+                invocations.push(program);
+            } else if status == "success" || status.starts_with("failed") {
+                invocations.pop();
+            }
+        }
+    }
+
+    events
+}
+
+/// Meep events of a confirmed transaction
+pub fn get_transaction_events(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    signature: &Signature,
+) -> Result<Vec<MeepEvent>, ClientError> {
+    let transaction = client.get_transaction(signature, UiTransactionEncoding::Json)?;
+    let logs = transaction
+        .transaction
+        .meta
+        .and_then(|meta| meta.log_messages)
+        .unwrap_or_default();
+
+    Ok(decode_events(program_id, &logs))
+}
@@ -0,0 +1,696 @@
+use crate::decoder::INSTRUCTIONS;
+use meep::{
+    error::MeepError,
+    state::{
+        MeepSettings, MintRecord, Proposal, StakeRecord, VoteRecord, MAX_DELEGATED_MINTERS,
+        MAX_SIGNERS,
+    },
+};
+use num_traits::FromPrimitive;
+use serde_json::{json, Map, Value};
+use solana_program::pubkey::Pubkey;
+use std::convert::TryInto;
+
+/// Borsh type of a field
+#[derive(Clone, Debug)]
+pub enum IdlType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I64,
+    Pubkey,
+    String,
+    Bytes(usize),
+    Option(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Defined(&'static str),
+}
+
+#[derive(Clone, Debug)]
+pub enum IdlFields {
+    Unit,
+    Tuple(Vec<IdlType>),
+    Named(Vec<(&'static str, IdlType)>),
+}
+
+#[derive(Clone, Debug)]
+pub enum IdlTypeDef {
+    Struct(Vec<(&'static str, IdlType)>),
+    Enum(Vec<(&'static str, IdlFields)>),
+}
+
+pub struct IdlAccount {
+    pub name: &'static str,
+
+    /// Allocated size, data may be shorter
+    pub size: usize,
+}
+
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    pub msg: String,
+}
+
+fn option(ty: IdlType) -> IdlType {
+    IdlType::Option(Box::new(ty))
+}
+
+fn vec(ty: IdlType) -> IdlType {
+    IdlType::Vec(Box::new(ty))
+}
+
+/// Layouts of the instruction arguments, accounts and events, in declaration order
+pub fn type_defs() -> Vec<(&'static str, IdlTypeDef)> {
+    use IdlType::*;
+
+    vec![
+        (
+            "SettingsArgs",
+            IdlTypeDef::Struct(vec![
+                ("primary_wallet_percentage", U8),
+                ("minimum_price", U64),
+                ("treasury_fee_basis_points", U16),
+                ("treasury_mint_fee", U64),
+                ("settings_delay", I64),
+            ]),
+        ),
+        (
+            "InitializeMeepArgs",
+            IdlTypeDef::Struct(vec![
+                ("collection_id", U64),
+                ("settings", Defined("SettingsArgs")),
+                ("pda_authority", Bool),
+                ("royalty_vault", Bool),
+                ("payment_mint", option(Pubkey)),
+                ("membership", Bool),
+            ]),
+        ),
+        (
+            "MintNftArgs",
+            IdlTypeDef::Struct(vec![
+                ("seller_fee_basis_points", U16),
+                ("token_name", String),
+                ("token_symbol", String),
+                ("uri", String),
+                ("is_mutable", Bool),
+                ("mint_record_bump", U8),
+            ]),
+        ),
+        (
+            "UpdateNftMetadataArgs",
+            IdlTypeDef::Struct(vec![
+                ("token_name", option(String)),
+                ("uri", option(String)),
+                ("seller_fee_basis_points", option(U16)),
+                ("lock", Bool),
+            ]),
+        ),
+        (
+            "MintApprovalArgs",
+            IdlTypeDef::Struct(vec![
+                ("max_mints", option(U32)),
+                ("expires_at", option(I64)),
+                ("delegate", option(Pubkey)),
+            ]),
+        ),
+        (
+            "RevealModeArgs",
+            IdlTypeDef::Struct(vec![
+                ("placeholder_uri", String),
+                ("uri_commitment", Bytes(32)),
+                ("uri_count", U32),
+            ]),
+        ),
+        (
+            "RevealArgs",
+            IdlTypeDef::Struct(vec![("uri", String), ("proof", vec(Bytes(32)))]),
+        ),
+        (
+            "TreasuryTransferArgs",
+            IdlTypeDef::Struct(vec![("amount", U64)]),
+        ),
+        (
+            "GovernanceArgs",
+            IdlTypeDef::Struct(vec![
+                ("quorum", U32),
+                ("threshold_percentage", U8),
+                ("voting_period", I64),
+                ("execution_delay", I64),
+            ]),
+        ),
+        (
+            "ProposalAction",
+            IdlTypeDef::Enum(vec![
+                ("None", IdlFields::Unit),
+                (
+                    "UpdateSettings",
+                    IdlFields::Tuple(vec![Defined("SettingsArgs")]),
+                ),
+                (
+                    "TreasuryTransfer",
+                    IdlFields::Named(vec![
+                        ("recipient", Pubkey),
+                        ("amount", U64),
+                        ("treasury_token_account", option(Pubkey)),
+                    ]),
+                ),
+                ("AddDelegatedMinter", IdlFields::Tuple(vec![Pubkey])),
+                ("RevokeDelegatedMinter", IdlFields::Tuple(vec![Pubkey])),
+                ("RotateAuthority", IdlFields::Tuple(vec![Pubkey])),
+            ]),
+        ),
+        (
+            "CreateProposalArgs",
+            IdlTypeDef::Struct(vec![
+                ("description_uri", String),
+                ("action", Defined("ProposalAction")),
+            ]),
+        ),
+        ("CastVoteArgs", IdlTypeDef::Struct(vec![("approve", Bool)])),
+        (
+            "UpdateSignersArgs",
+            IdlTypeDef::Struct(vec![("signers", vec(Pubkey)), ("threshold", U8)]),
+        ),
+        (
+            "StakingArgs",
+            IdlTypeDef::Struct(vec![("reward_rate", U64)]),
+        ),
+        (
+            "StakeArgs",
+            IdlTypeDef::Struct(vec![("stake_record_bump", U8), ("stake_account_bump", U8)]),
+        ),
+        (
+            "UnstakeArgs",
+            IdlTypeDef::Struct(vec![("forfeit_rewards", Bool)]),
+        ),
+        (
+            "RevealSettings",
+            IdlTypeDef::Struct(vec![
+                ("placeholder_uri", String),
+                ("uri_commitment", Bytes(32)),
+                ("uri_count", U32),
+                ("minted", U32),
+            ]),
+        ),
+        (
+            "MintApproval",
+            IdlTypeDef::Struct(vec![
+                ("remaining", option(U32)),
+                ("expires_at", option(I64)),
+                ("delegate", option(Pubkey)),
+            ]),
+        ),
+        (
+            "GovernanceConfig",
+            IdlTypeDef::Struct(vec![
+                ("quorum", U32),
+                ("threshold_percentage", U8),
+                ("voting_period", I64),
+                ("execution_delay", I64),
+            ]),
+        ),
+        (
+            "StakingConfig",
+            IdlTypeDef::Struct(vec![
+                ("reward_mint", Pubkey),
+                ("reward_rate", U64),
+                ("staked_count", U64),
+                ("reward_per_nft", U64),
+                ("updated_at", I64),
+            ]),
+        ),
+        (
+            "PdaBumps",
+            IdlTypeDef::Struct(vec![
+                ("authority", U8),
+                ("royalty_vault", U8),
+                ("treasury", U8),
+                ("governance", U8),
+                ("secondary_approval", U8),
+                ("locked", U8),
+            ]),
+        ),
+        (
+            "PendingSettings",
+            IdlTypeDef::Struct(vec![
+                ("settings", Defined("SettingsArgs")),
+                ("effective_at", I64),
+            ]),
+        ),
+        (
+            "MeepSettings",
+            IdlTypeDef::Struct(vec![
+                ("authority", Pubkey),
+                ("collection_id", U64),
+                ("secondary_creator", Pubkey),
+                ("primary_wallet_percentage", U8),
+                ("minimum_price", U64),
+                ("reveal", option(Defined("RevealSettings"))),
+                ("delegated_minters", vec(Pubkey)),
+                ("mint_approval", option(Defined("MintApproval"))),
+                ("pda_authority", Bool),
+                ("royalty_vault", Bool),
+                ("payment_mint", option(Pubkey)),
+                ("treasury_fee_basis_points", U16),
+                ("treasury_mint_fee", U64),
+                ("governance", option(Defined("GovernanceConfig"))),
+                ("proposal_count", U64),
+                ("original_authority", Pubkey),
+                ("signers", vec(Pubkey)),
+                ("signer_threshold", U8),
+                ("settings_delay", I64),
+                ("pending_settings", option(Defined("PendingSettings"))),
+                ("bump", U8),
+                ("membership", Bool),
+                ("staking", option(Defined("StakingConfig"))),
+                ("bumps", Defined("PdaBumps")),
+            ]),
+        ),
+        (
+            "MintRecord",
+            IdlTypeDef::Struct(vec![
+                ("settings", Pubkey),
+                ("minter", Pubkey),
+                ("reveal_index", option(U32)),
+                ("revealed", Bool),
+                ("bump", U8),
+                ("minted_at", I64),
+            ]),
+        ),
+        (
+            "ProposalState",
+            IdlTypeDef::Enum(vec![
+                ("Voting", IdlFields::Unit),
+                ("Approved", IdlFields::Unit),
+                ("Rejected", IdlFields::Unit),
+                ("Executed", IdlFields::Unit),
+            ]),
+        ),
+        (
+            "Proposal",
+            IdlTypeDef::Struct(vec![
+                ("settings", Pubkey),
+                ("proposer", Pubkey),
+                ("index", U64),
+                ("description_uri", String),
+                ("voting_ends_at", I64),
+                ("yes_votes", U32),
+                ("no_votes", U32),
+                ("state", Defined("ProposalState")),
+                ("governance", Defined("GovernanceConfig")),
+                ("action", Defined("ProposalAction")),
+                ("created_at", I64),
+                ("bump", U8),
+            ]),
+        ),
+        (
+            "VoteRecord",
+            IdlTypeDef::Struct(vec![
+                ("proposal", Pubkey),
+                ("mint", Pubkey),
+                ("voter", Pubkey),
+                ("approve", Bool),
+            ]),
+        ),
+        (
+            "StakeRecord",
+            IdlTypeDef::Struct(vec![
+                ("settings", Pubkey),
+                ("mint", Pubkey),
+                ("staker", Pubkey),
+                ("staked_at", I64),
+                ("claimed_at", I64),
+                ("reward_checkpoint", U64),
+                ("bump", U8),
+                ("stake_account_bump", U8),
+            ]),
+        ),
+        (
+            "MeepEvent",
+            IdlTypeDef::Enum(vec![
+                (
+                    "Initialized",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("authority", Pubkey),
+                        ("secondary_creator", Pubkey),
+                        ("collection_id", U64),
+                        ("args", Defined("SettingsArgs")),
+                        ("pda_authority", Bool),
+                        ("royalty_vault", Bool),
+                        ("payment_mint", option(Pubkey)),
+                        ("membership", Bool),
+                    ]),
+                ),
+                (
+                    "SettingsUpdated",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("authority", Pubkey),
+                        ("args", Defined("SettingsArgs")),
+                        ("effective_at", I64),
+                    ]),
+                ),
+                (
+                    "Minted",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("minter", Pubkey),
+                        ("mint", Pubkey),
+                        ("token_account", Pubkey),
+                        ("metadata", Pubkey),
+                        ("owner", Pubkey),
+                        ("update_authority", Pubkey),
+                        ("uri", String),
+                        ("reveal_index", option(U32)),
+                        ("treasury_fee", U64),
+                    ]),
+                ),
+                (
+                    "Sold",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("mint", Pubkey),
+                        ("seller", Pubkey),
+                        ("buyer", Pubkey),
+                        ("price", U64),
+                        ("payment_mint", option(Pubkey)),
+                        ("treasury_fee", U64),
+                    ]),
+                ),
+                (
+                    "Burned",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("holder", Pubkey),
+                        ("mint", Pubkey),
+                    ]),
+                ),
+                (
+                    "MembershipRecovered",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("mint", Pubkey),
+                        ("from", Pubkey),
+                        ("to", Pubkey),
+                    ]),
+                ),
+                (
+                    "Staked",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("mint", Pubkey),
+                        ("staker", Pubkey),
+                    ]),
+                ),
+                (
+                    "Unstaked",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("mint", Pubkey),
+                        ("staker", Pubkey),
+                    ]),
+                ),
+                (
+                    "RewardsClaimed",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("mint", Pubkey),
+                        ("staker", Pubkey),
+                        ("reward_mint", Pubkey),
+                        ("amount", U64),
+                        ("claimed_at", I64),
+                    ]),
+                ),
+                (
+                    "SettingsVetoed",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("secondary_creator", Pubkey),
+                        ("args", Defined("SettingsArgs")),
+                        ("effective_at", I64),
+                    ]),
+                ),
+                (
+                    "SettingsApplied",
+                    IdlFields::Named(vec![
+                        ("settings", Pubkey),
+                        ("args", Defined("SettingsArgs")),
+                        ("effective_at", I64),
+                    ]),
+                ),
+            ]),
+        ),
+    ]
+}
+
+/// Program-owned accounts, their layouts are in `type_defs`
+pub fn accounts() -> Vec<IdlAccount> {
+    vec![
+        IdlAccount {
+            name: "MeepSettings",
+            size: MeepSettings::LEN,
+        },
+        IdlAccount {
+            name: "MintRecord",
+            size: MintRecord::LEN,
+        },
+        IdlAccount {
+            name: "Proposal",
+            size: Proposal::LEN,
+        },
+        IdlAccount {
+            name: "VoteRecord",
+            size: VoteRecord::LEN,
+        },
+        IdlAccount {
+            name: "StakeRecord",
+            size: StakeRecord::LEN,
+        },
+    ]
+}
+
+/// `MeepError` variants, their codes are `ProgramError::Custom` values
+pub fn errors() -> Vec<IdlError> {
+    (0..=u8::MAX as u32)
+        .filter_map(|code| {
+            MeepError::from_u32(code).map(|error| IdlError {
+                code,
+                name: format!("{:?}", error),
+                msg: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn type_json(ty: &IdlType) -> Value {
+    match ty {
+        IdlType::Bool => json!("bool"),
+        IdlType::U8 => json!("u8"),
+        IdlType::U16 => json!("u16"),
+        IdlType::U32 => json!("u32"),
+        IdlType::U64 => json!("u64"),
+        IdlType::I64 => json!("i64"),
+        IdlType::Pubkey => json!("publicKey"),
+        IdlType::String => json!("string"),
+        IdlType::Bytes(len) => json!({ "array": ["u8", len] }),
+        IdlType::Option(ty) => json!({ "option": type_json(ty) }),
+        IdlType::Vec(ty) => json!({ "vec": type_json(ty) }),
+        IdlType::Defined(name) => json!({ "defined": name }),
+    }
+}
+
+fn fields_json(fields: &[(&'static str, IdlType)]) -> Value {
+    Value::Array(
+        fields
+            .iter()
+            .map(|(name, ty)| json!({ "name": name, "type": type_json(ty) }))
+            .collect(),
+    )
+}
+
+fn type_def_json(name: &str, def: &IdlTypeDef) -> Value {
+    let ty = match def {
+        IdlTypeDef::Struct(fields) => json!({ "kind": "struct", "fields": fields_json(fields) }),
+        IdlTypeDef::Enum(variants) => {
+            let variants: Vec<Value> = variants
+                .iter()
+                .map(|(name, fields)| match fields {
+                    IdlFields::Unit => json!({ "name": name }),
+                    IdlFields::Tuple(types) => json!({
+                        "name": name,
+                        "fields": types.iter().map(type_json).collect::<Vec<Value>>(),
+                    }),
+                    IdlFields::Named(fields) => json!({
+                        "name": name,
+                        "fields": fields_json(fields),
+                    }),
+                })
+                .collect();
+            json!({ "kind": "enum", "variants": variants })
+        }
+    };
+
+    json!({ "name": name, "type": ty })
+}
+
+/// JSON interface description of the Meep program
+pub fn generate_idl(program_id: &Pubkey) -> Value {
+    let instructions: Vec<Value> = INSTRUCTIONS
+        .iter()
+        .enumerate()
+        .map(|(discriminant, instruction)| {
+            let accounts: Vec<Value> = instruction
+                .required
+                .iter()
+                .map(|role| json!({ "name": role, "optional": false }))
+                .chain(
+                    instruction
+                        .optional
+                        .iter()
+                        .map(|role| json!({ "name": role, "optional": true })),
+                )
+                .collect();
+
+            json!({
+                "name": instruction.name,
+                "discriminant": discriminant,
+                "accounts": accounts,
+                "remainingAccounts": instruction.rest,
+                "args": instruction.args.map(|args| json!({ "defined": args })),
+            })
+        })
+        .collect();
+
+    let types = type_defs();
+    let accounts: Vec<Value> = accounts()
+        .iter()
+        .map(|account| {
+            let def = types
+                .iter()
+                .find(|(name, _)| *name == account.name)
+                .map(|(_, def)| def)
+                .expect("Account layout is missing");
+            let mut value = type_def_json(account.name, def);
+            value["size"] = json!(account.size);
+            value
+        })
+        .collect();
+
+    let errors: Vec<Value> = errors()
+        .iter()
+        .map(|error| json!({ "code": error.code, "name": error.name, "msg": error.msg }))
+        .collect();
+
+    json!({
+        "name": "meep",
+        "programId": program_id.to_string(),
+        "constants": {
+            "MAX_DELEGATED_MINTERS": MAX_DELEGATED_MINTERS,
+            "MAX_SIGNERS": MAX_SIGNERS,
+        },
+        "instructions": instructions,
+        "accounts": accounts,
+        "types": types
+            .iter()
+            .map(|(name, def)| type_def_json(name, def))
+            .collect::<Vec<Value>>(),
+        "errors": errors,
+    })
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+
+    let (value, rest) = data.split_at(len);
+    *data = rest;
+    Some(value)
+}
+
+fn variant(name: &str, value: Value) -> Value {
+    let mut object = Map::new();
+    object.insert(name.to_string(), value);
+    Value::Object(object)
+}
+
+fn decode_fields(
+    types: &[(&'static str, IdlTypeDef)],
+    fields: &[(&'static str, IdlType)],
+    data: &mut &[u8],
+) -> Option<Value> {
+    let mut object = Map::new();
+    for (name, ty) in fields {
+        object.insert(name.to_string(), decode_value(types, ty, data)?);
+    }
+
+    Some(Value::Object(object))
+}
+
+/// Reads a Borsh value of `ty` from the front of `data` following the IDL only, the way a
+/// client in another language would. Returns `None` if the data is too short or invalid
+pub fn decode_value(
+    types: &[(&'static str, IdlTypeDef)],
+    ty: &IdlType,
+    data: &mut &[u8],
+) -> Option<Value> {
+    let value = match ty {
+        IdlType::Bool => match take(data, 1)?[0] {
+            0 => json!(false),
+            1 => json!(true),
+            _ => return None,
+        },
+        IdlType::U8 => json!(take(data, 1)?[0]),
+        IdlType::U16 => json!(u16::from_le_bytes(take(data, 2)?.try_into().ok()?)),
+        IdlType::U32 => json!(u32::from_le_bytes(take(data, 4)?.try_into().ok()?)),
+        IdlType::U64 => json!(u64::from_le_bytes(take(data, 8)?.try_into().ok()?)),
+        IdlType::I64 => json!(i64::from_le_bytes(take(data, 8)?.try_into().ok()?)),
+        IdlType::Pubkey => json!(Pubkey::new(take(data, 32)?).to_string()),
+        IdlType::String => {
+            let len = u32::from_le_bytes(take(data, 4)?.try_into().ok()?) as usize;
+            json!(std::str::from_utf8(take(data, len)?).ok()?)
+        }
+        IdlType::Bytes(len) => json!(take(data, *len)?),
+        IdlType::Option(ty) => match take(data, 1)?[0] {
+            0 => Value::Null,
+            1 => decode_value(types, ty, data)?,
+            _ => return None,
+        },
+        IdlType::Vec(ty) => {
+            let len = u32::from_le_bytes(take(data, 4)?.try_into().ok()?);
+            let mut values = Vec::new();
+            for _ in 0..len {
+                values.push(decode_value(types, ty, data)?);
+            }
+            Value::Array(values)
+        }
+        IdlType::Defined(name) => {
+            let (_, def) = types.iter().find(|(type_name, _)| type_name == name)?;
+            match def {
+                IdlTypeDef::Struct(fields) => decode_fields(types, fields, data)?,
+                IdlTypeDef::Enum(variants) => {
+                    let (name, fields) = variants.get(take(data, 1)?[0] as usize)?;
+                    match fields {
+                        IdlFields::Unit => json!(name),
+                        IdlFields::Tuple(tuple) => {
+                            let mut values = Vec::new();
+                            for ty in tuple {
+                                values.push(decode_value(types, ty, data)?);
+                            }
+                            variant(name, Value::Array(values))
+                        }
+                        IdlFields::Named(fields) => {
+                            variant(name, decode_fields(types, fields, data)?)
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Some(value)
+}
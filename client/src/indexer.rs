@@ -0,0 +1,610 @@
+use meep::{
+    state::{MeepSettings, MintRecord},
+    utils::Pda,
+};
+use metaplex_token_metadata::state::{
+    Metadata, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use rusqlite::{params, Connection, OptionalExtension};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    client_error::ClientError,
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_program::{borsh::try_from_slice_unchecked, program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Account;
+use std::{collections::HashSet, path::Path, str::FromStr};
+use thiserror::Error;
+
+/// Offset of the first creator address in a metadata account, Metaplex pads name,
+/// symbol and URI to their maximum length
+const METADATA_CREATORS_OFFSET: usize = 1
+    + 32
+    + 32
+    + (4 + MAX_NAME_LENGTH)
+    + (4 + MAX_SYMBOL_LENGTH)
+    + (4 + MAX_URI_LENGTH)
+    + 2
+    + 1
+    + 4;
+
+/// Creator address, verified flag and share
+const CREATOR_LEN: usize = 32 + 1 + 1;
+
+/// Accounts per getMultipleAccounts request
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS settings (
+    pubkey TEXT PRIMARY KEY,
+    authority TEXT NOT NULL,
+    original_authority TEXT NOT NULL,
+    secondary_creator TEXT NOT NULL,
+    collection_id INTEGER NOT NULL,
+    payment_mint TEXT,
+    data BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS settings_authority ON settings (authority);
+CREATE INDEX IF NOT EXISTS settings_original_authority ON settings (original_authority);
+
+CREATE TABLE IF NOT EXISTS mints (
+    mint TEXT PRIMARY KEY,
+    settings TEXT NOT NULL,
+    minter TEXT NOT NULL,
+    reveal_index INTEGER,
+    revealed INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS mints_settings ON mints (settings);
+
+CREATE TABLE IF NOT EXISTS metadata (
+    mint TEXT PRIMARY KEY,
+    pubkey TEXT NOT NULL,
+    update_authority TEXT NOT NULL,
+    name TEXT NOT NULL,
+    symbol TEXT NOT NULL,
+    uri TEXT NOT NULL,
+    seller_fee_basis_points INTEGER NOT NULL,
+    primary_sale_happened INTEGER NOT NULL,
+    is_mutable INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS holders (
+    mint TEXT PRIMARY KEY,
+    token_account TEXT NOT NULL,
+    owner TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS holders_owner ON holders (owner);
+";
+
+const NFT_QUERY: &str = "
+SELECT mints.mint, mints.settings, metadata.name, metadata.symbol, metadata.uri,
+       holders.owner, holders.token_account
+FROM mints
+JOIN settings ON settings.pubkey = mints.settings
+JOIN metadata ON metadata.mint = mints.mint
+LEFT JOIN holders ON holders.mint = mints.mint
+";
+
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("RPC error: {0}")]
+    Rpc(#[from] ClientError),
+
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct RefreshStats {
+    pub settings: usize,
+    pub new_nfts: usize,
+    pub holders: usize,
+}
+
+pub struct IndexedSettings {
+    pub pubkey: Pubkey,
+    pub authority: Pubkey,
+    pub collection_id: u64,
+    pub settings: MeepSettings,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct IndexedNft {
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub owner: Option<Pubkey>,
+    pub token_account: Option<Pubkey>,
+}
+
+fn parse_pubkey(value: String) -> rusqlite::Result<Pubkey> {
+    Pubkey::from_str(&value).map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(err))
+    })
+}
+
+fn parse_optional_pubkey(value: Option<String>) -> rusqlite::Result<Option<Pubkey>> {
+    value.map(parse_pubkey).transpose()
+}
+
+fn trim_padding(value: &str) -> String {
+    value.trim_end_matches(char::from(0)).to_string()
+}
+
+/// `BurnNft` closes the mint record and the metadata
+fn delete_nft(db: &Connection, mint: &str) -> rusqlite::Result<()> {
+    db.execute("DELETE FROM mints WHERE mint = ?1", params![mint])?;
+    db.execute("DELETE FROM metadata WHERE mint = ?1", params![mint])?;
+    db.execute("DELETE FROM holders WHERE mint = ?1", params![mint])?;
+    Ok(())
+}
+
+/// The token account if it still holds the NFT of `mint`
+fn holding_account(data: Option<Vec<u8>>, mint: &Pubkey) -> Option<Account> {
+    Account::unpack(&data?)
+        .ok()
+        .filter(|account| account.mint == *mint && account.amount == 1)
+}
+
+/// Mirrors Meep settings, their NFTs and holders into SQLite
+pub struct Indexer {
+    client: RpcClient,
+    program_id: Pubkey,
+    db: Connection,
+}
+
+impl Indexer {
+    pub fn open(client: RpcClient, program_id: &Pubkey, path: &Path) -> Result<Self, IndexerError> {
+        Self::with_connection(client, program_id, Connection::open(path)?)
+    }
+
+    pub fn open_in_memory(client: RpcClient, program_id: &Pubkey) -> Result<Self, IndexerError> {
+        Self::with_connection(client, program_id, Connection::open_in_memory()?)
+    }
+
+    fn with_connection(
+        client: RpcClient,
+        program_id: &Pubkey,
+        db: Connection,
+    ) -> Result<Self, IndexerError> {
+        db.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            client,
+            program_id: *program_id,
+            db,
+        })
+    }
+
+    /// Loads all settings and the NFTs minted since the last refresh, or every NFT again if
+    /// `full` is set, e.g. to pick up metadata updates and reveals. Burned NFTs are dropped
+    /// and holders are always reloaded
+    pub fn refresh(&mut self, full: bool) -> Result<RefreshStats, IndexerError> {
+        let mut stats = RefreshStats::default();
+
+        let settings = self.fetch_settings()?;
+        stats.settings = settings.len();
+
+        for (pubkey, settings, data) in settings.iter() {
+            self.store_settings(pubkey, settings, data)?;
+            stats.new_nfts += self.refresh_nfts(pubkey, settings, full)?;
+        }
+
+        stats.holders = self.refresh_holders()?;
+        Ok(stats)
+    }
+
+    /// Settings accounts with their raw data
+    fn fetch_settings(&self) -> Result<Vec<(Pubkey, MeepSettings, Vec<u8>)>, IndexerError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::DataSize(MeepSettings::LEN as u64)]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        };
+
+        let accounts = self
+            .client
+            .get_program_accounts_with_config(&self.program_id, config)?;
+
+        Ok(accounts
+            .into_iter()
+            .filter_map(|(pubkey, account)| {
+                let settings = try_from_slice_unchecked(&account.data).ok()?;
+                Some((pubkey, settings, account.data))
+            })
+            .collect())
+    }
+
+    fn store_settings(
+        &self,
+        pubkey: &Pubkey,
+        settings: &MeepSettings,
+        data: &[u8],
+    ) -> Result<(), IndexerError> {
+        self.db.execute(
+            "INSERT INTO settings
+                (pubkey, authority, original_authority, secondary_creator, collection_id,
+                 payment_mint, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT (pubkey) DO UPDATE SET
+                authority = excluded.authority,
+                payment_mint = excluded.payment_mint,
+                data = excluded.data",
+            params![
+                pubkey.to_string(),
+                settings.authority.to_string(),
+                settings.original_authority.to_string(),
+                settings.secondary_creator.to_string(),
+                settings.collection_id as i64,
+                settings.payment_mint.map(|mint| mint.to_string()),
+                data,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Metadata accounts whose first two creators are those of `settings`. The primary
+    /// creator is the authority at the time of minting, so both authorities are searched
+    fn fetch_metadata_pubkeys(&self, settings: &MeepSettings) -> Result<Vec<Pubkey>, ClientError> {
+        let mut authorities = vec![settings.authority];
+        if settings.original_authority != settings.authority {
+            authorities.push(settings.original_authority);
+        }
+
+        let mut pubkeys = Vec::new();
+        for authority in authorities {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::Memcmp(Memcmp {
+                        offset: METADATA_CREATORS_OFFSET,
+                        bytes: MemcmpEncodedBytes::Binary(authority.to_string()),
+                        encoding: None,
+                    }),
+                    RpcFilterType::Memcmp(Memcmp {
+                        offset: METADATA_CREATORS_OFFSET + CREATOR_LEN,
+                        bytes: MemcmpEncodedBytes::Binary(settings.secondary_creator.to_string()),
+                        encoding: None,
+                    }),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(UiDataSliceConfig {
+                        offset: 0,
+                        length: 0,
+                    }),
+                    ..RpcAccountInfoConfig::default()
+                },
+                with_context: None,
+            };
+
+            let accounts = self
+                .client
+                .get_program_accounts_with_config(&metaplex_token_metadata::ID, config)?;
+            pubkeys.extend(accounts.into_iter().map(|(pubkey, _)| pubkey));
+        }
+
+        Ok(pubkeys)
+    }
+
+    fn get_multiple_accounts_data(
+        &self,
+        pubkeys: &[Pubkey],
+        owner: &Pubkey,
+    ) -> Result<Vec<Option<Vec<u8>>>, ClientError> {
+        let mut data = Vec::with_capacity(pubkeys.len());
+        for chunk in pubkeys.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+            data.extend(
+                self.client
+                    .get_multiple_accounts(chunk)?
+                    .into_iter()
+                    .map(|account| {
+                        account
+                            .filter(|account| account.owner == *owner)
+                            .map(|account| account.data)
+                    }),
+            );
+        }
+
+        Ok(data)
+    }
+
+    fn known_metadata(&self) -> Result<HashSet<String>, IndexerError> {
+        let mut statement = self.db.prepare("SELECT pubkey FROM metadata")?;
+        let pubkeys = statement
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<HashSet<String>>>()?;
+        Ok(pubkeys)
+    }
+
+    /// Drops indexed NFTs of `settings` whose mint record is closed. Incremental refreshes
+    /// skip known metadata, so the mint records are the only place a burn shows up
+    fn prune_burned_nfts(&mut self, settings_pubkey: &Pubkey) -> Result<(), IndexerError> {
+        let mints = {
+            let mut statement = self
+                .db
+                .prepare("SELECT mint FROM mints WHERE settings = ?1")?;
+            let mints = statement
+                .query_map(params![settings_pubkey.to_string()], |row| {
+                    parse_pubkey(row.get(0)?)
+                })?
+                .collect::<rusqlite::Result<Vec<Pubkey>>>()?;
+            mints
+        };
+
+        let mint_records: Vec<Pubkey> = mints
+            .iter()
+            .map(|mint| Pda::mint_record_pubkey_with_bump(&self.program_id, mint).0)
+            .collect();
+        let mint_records = self.get_multiple_accounts_data(&mint_records, &self.program_id)?;
+
+        let transaction = self.db.transaction()?;
+        for (mint, mint_record) in mints.iter().zip(mint_records) {
+            if mint_record.is_none() {
+                delete_nft(&transaction, &mint.to_string())?;
+            }
+        }
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    /// Stores the NFTs of `settings`, returns how many weren't indexed before
+    fn refresh_nfts(
+        &mut self,
+        settings_pubkey: &Pubkey,
+        settings: &MeepSettings,
+        full: bool,
+    ) -> Result<usize, IndexerError> {
+        if !full {
+            self.prune_burned_nfts(settings_pubkey)?;
+        }
+
+        let known = self.known_metadata()?;
+        let metadata_pubkeys: Vec<Pubkey> = self
+            .fetch_metadata_pubkeys(settings)?
+            .into_iter()
+            .filter(|pubkey| full || !known.contains(&pubkey.to_string()))
+            .collect();
+
+        let metadata: Vec<(Pubkey, Metadata)> = self
+            .get_multiple_accounts_data(&metadata_pubkeys, &metaplex_token_metadata::ID)?
+            .into_iter()
+            .zip(metadata_pubkeys)
+            .filter_map(|(data, pubkey)| Some((pubkey, try_from_slice_unchecked(&data?).ok()?)))
+            .collect();
+
+        // Creators alone don't tell collections of the same authority and secondary
+        // creator apart, the mint record does
+        let mint_records: Vec<Pubkey> = metadata
+            .iter()
+            .map(|(_, metadata)| {
+                Pda::mint_record_pubkey_with_bump(&self.program_id, &metadata.mint).0
+            })
+            .collect();
+        let mint_records = self.get_multiple_accounts_data(&mint_records, &self.program_id)?;
+
+        let transaction = self.db.transaction()?;
+        let mut new_nfts = 0;
+        for ((pubkey, metadata), mint_record) in metadata.iter().zip(mint_records) {
+            let mint_record: MintRecord =
+                match mint_record.and_then(|data| try_from_slice_unchecked(&data).ok()) {
+                    Some(mint_record) => mint_record,
+                    None => {
+                        delete_nft(&transaction, &metadata.mint.to_string())?;
+                        continue;
+                    }
+                };
+            if mint_record.settings != *settings_pubkey {
+                continue;
+            }
+
+            let mint = metadata.mint.to_string();
+            transaction.execute(
+                "INSERT OR REPLACE INTO mints (mint, settings, minter, reveal_index, revealed)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    mint,
+                    settings_pubkey.to_string(),
+                    mint_record.minter.to_string(),
+                    mint_record.reveal_index,
+                    mint_record.revealed,
+                ],
+            )?;
+            let replaced = transaction
+                .query_row(
+                    "SELECT 1 FROM metadata WHERE mint = ?1",
+                    params![mint],
+                    |_| Ok(()),
+                )
+                .optional()?
+                .is_some();
+            transaction.execute(
+                "INSERT OR REPLACE INTO metadata
+                    (mint, pubkey, update_authority, name, symbol, uri, seller_fee_basis_points,
+                     primary_sale_happened, is_mutable)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    mint,
+                    pubkey.to_string(),
+                    metadata.update_authority.to_string(),
+                    trim_padding(&metadata.data.name),
+                    trim_padding(&metadata.data.symbol),
+                    trim_padding(&metadata.data.uri),
+                    metadata.data.seller_fee_basis_points,
+                    metadata.primary_sale_happened,
+                    metadata.is_mutable,
+                ],
+            )?;
+
+            if !replaced {
+                new_nfts += 1;
+            }
+        }
+        transaction.commit()?;
+
+        Ok(new_nfts)
+    }
+
+    /// Reloads the token account holding each indexed NFT and its owner. Known token
+    /// accounts are fetched in batches, only NFTs that left theirs are looked up by mint
+    fn refresh_holders(&mut self) -> Result<usize, IndexerError> {
+        let indexed = {
+            let mut statement = self.db.prepare(
+                "SELECT mints.mint, holders.token_account FROM mints
+                 LEFT JOIN holders ON holders.mint = mints.mint",
+            )?;
+            let indexed = statement
+                .query_map([], |row| {
+                    Ok((
+                        parse_pubkey(row.get(0)?)?,
+                        parse_optional_pubkey(row.get(1)?)?,
+                    ))
+                })?
+                .collect::<rusqlite::Result<Vec<(Pubkey, Option<Pubkey>)>>>()?;
+            indexed
+        };
+
+        let known_pubkeys: Vec<Pubkey> = indexed
+            .iter()
+            .filter_map(|(_, token_account)| *token_account)
+            .collect();
+        let mut known_data = self
+            .get_multiple_accounts_data(&known_pubkeys, &spl_token::ID)?
+            .into_iter();
+
+        let mut held: Vec<(Pubkey, Pubkey, Account)> = Vec::with_capacity(indexed.len());
+        let mut moved = Vec::new();
+        for (mint, token_account) in indexed {
+            let holder = token_account.and_then(|token_account| {
+                let account = holding_account(known_data.next().flatten(), &mint)?;
+                Some((token_account, account))
+            });
+
+            match holder {
+                Some((token_account, account)) => held.push((mint, token_account, account)),
+                None => moved.push(mint),
+            }
+        }
+
+        let mut moved_holders = Vec::with_capacity(moved.len());
+        for mint in moved {
+            let token_account = self
+                .client
+                .get_token_largest_accounts(&mint)?
+                .into_iter()
+                .find(|balance| balance.amount.amount == "1")
+                .and_then(|balance| Pubkey::from_str(&balance.address).ok());
+            if let Some(token_account) = token_account {
+                moved_holders.push((mint, token_account));
+            }
+        }
+
+        let moved_pubkeys: Vec<Pubkey> = moved_holders
+            .iter()
+            .map(|(_, token_account)| *token_account)
+            .collect();
+        let moved_data = self.get_multiple_accounts_data(&moved_pubkeys, &spl_token::ID)?;
+        for ((mint, token_account), data) in moved_holders.into_iter().zip(moved_data) {
+            if let Some(account) = holding_account(data, &mint) {
+                held.push((mint, token_account, account));
+            }
+        }
+
+        let transaction = self.db.transaction()?;
+        transaction.execute("DELETE FROM holders", [])?;
+        for (mint, token_account, account) in held.iter() {
+            transaction.execute(
+                "INSERT INTO holders (mint, token_account, owner) VALUES (?1, ?2, ?3)",
+                params![
+                    mint.to_string(),
+                    token_account.to_string(),
+                    account.owner.to_string()
+                ],
+            )?;
+        }
+        transaction.commit()?;
+
+        Ok(held.len())
+    }
+
+    /// Indexed configurations of `authority`, including those it rotated away
+    pub fn settings_by_authority(
+        &self,
+        authority: &Pubkey,
+    ) -> Result<Vec<IndexedSettings>, IndexerError> {
+        let mut statement = self.db.prepare(
+            "SELECT pubkey, authority, collection_id, data FROM settings
+             WHERE authority = ?1 OR original_authority = ?1
+             ORDER BY collection_id",
+        )?;
+
+        let settings = statement
+            .query_map(params![authority.to_string()], |row| {
+                let data: Vec<u8> = row.get(3)?;
+                let settings = try_from_slice_unchecked(&data).map_err(|err| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        3,
+                        rusqlite::types::Type::Blob,
+                        Box::new(err),
+                    )
+                })?;
+
+                Ok(IndexedSettings {
+                    pubkey: parse_pubkey(row.get(0)?)?,
+                    authority: parse_pubkey(row.get(1)?)?,
+                    collection_id: row.get::<_, i64>(2)? as u64,
+                    settings,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<IndexedSettings>>>()?;
+
+        Ok(settings)
+    }
+
+    fn query_nfts(&self, filter: &str, value: &Pubkey) -> Result<Vec<IndexedNft>, IndexerError> {
+        let mut statement = self.db.prepare(&format!(
+            "{} WHERE {} ORDER BY mints.mint",
+            NFT_QUERY, filter
+        ))?;
+
+        let nfts = statement
+            .query_map(params![value.to_string()], |row| {
+                Ok(IndexedNft {
+                    mint: parse_pubkey(row.get(0)?)?,
+                    settings: parse_pubkey(row.get(1)?)?,
+                    name: row.get(2)?,
+                    symbol: row.get(3)?,
+                    uri: row.get(4)?,
+                    owner: parse_optional_pubkey(row.get(5)?)?,
+                    token_account: parse_optional_pubkey(row.get(6)?)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<IndexedNft>>>()?;
+
+        Ok(nfts)
+    }
+
+    /// NFTs of all configurations of `authority`
+    pub fn nfts_by_authority(&self, authority: &Pubkey) -> Result<Vec<IndexedNft>, IndexerError> {
+        self.query_nfts(
+            "settings.authority = ?1 OR settings.original_authority = ?1",
+            authority,
+        )
+    }
+
+    pub fn nfts_by_settings(&self, settings: &Pubkey) -> Result<Vec<IndexedNft>, IndexerError> {
+        self.query_nfts("mints.settings = ?1", settings)
+    }
+
+    pub fn nfts_by_owner(&self, owner: &Pubkey) -> Result<Vec<IndexedNft>, IndexerError> {
+        self.query_nfts("holders.owner = ?1", owner)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod accounts;
+pub mod decoder;
+pub mod errors;
+pub mod events;
+pub mod idl;
+pub mod indexer;
+pub mod treasury;
@@ -0,0 +1,62 @@
+use meep::utils::Pda;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Account;
+use std::collections::BTreeMap;
+
+/// Offset of `Account::owner` in SPL token account data
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+pub struct TreasuryBalances {
+    pub treasury: Pubkey,
+    pub lamports: u64,
+
+    /// Total amount per mint over all token accounts owned by the treasury
+    pub tokens: BTreeMap<Pubkey, u64>,
+}
+
+/// Lamports and token balances of the treasury of `settings`
+pub fn get_treasury_balances(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    settings: &Pubkey,
+) -> Result<TreasuryBalances, ClientError> {
+    let treasury = Pda::treasury_pubkey_with_bump(program_id, settings).0;
+    let lamports = client.get_balance(&treasury)?;
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(Account::LEN as u64),
+            RpcFilterType::Memcmp(Memcmp {
+                offset: TOKEN_ACCOUNT_OWNER_OFFSET,
+                bytes: MemcmpEncodedBytes::Binary(treasury.to_string()),
+                encoding: None,
+            }),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        with_context: None,
+    };
+
+    let accounts = client.get_program_accounts_with_config(&spl_token::ID, config)?;
+    let mut tokens = BTreeMap::new();
+    for (_, account) in accounts {
+        if let Ok(token_account) = Account::unpack(&account.data) {
+            *tokens.entry(token_account.mint).or_insert(0) += token_account.amount;
+        }
+    }
+
+    Ok(TreasuryBalances {
+        treasury,
+        lamports,
+        tokens,
+    })
+}
@@ -0,0 +1,170 @@
+use meep::{
+    instruction::{InitializeMeepArgs, MeepInstructions, MintNftArgs, SettingsArgs},
+    utils::Pda,
+};
+use meep_client::decoder::{
+    decode_message, MINT, MINTER, MINT_RECORD, SETTINGS, SIGNER_SET_MEMBER, STAKE_ACCOUNT,
+    STAKE_RECORD, TOKEN_METADATA, TREASURY,
+};
+use solana_program::{message::Message, pubkey::Pubkey};
+
+fn get_settings_args() -> SettingsArgs {
+    SettingsArgs {
+        primary_wallet_percentage: 50,
+        minimum_price: 0,
+        treasury_fee_basis_points: 0,
+        treasury_mint_fee: 0,
+        settings_delay: 0,
+    }
+}
+
+#[test]
+fn decode_mint() {
+    let authority = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mint_args = MintNftArgs {
+        seller_fee_basis_points: 500,
+        token_name: "Meep".to_string(),
+        token_symbol: "MEEP".to_string(),
+        uri: "https://test.com".to_string(),
+        is_mutable: false,
+        mint_record_bump: 0,
+    };
+
+    let mut ix = MeepInstructions::mint_nft(
+        &meep::ID,
+        &authority,
+        0,
+        &authority,
+        &Pubkey::new_unique(),
+        true,
+        &mint,
+        &Pubkey::new_unique(),
+        &mint_args,
+    );
+    let member = Pubkey::new_unique();
+    MeepInstructions::append_signers(&mut ix, &[member]);
+
+    let message = Message::new(&[ix.clone()], Some(&authority));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    assert_eq!(decoded.len(), 1);
+
+    let decoded = decoded[0].as_ref().unwrap();
+    assert!(matches!(decoded.instruction, MeepInstructions::MintNft(_)));
+    assert_eq!(decoded.accounts[0].role, MINTER);
+    assert!(decoded.accounts[0].is_signer);
+    assert_eq!(decoded.accounts[2].role, SETTINGS);
+    assert_eq!(decoded.accounts[3].role, MINT);
+    assert_eq!(decoded.accounts[3].pubkey, mint);
+    assert_eq!(decoded.accounts[15].role, SIGNER_SET_MEMBER);
+
+    // only the unloaded settings are reported
+    assert_eq!(decoded.mismatches.len(), 1);
+
+    // PDAs are derived from the settings and mint accounts
+    ix.accounts[14].pubkey = Pubkey::new_unique();
+    let message = Message::new(&[ix], Some(&authority));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    let decoded = decoded[0].as_ref().unwrap();
+    assert_eq!(decoded.accounts[14].role, TREASURY);
+    assert!(decoded
+        .mismatches
+        .iter()
+        .any(|mismatch| mismatch.starts_with(TREASURY)));
+}
+
+#[test]
+fn decode_wrong_settings() {
+    let authority = Pubkey::new_unique();
+    let secondary_creator = Pubkey::new_unique();
+    let args = InitializeMeepArgs {
+        collection_id: 7,
+        settings: get_settings_args(),
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: false,
+    };
+
+    let mut ix =
+        MeepInstructions::initialize_meep(&meep::ID, &authority, &secondary_creator, &args);
+    let message = Message::new(&[ix.clone()], Some(&authority));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    assert!(decoded[0].as_ref().unwrap().mismatches.is_empty());
+
+    // settings of another collection id
+    ix.accounts[2].pubkey = Pda::settings_pubkey_with_bump(&meep::ID, &authority, 8).0;
+    let message = Message::new(&[ix], Some(&authority));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    let mismatches = &decoded[0].as_ref().unwrap().mismatches;
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].starts_with(SETTINGS));
+}
+
+#[test]
+fn decode_stake() {
+    let authority = Pubkey::new_unique();
+    let staker = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut ix = MeepInstructions::stake(
+        &meep::ID,
+        &authority,
+        0,
+        &staker,
+        &mint,
+        &Pubkey::new_unique(),
+    );
+    let message = Message::new(&[ix.clone()], Some(&staker));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    let decoded = decoded[0].as_ref().unwrap();
+    assert!(matches!(decoded.instruction, MeepInstructions::Stake(_)));
+    assert_eq!(decoded.accounts[2].role, STAKE_RECORD);
+    assert_eq!(decoded.accounts[3].role, STAKE_ACCOUNT);
+    assert_eq!(decoded.mismatches.len(), 1);
+
+    // stake accounts of another mint
+    let other_mint = Pubkey::new_unique();
+    ix.accounts[3].pubkey = Pda::stake_account_pubkey_with_bump(&meep::ID, &other_mint).0;
+    let message = Message::new(&[ix], Some(&staker));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    assert!(decoded[0]
+        .as_ref()
+        .unwrap()
+        .mismatches
+        .iter()
+        .any(|mismatch| mismatch.starts_with(STAKE_ACCOUNT)));
+}
+
+#[test]
+fn decode_sign_as_secondary() {
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let mut ix = MeepInstructions::sign_as_secondary(&meep::ID, &authority, 0, &signer, &mints);
+    let message = Message::new(&[ix.clone()], Some(&signer));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    let decoded = decoded[0].as_ref().unwrap();
+    assert!(matches!(
+        decoded.instruction,
+        MeepInstructions::SignAsSecondary
+    ));
+    for (i, mint) in mints.iter().enumerate() {
+        assert_eq!(decoded.accounts[4 + 3 * i].role, MINT);
+        assert_eq!(decoded.accounts[4 + 3 * i].pubkey, *mint);
+        assert_eq!(decoded.accounts[5 + 3 * i].role, TOKEN_METADATA);
+        assert_eq!(decoded.accounts[6 + 3 * i].role, MINT_RECORD);
+    }
+    assert_eq!(decoded.mismatches.len(), 1);
+
+    // the second NFT's mint record is checked against its own mint
+    ix.accounts[9].pubkey = Pda::mint_record_pubkey_with_bump(&meep::ID, &mints[0]).0;
+    let message = Message::new(&[ix], Some(&signer));
+    let decoded = decode_message(&meep::ID, &message, &|_| None);
+    let mismatches = &decoded[0].as_ref().unwrap().mismatches;
+    assert_eq!(mismatches.len(), 2);
+    assert!(mismatches
+        .iter()
+        .any(|mismatch| mismatch.starts_with(MINT_RECORD)));
+}
@@ -0,0 +1,120 @@
+use meep::{
+    error::MeepError,
+    instruction::{InitializeMeepArgs, MeepInstructions, SettingsArgs},
+};
+use meep_client::errors::{decode_client_error, decode_transaction_error, CustomError};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::error::TokenError;
+
+fn get_initialize_tx(authority: &Keypair, secondary_creator: &Pubkey) -> Transaction {
+    let args = InitializeMeepArgs {
+        collection_id: 0,
+        settings: SettingsArgs {
+            primary_wallet_percentage: 50,
+            minimum_price: 0,
+            treasury_fee_basis_points: 0,
+            treasury_mint_fee: 0,
+            settings_delay: 0,
+        },
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: false,
+    };
+
+    let ix =
+        MeepInstructions::initialize_meep(&meep::ID, &authority.pubkey(), secondary_creator, &args);
+    Transaction::new_with_payer(&[ix], Some(&authority.pubkey()))
+}
+
+fn custom_error(code: u32) -> TransactionError {
+    TransactionError::InstructionError(0, InstructionError::Custom(code))
+}
+
+#[test]
+fn decode_errors() {
+    let tx = get_initialize_tx(&Keypair::new(), &Pubkey::new_unique());
+
+    // without logs the error belongs to the failed instruction's program
+    assert_eq!(
+        decode_transaction_error(&meep::ID, &tx, &custom_error(4), None),
+        Some(CustomError::Meep(MeepError::WrongPrimaryCreator))
+    );
+
+    let logs = vec![
+        format!("Program {} invoke [1]", meep::ID),
+        format!("Program {} invoke [2]", spl_token::ID),
+        "Program log: Error: insufficient funds".to_string(),
+        format!(
+            "Program {} failed: custom program error: 0x1",
+            spl_token::ID
+        ),
+        format!("Program {} failed: custom program error: 0x1", meep::ID),
+    ];
+    assert_eq!(
+        decode_transaction_error(&meep::ID, &tx, &custom_error(1), Some(&logs)),
+        Some(CustomError::Token(TokenError::InsufficientFunds))
+    );
+
+    let other = Pubkey::new_unique();
+    let logs = vec![format!(
+        "Program {} failed: custom program error: 0x7",
+        other
+    )];
+    assert_eq!(
+        decode_transaction_error(&meep::ID, &tx, &custom_error(7), Some(&logs)),
+        Some(CustomError::Unknown {
+            program_id: other,
+            code: 7
+        })
+    );
+
+    // codes the program doesn't know aren't mistaken for its errors
+    assert_eq!(
+        decode_transaction_error(&meep::ID, &tx, &custom_error(10_000), None),
+        Some(CustomError::Unknown {
+            program_id: meep::ID,
+            code: 10_000
+        })
+    );
+
+    assert_eq!(
+        decode_transaction_error(
+            &meep::ID,
+            &tx,
+            &TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
+            None
+        ),
+        None
+    );
+}
+
+#[test]
+fn decode_preflight_error() {
+    let client = RpcClient::new_with_commitment(
+        "http://localhost:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let authority = Keypair::new();
+    let signature = client
+        .request_airdrop(&authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    client.poll_for_signature(&signature).unwrap();
+
+    let mut tx = get_initialize_tx(&authority, &authority.pubkey());
+    tx.sign(&[&authority], client.get_recent_blockhash().unwrap().0);
+
+    let error = client.send_and_confirm_transaction(&tx).unwrap_err();
+    assert_eq!(
+        decode_client_error(&meep::ID, &tx, &error),
+        Some(CustomError::Meep(MeepError::PrimaryAndSecondaryAreSame))
+    );
+}
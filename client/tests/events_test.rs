@@ -0,0 +1,110 @@
+use borsh::BorshSerialize;
+use meep::{
+    event::MeepEvent,
+    instruction::{InitializeMeepArgs, MeepInstructions, SettingsArgs},
+    utils::Pda,
+};
+use meep_client::events::{decode_events, get_transaction_events};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+fn get_settings_args() -> SettingsArgs {
+    SettingsArgs {
+        primary_wallet_percentage: 50,
+        minimum_price: 1_000,
+        treasury_fee_basis_points: 250,
+        treasury_mint_fee: 0,
+        settings_delay: 0,
+    }
+}
+
+#[test]
+fn decode_logged_events() {
+    let event = MeepEvent::SettingsUpdated {
+        settings: Pubkey::new_unique(),
+        authority: Pubkey::new_unique(),
+        args: get_settings_args(),
+        effective_at: 42,
+    };
+    let data = base64::encode(event.try_to_vec().unwrap());
+    let other_program = Pubkey::new_unique();
+
+    let logs = vec![
+        format!("Program {} invoke [1]", meep::ID),
+        format!("Program {} invoke [2]", other_program),
+        format!("Program data: {}", data),
+        format!("Program {} success", other_program),
+        "Program log: Create metadata account".to_string(),
+        format!("Program data: {}", data),
+        format!(
+            "Program {} consumed 20000 of 200000 compute units",
+            meep::ID
+        ),
+        format!("Program {} success", meep::ID),
+    ];
+
+    // data of the inner program is skipped
+    assert_eq!(decode_events(&meep::ID, &logs), vec![event]);
+    assert_eq!(decode_events(&other_program, &logs).len(), 1);
+}
+
+#[test]
+fn initialize_event() {
+    let client = RpcClient::new_with_commitment(
+        "http://localhost:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let authority = Keypair::new();
+    let signature = client
+        .request_airdrop(&authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    client.poll_for_signature(&signature).unwrap();
+
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_settings_args(),
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: false,
+    };
+
+    let ix = MeepInstructions::initialize_meep(
+        &meep::ID,
+        &authority.pubkey(),
+        &secondary_creator.pubkey(),
+        &args,
+    );
+
+    let blockhash = client.get_recent_blockhash().unwrap().0;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[&authority, &secondary_creator],
+        blockhash,
+    );
+    let signature = client.send_and_confirm_transaction(&tx).unwrap();
+
+    let events = get_transaction_events(&client, &meep::ID, &signature).unwrap();
+    assert_eq!(
+        events,
+        vec![MeepEvent::Initialized {
+            settings: Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id)
+                .0,
+            authority: authority.pubkey(),
+            secondary_creator: secondary_creator.pubkey(),
+            collection_id,
+            args: get_settings_args(),
+            pda_authority: false,
+            royalty_vault: false,
+            payment_mint: None,
+            membership: false,
+        }]
+    );
+}
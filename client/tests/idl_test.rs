@@ -0,0 +1,409 @@
+use borsh::BorshSerialize;
+use meep::{
+    error::MeepError,
+    event::MeepEvent,
+    instruction::{
+        CastVoteArgs, CreateProposalArgs, GovernanceArgs, InitializeMeepArgs, MeepInstructions,
+        MintApprovalArgs, MintNftArgs, ProposalAction, RevealArgs, RevealModeArgs, SettingsArgs,
+        StakeArgs, StakingArgs, TreasuryTransferArgs, UnstakeArgs, UpdateNftMetadataArgs,
+        UpdateSignersArgs,
+    },
+    state::{
+        GovernanceConfig, MeepSettings, MintRecord, PdaBumps, PendingSettings, Proposal,
+        ProposalState, StakeRecord, StakingConfig,
+    },
+};
+use meep_client::{
+    decoder::INSTRUCTIONS,
+    idl::{accounts, decode_value, errors, generate_idl, type_defs, IdlType},
+};
+use serde_json::{json, Value};
+use solana_program::pubkey::Pubkey;
+
+fn get_settings_args() -> SettingsArgs {
+    SettingsArgs {
+        primary_wallet_percentage: 50,
+        minimum_price: 1_000,
+        treasury_fee_basis_points: 250,
+        treasury_mint_fee: 10,
+        settings_delay: 3_600,
+    }
+}
+
+// Exhaustive on purpose, a new instruction doesn't compile until it's described
+fn variant_name(instruction: &MeepInstructions) -> &'static str {
+    match instruction {
+        MeepInstructions::InitializeMeep(_) => "InitializeMeep",
+        MeepInstructions::UpdateSettings(_) => "UpdateSettings",
+        MeepInstructions::MintNft(_) => "MintNft",
+        MeepInstructions::EnableRevealMode(_) => "EnableRevealMode",
+        MeepInstructions::Reveal(_) => "Reveal",
+        MeepInstructions::AddDelegatedMinter => "AddDelegatedMinter",
+        MeepInstructions::RevokeDelegatedMinter => "RevokeDelegatedMinter",
+        MeepInstructions::GrantMintApproval(_) => "GrantMintApproval",
+        MeepInstructions::RevokeMintApproval => "RevokeMintApproval",
+        MeepInstructions::SignAsSecondary => "SignAsSecondary",
+        MeepInstructions::UpdateNftMetadata(_) => "UpdateNftMetadata",
+        MeepInstructions::DistributeRoyalties => "DistributeRoyalties",
+        MeepInstructions::Deposit(_) => "Deposit",
+        MeepInstructions::Withdraw(_) => "Withdraw",
+        MeepInstructions::ConfigureGovernance(_) => "ConfigureGovernance",
+        MeepInstructions::CreateProposal(_) => "CreateProposal",
+        MeepInstructions::CastVote(_) => "CastVote",
+        MeepInstructions::FinalizeProposal => "FinalizeProposal",
+        MeepInstructions::ExecuteProposal => "ExecuteProposal",
+        MeepInstructions::UpdateSigners(_) => "UpdateSigners",
+        MeepInstructions::ApplyPendingSettings => "ApplyPendingSettings",
+        MeepInstructions::VetoPendingSettings => "VetoPendingSettings",
+        MeepInstructions::BurnNft => "BurnNft",
+        MeepInstructions::RevokeMembership => "RevokeMembership",
+        MeepInstructions::RecoverMembership => "RecoverMembership",
+        MeepInstructions::ConfigureStaking(_) => "ConfigureStaking",
+        MeepInstructions::Stake(_) => "Stake",
+        MeepInstructions::Unstake(_) => "Unstake",
+        MeepInstructions::ClaimRewards => "ClaimRewards",
+    }
+}
+
+fn get_instructions() -> Vec<MeepInstructions> {
+    vec![
+        MeepInstructions::InitializeMeep(InitializeMeepArgs {
+            collection_id: 7,
+            settings: get_settings_args(),
+            pda_authority: true,
+            royalty_vault: false,
+            payment_mint: Some(Pubkey::new_unique()),
+            membership: false,
+        }),
+        MeepInstructions::UpdateSettings(get_settings_args()),
+        MeepInstructions::MintNft(MintNftArgs {
+            seller_fee_basis_points: 500,
+            token_name: "Meep".to_string(),
+            token_symbol: "MEEP".to_string(),
+            uri: "https://test.com".to_string(),
+            is_mutable: true,
+            mint_record_bump: 0,
+        }),
+        MeepInstructions::EnableRevealMode(RevealModeArgs {
+            placeholder_uri: "https://test.com/hidden".to_string(),
+            uri_commitment: [3; 32],
+            uri_count: 10,
+        }),
+        MeepInstructions::Reveal(RevealArgs {
+            uri: "https://test.com/1".to_string(),
+            proof: vec![[1; 32], [2; 32]],
+        }),
+        MeepInstructions::AddDelegatedMinter,
+        MeepInstructions::RevokeDelegatedMinter,
+        MeepInstructions::GrantMintApproval(MintApprovalArgs {
+            max_mints: Some(10),
+            expires_at: None,
+            delegate: None,
+        }),
+        MeepInstructions::RevokeMintApproval,
+        MeepInstructions::SignAsSecondary,
+        MeepInstructions::UpdateNftMetadata(UpdateNftMetadataArgs {
+            token_name: None,
+            uri: Some("https://test.com/2".to_string()),
+            seller_fee_basis_points: Some(100),
+            lock: false,
+        }),
+        MeepInstructions::DistributeRoyalties,
+        MeepInstructions::Deposit(TreasuryTransferArgs { amount: 5 }),
+        MeepInstructions::Withdraw(TreasuryTransferArgs { amount: 6 }),
+        MeepInstructions::ConfigureGovernance(GovernanceArgs {
+            quorum: 3,
+            threshold_percentage: 51,
+            voting_period: 600,
+            execution_delay: 60,
+        }),
+        MeepInstructions::CreateProposal(CreateProposalArgs {
+            description_uri: "https://test.com/proposal".to_string(),
+            action: ProposalAction::TreasuryTransfer {
+                recipient: Pubkey::new_unique(),
+                amount: 100,
+                treasury_token_account: None,
+            },
+        }),
+        MeepInstructions::CastVote(CastVoteArgs { approve: true }),
+        MeepInstructions::FinalizeProposal,
+        MeepInstructions::ExecuteProposal,
+        MeepInstructions::UpdateSigners(UpdateSignersArgs {
+            signers: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            threshold: 2,
+        }),
+        MeepInstructions::ApplyPendingSettings,
+        MeepInstructions::VetoPendingSettings,
+        MeepInstructions::BurnNft,
+        MeepInstructions::RevokeMembership,
+        MeepInstructions::RecoverMembership,
+        MeepInstructions::ConfigureStaking(StakingArgs { reward_rate: 10 }),
+        MeepInstructions::Stake(StakeArgs {
+            stake_record_bump: 254,
+            stake_account_bump: 253,
+        }),
+        MeepInstructions::Unstake(UnstakeArgs {
+            forfeit_rewards: true,
+        }),
+        MeepInstructions::ClaimRewards,
+    ]
+}
+
+fn decode_all(name: &'static str, data: &[u8]) -> Value {
+    let types = type_defs();
+    let mut data = data;
+    let value = decode_value(&types, &IdlType::Defined(name), &mut data)
+        .unwrap_or_else(|| panic!("{} doesn't match its layout", name));
+    assert!(data.is_empty(), "{} has trailing bytes", name);
+    value
+}
+
+#[test]
+fn idl_instructions() {
+    let instructions = get_instructions();
+    assert_eq!(instructions.len(), INSTRUCTIONS.len());
+
+    for (discriminant, instruction) in instructions.iter().enumerate() {
+        let data = instruction.try_to_vec().unwrap();
+        let layout = &INSTRUCTIONS[discriminant];
+
+        assert_eq!(data[0] as usize, discriminant);
+        assert_eq!(layout.name, variant_name(instruction));
+
+        match layout.args {
+            Some(args) => {
+                decode_all(args, &data[1..]);
+            }
+            None => assert_eq!(data.len(), 1, "{} has args", layout.name),
+        }
+    }
+
+    let data = MeepInstructions::UpdateSettings(get_settings_args())
+        .try_to_vec()
+        .unwrap();
+    assert_eq!(
+        decode_all("SettingsArgs", &data[1..]),
+        json!({
+            "primary_wallet_percentage": 50,
+            "minimum_price": 1_000,
+            "treasury_fee_basis_points": 250,
+            "treasury_mint_fee": 10,
+            "settings_delay": 3_600,
+        })
+    );
+}
+
+#[test]
+fn idl_accounts() {
+    let authority = Pubkey::new_unique();
+    let signer = Pubkey::new_unique();
+    let settings = MeepSettings {
+        authority,
+        collection_id: 3,
+        secondary_creator: Pubkey::new_unique(),
+        primary_wallet_percentage: 60,
+        minimum_price: 0,
+        reveal: None,
+        delegated_minters: vec![Pubkey::new_unique()],
+        mint_approval: None,
+        pda_authority: false,
+        royalty_vault: true,
+        payment_mint: None,
+        treasury_fee_basis_points: 0,
+        treasury_mint_fee: 0,
+        governance: Some(GovernanceConfig {
+            quorum: 1,
+            threshold_percentage: 50,
+            voting_period: 10,
+            execution_delay: 0,
+        }),
+        proposal_count: 2,
+        original_authority: authority,
+        signers: vec![signer],
+        signer_threshold: 1,
+        settings_delay: 60,
+        pending_settings: Some(PendingSettings {
+            settings: get_settings_args(),
+            effective_at: 1_000,
+        }),
+        bump: 254,
+        membership: true,
+        staking: Some(StakingConfig {
+            reward_mint: Pubkey::new_unique(),
+            reward_rate: 25,
+            staked_count: 1,
+            reward_per_nft: 500,
+            updated_at: 1_000,
+        }),
+        bumps: PdaBumps {
+            authority: 255,
+            royalty_vault: 254,
+            treasury: 253,
+            governance: 252,
+            secondary_approval: 251,
+            locked: 249,
+        },
+    };
+
+    let value = decode_all("MeepSettings", &settings.try_to_vec().unwrap());
+    assert_eq!(value["authority"], json!(authority.to_string()));
+    assert_eq!(value["signers"], json!([signer.to_string()]));
+    assert_eq!(value["governance"]["quorum"], json!(1));
+    assert_eq!(
+        value["pending_settings"]["settings"]["settings_delay"],
+        json!(3_600)
+    );
+    assert_eq!(value["pending_settings"]["effective_at"], json!(1_000));
+    assert_eq!(value["staking"]["reward_rate"], json!(25));
+    assert_eq!(value["staking"]["reward_per_nft"], json!(500));
+    assert_eq!(value["bumps"]["secondary_approval"], json!(251));
+    assert_eq!(value["bumps"]["locked"], json!(249));
+
+    let mint_record = MintRecord {
+        settings: Pubkey::new_unique(),
+        minter: authority,
+        reveal_index: Some(3),
+        revealed: true,
+        bump: 250,
+        minted_at: 7,
+    };
+    let value = decode_all("MintRecord", &mint_record.try_to_vec().unwrap());
+    assert_eq!(value["reveal_index"], json!(3));
+    assert_eq!(value["bump"], json!(250));
+    assert_eq!(value["minted_at"], json!(7));
+
+    let proposal = Proposal {
+        settings: Pubkey::new_unique(),
+        proposer: Pubkey::new_unique(),
+        index: 0,
+        description_uri: "https://test.com/proposal".to_string(),
+        voting_ends_at: 100,
+        yes_votes: 1,
+        no_votes: 0,
+        state: ProposalState::Approved,
+        governance: GovernanceConfig {
+            quorum: 3,
+            threshold_percentage: 60,
+            voting_period: 10,
+            execution_delay: 5,
+        },
+        action: ProposalAction::UpdateSettings(get_settings_args()),
+        created_at: 90,
+        bump: 248,
+    };
+    let value = decode_all("Proposal", &proposal.try_to_vec().unwrap());
+    assert_eq!(value["state"], json!("Approved"));
+    assert_eq!(value["governance"]["execution_delay"], json!(5));
+    assert_eq!(value["created_at"], json!(90));
+    assert_eq!(value["bump"], json!(248));
+    assert_eq!(
+        value["action"]["UpdateSettings"][0]["primary_wallet_percentage"],
+        json!(50)
+    );
+
+    let stake_record = StakeRecord {
+        settings: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        staker: authority,
+        staked_at: 10,
+        claimed_at: 20,
+        reward_checkpoint: 30,
+        bump: 247,
+        stake_account_bump: 246,
+    };
+    let value = decode_all("StakeRecord", &stake_record.try_to_vec().unwrap());
+    assert_eq!(value["reward_checkpoint"], json!(30));
+    assert_eq!(value["stake_account_bump"], json!(246));
+
+    let event = MeepEvent::SettingsUpdated {
+        settings: Pubkey::new_unique(),
+        authority,
+        args: get_settings_args(),
+        effective_at: 42,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(value["SettingsUpdated"]["effective_at"], json!(42));
+
+    let mint = Pubkey::new_unique();
+    let event = MeepEvent::Burned {
+        settings: Pubkey::new_unique(),
+        holder: authority,
+        mint,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(value["Burned"]["mint"], json!(mint.to_string()));
+
+    let event = MeepEvent::MembershipRecovered {
+        settings: Pubkey::new_unique(),
+        mint,
+        from: Pubkey::new_unique(),
+        to: authority,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(
+        value["MembershipRecovered"]["to"],
+        json!(authority.to_string())
+    );
+
+    let event = MeepEvent::RewardsClaimed {
+        settings: Pubkey::new_unique(),
+        mint,
+        staker: authority,
+        reward_mint: Pubkey::new_unique(),
+        amount: 3_600,
+        claimed_at: 1_000,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(value["RewardsClaimed"]["amount"], json!(3_600));
+
+    let event = MeepEvent::SettingsVetoed {
+        settings: Pubkey::new_unique(),
+        secondary_creator: authority,
+        args: get_settings_args(),
+        effective_at: 42,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(
+        value["SettingsVetoed"]["secondary_creator"],
+        json!(authority.to_string())
+    );
+
+    let event = MeepEvent::SettingsApplied {
+        settings: Pubkey::new_unique(),
+        args: get_settings_args(),
+        effective_at: 42,
+    };
+    let value = decode_all("MeepEvent", &event.try_to_vec().unwrap());
+    assert_eq!(value["SettingsApplied"]["effective_at"], json!(42));
+
+    let sizes: Vec<(&str, usize)> = accounts()
+        .iter()
+        .map(|account| (account.name, account.size))
+        .collect();
+    assert!(sizes.contains(&("MeepSettings", MeepSettings::LEN)));
+    assert!(sizes.contains(&("MintRecord", MintRecord::LEN)));
+    assert!(sizes.contains(&("Proposal", Proposal::LEN)));
+    assert!(sizes.contains(&("StakeRecord", StakeRecord::LEN)));
+}
+
+#[test]
+fn idl_errors() {
+    let errors = errors();
+    assert_eq!(errors[0].code, 0);
+    assert_eq!(
+        errors[0].name,
+        format!("{:?}", MeepError::PrimaryAndSecondaryAreSame)
+    );
+
+    let last = errors.last().unwrap();
+    assert_eq!(last.code, MeepError::WrongTreasuryTokenAccount as u32);
+    assert_eq!(last.msg, MeepError::WrongTreasuryTokenAccount.to_string());
+
+    let idl = generate_idl(&meep::ID);
+    assert_eq!(idl["programId"], json!(meep::ID.to_string()));
+    assert_eq!(
+        idl["instructions"].as_array().unwrap().len(),
+        INSTRUCTIONS.len()
+    );
+    assert_eq!(idl["errors"].as_array().unwrap().len(), errors.len());
+}
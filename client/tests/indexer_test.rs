@@ -0,0 +1,194 @@
+use meep::{
+    instruction::{InitializeMeepArgs, MeepInstructions, MintNftArgs, SettingsArgs},
+    utils::Pda,
+};
+use meep_client::indexer::Indexer;
+use solana_client::rpc_client::RpcClient;
+use solana_program::instruction::Instruction;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+fn get_client() -> RpcClient {
+    RpcClient::new_with_commitment(
+        "http://localhost:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    )
+}
+
+fn send(client: &RpcClient, ix: Instruction, payer: &Keypair, signers: &[&Keypair]) {
+    let mut tx_signers = vec![payer];
+    tx_signers.extend_from_slice(signers);
+
+    let blockhash = client.get_recent_blockhash().unwrap().0;
+    let tx =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &tx_signers, blockhash);
+    client.send_and_confirm_transaction(&tx).unwrap();
+}
+
+fn mint_nft(
+    client: &RpcClient,
+    authority: &Keypair,
+    collection_id: u64,
+    secondary_creator: &Keypair,
+    uri: &str,
+) -> Keypair {
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let args = MintNftArgs {
+        seller_fee_basis_points: 500,
+        token_name: "Meep".to_string(),
+        token_symbol: "MEEP".to_string(),
+        uri: uri.to_string(),
+        is_mutable: false,
+        mint_record_bump: 0,
+    };
+
+    let ix = MeepInstructions::mint_nft(
+        &meep::ID,
+        &authority.pubkey(),
+        collection_id,
+        &authority.pubkey(),
+        &secondary_creator.pubkey(),
+        true,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &args,
+    );
+    send(
+        client,
+        ix,
+        authority,
+        &[secondary_creator, &mint, &token_account],
+    );
+
+    mint
+}
+
+#[test]
+fn indexer() {
+    let client = get_client();
+
+    let authority = Keypair::new();
+    let signature = client
+        .request_airdrop(&authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    client.poll_for_signature(&signature).unwrap();
+
+    // both collections have the same creators, mint records tell them apart
+    let secondary_creator = Keypair::new();
+    for collection_id in 0..2 {
+        let args = InitializeMeepArgs {
+            collection_id,
+            settings: SettingsArgs {
+                primary_wallet_percentage: 50,
+                minimum_price: 0,
+                treasury_fee_basis_points: 0,
+                treasury_mint_fee: 0,
+                settings_delay: 0,
+            },
+            pda_authority: false,
+            royalty_vault: false,
+            payment_mint: None,
+            membership: false,
+        };
+        let ix = MeepInstructions::initialize_meep(
+            &meep::ID,
+            &authority.pubkey(),
+            &secondary_creator.pubkey(),
+            &args,
+        );
+        send(&client, ix, &authority, &[&secondary_creator]);
+    }
+
+    let first = mint_nft(
+        &client,
+        &authority,
+        0,
+        &secondary_creator,
+        "https://test.com/0",
+    );
+    let second = mint_nft(
+        &client,
+        &authority,
+        0,
+        &secondary_creator,
+        "https://test.com/1",
+    );
+    let other = mint_nft(
+        &client,
+        &authority,
+        1,
+        &secondary_creator,
+        "https://test.com/2",
+    );
+
+    let mut indexer = Indexer::open_in_memory(get_client(), &meep::ID).unwrap();
+    let stats = indexer.refresh(false).unwrap();
+    assert!(stats.new_nfts >= 3);
+    assert!(stats.holders >= 3);
+
+    let settings = indexer.settings_by_authority(&authority.pubkey()).unwrap();
+    assert_eq!(settings.len(), 2);
+    assert_eq!(settings[1].collection_id, 1);
+
+    let settings_pubkey = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), 0).0;
+    let nfts = indexer.nfts_by_settings(&settings_pubkey).unwrap();
+    let mut mints: Vec<_> = nfts.iter().map(|nft| nft.mint).collect();
+    let mut expected = vec![first.pubkey(), second.pubkey()];
+    mints.sort();
+    expected.sort();
+    assert_eq!(mints, expected);
+
+    let nfts = indexer.nfts_by_authority(&authority.pubkey()).unwrap();
+    assert_eq!(nfts.len(), 3);
+    let nft = nfts.iter().find(|nft| nft.mint == other.pubkey()).unwrap();
+    assert_eq!(nft.uri, "https://test.com/2");
+    assert_eq!(nft.name, "Meep");
+    assert_eq!(nft.owner, Some(authority.pubkey()));
+
+    assert_eq!(indexer.nfts_by_owner(&authority.pubkey()).unwrap().len(), 3);
+
+    // a refresh only loads NFTs minted since the last one
+    let third = mint_nft(
+        &client,
+        &authority,
+        0,
+        &secondary_creator,
+        "https://test.com/3",
+    );
+    let stats = indexer.refresh(false).unwrap();
+    assert!(stats.new_nfts >= 1);
+
+    let nfts = indexer.nfts_by_settings(&settings_pubkey).unwrap();
+    assert_eq!(nfts.len(), 3);
+    assert!(nfts.iter().any(|nft| nft.mint == third.pubkey()));
+
+    // burned NFTs leave an incremental refresh as well
+    let token_account = nfts
+        .iter()
+        .find(|nft| nft.mint == first.pubkey())
+        .and_then(|nft| nft.token_account)
+        .unwrap();
+    let ix = MeepInstructions::burn_nft(
+        &meep::ID,
+        &authority.pubkey(),
+        0,
+        &authority.pubkey(),
+        &first.pubkey(),
+        &token_account,
+    );
+    send(&client, ix, &authority, &[]);
+
+    indexer.refresh(false).unwrap();
+    let nfts = indexer.nfts_by_settings(&settings_pubkey).unwrap();
+    assert_eq!(nfts.len(), 2);
+    assert!(nfts.iter().all(|nft| nft.mint != first.pubkey()));
+    assert_eq!(indexer.nfts_by_owner(&authority.pubkey()).unwrap().len(), 3);
+
+    assert!(indexer
+        .nfts_by_authority(&Keypair::new().pubkey())
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,75 @@
+use meep::instruction::{InitializeMeepArgs, MeepInstructions, SettingsArgs};
+use meep_client::accounts::get_settings_by_authority;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+#[test]
+fn settings_by_authority() {
+    let client = RpcClient::new_with_commitment(
+        "http://localhost:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let authority = Keypair::new();
+    let signature = client
+        .request_airdrop(&authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    client.poll_for_signature(&signature).unwrap();
+
+    let collection_ids = vec![1, 7, 42];
+    for collection_id in collection_ids.iter() {
+        let secondary_creator = Keypair::new();
+        let args = InitializeMeepArgs {
+            collection_id: *collection_id,
+            settings: SettingsArgs {
+                primary_wallet_percentage: 50,
+                minimum_price: *collection_id,
+                treasury_fee_basis_points: 0,
+                treasury_mint_fee: 0,
+                settings_delay: 0,
+            },
+            pda_authority: false,
+            royalty_vault: false,
+            payment_mint: None,
+            membership: false,
+        };
+
+        let ix = MeepInstructions::initialize_meep(
+            &meep::ID,
+            &authority.pubkey(),
+            &secondary_creator.pubkey(),
+            &args,
+        );
+
+        let blockhash = client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&authority.pubkey()),
+            &[&authority, &secondary_creator],
+            blockhash,
+        );
+        client.send_and_confirm_transaction(&tx).unwrap();
+    }
+
+    let settings = get_settings_by_authority(&client, &meep::ID, &authority.pubkey()).unwrap();
+    let found: Vec<u64> = settings
+        .iter()
+        .map(|(_, settings)| settings.collection_id)
+        .collect();
+    assert_eq!(found, collection_ids);
+
+    for (_, settings) in settings.iter() {
+        assert_eq!(settings.authority, authority.pubkey());
+        assert_eq!(settings.minimum_price, settings.collection_id);
+    }
+
+    let other = Keypair::new();
+    assert!(
+        get_settings_by_authority(&client, &meep::ID, &other.pubkey())
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,132 @@
+use meep::{
+    instruction::{InitializeMeepArgs, MeepInstructions, SettingsArgs},
+    utils::Pda,
+};
+use meep_client::treasury::get_treasury_balances;
+use solana_client::rpc_client::RpcClient;
+use solana_program::{program_pack::Pack, system_instruction};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use spl_token::{
+    instruction::{initialize_account2, initialize_mint, mint_to},
+    state::{Account, Mint},
+};
+
+#[test]
+fn treasury_balances() {
+    let client = RpcClient::new_with_commitment(
+        "http://localhost:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let authority = Keypair::new();
+    let signature = client
+        .request_airdrop(&authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    client.poll_for_signature(&signature).unwrap();
+
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: SettingsArgs {
+            primary_wallet_percentage: 50,
+            minimum_price: 0,
+            treasury_fee_basis_points: 0,
+            treasury_mint_fee: 0,
+            settings_delay: 0,
+        },
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: false,
+    };
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings).0;
+
+    // two treasury token accounts of one mint
+    let mint = Keypair::new();
+    let token_accounts = [Keypair::new(), Keypair::new()];
+    let mint_rent = client
+        .get_minimum_balance_for_rent_exemption(Mint::LEN)
+        .unwrap();
+    let account_rent = client
+        .get_minimum_balance_for_rent_exemption(Account::LEN)
+        .unwrap();
+
+    let mut instructions = vec![
+        MeepInstructions::initialize_meep(
+            &meep::ID,
+            &authority.pubkey(),
+            &secondary_creator.pubkey(),
+            &args,
+        ),
+        MeepInstructions::deposit(
+            &meep::ID,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            None,
+            2_000_000,
+        ),
+        system_instruction::create_account(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            mint_rent,
+            Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        initialize_mint(&spl_token::ID, &mint.pubkey(), &authority.pubkey(), None, 6).unwrap(),
+    ];
+    for (token_account, amount) in token_accounts.iter().zip([30, 12].iter()) {
+        instructions.extend(vec![
+            system_instruction::create_account(
+                &authority.pubkey(),
+                &token_account.pubkey(),
+                account_rent,
+                Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            initialize_account2(
+                &spl_token::ID,
+                &token_account.pubkey(),
+                &mint.pubkey(),
+                &treasury,
+            )
+            .unwrap(),
+            mint_to(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &token_account.pubkey(),
+                &authority.pubkey(),
+                &[],
+                *amount,
+            )
+            .unwrap(),
+        ]);
+    }
+
+    let blockhash = client.get_recent_blockhash().unwrap().0;
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[
+            &authority,
+            &secondary_creator,
+            &mint,
+            &token_accounts[0],
+            &token_accounts[1],
+        ],
+        blockhash,
+    );
+    client.send_and_confirm_transaction(&tx).unwrap();
+
+    let balances = get_treasury_balances(&client, &meep::ID, &settings).unwrap();
+    assert_eq!(balances.treasury, treasury);
+    assert_eq!(balances.lamports, 2_000_000);
+    assert_eq!(balances.tokens.len(), 1);
+    assert_eq!(balances.tokens[&mint.pubkey()], 42);
+}
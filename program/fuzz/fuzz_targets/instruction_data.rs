@@ -0,0 +1,30 @@
+#![no_main]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use libfuzzer_sys::fuzz_target;
+use meep::instruction::MeepInstructions;
+
+fuzz_target!(|data: &[u8]| {
+    meep_fuzz::runtime::start(meep_fuzz::START_TIMESTAMP);
+
+    if let Ok(instruction) = MeepInstructions::try_from_slice(data) {
+        // Borsh has a single encoding per value
+        assert_eq!(instruction.try_to_vec().unwrap(), data);
+
+        let _ = match &instruction {
+            MeepInstructions::InitializeMeep(args) => args.settings.validate(),
+            MeepInstructions::UpdateSettings(args) => args.validate(),
+            MeepInstructions::MintNft(args) => args.validate(),
+            MeepInstructions::EnableRevealMode(args) => args.validate(),
+            MeepInstructions::Reveal(args) => args.validate(),
+            MeepInstructions::UpdateNftMetadata(args) => args.validate(),
+            MeepInstructions::ConfigureGovernance(args) => args.validate(),
+            MeepInstructions::CreateProposal(args) => args.validate(),
+            MeepInstructions::UpdateSigners(args) => args.validate(),
+            _ => Ok(()),
+        };
+    }
+
+    // every instruction reads at least one account
+    assert!(meep::processor::process_instruction(&meep::id(), &[], data).is_err());
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use meep_fuzz::{invariants, ledger::Ledger, runtime, FuzzInput, MAX_STEPS, START_TIMESTAMP};
+
+fuzz_target!(|input: FuzzInput| {
+    runtime::start(START_TIMESTAMP);
+    let ledger = Ledger::new(&input.balances);
+
+    for instruction in input.instructions.iter().take(MAX_STEPS) {
+        runtime::advance_clock(instruction.seconds);
+
+        let before = ledger.snapshot();
+        match ledger.process(instruction) {
+            Ok(()) => invariants::check(&ledger, &before),
+            Err(_) => ledger.restore(&before),
+        }
+    }
+});
@@ -0,0 +1,73 @@
+use crate::ledger::{AccountState, Ledger, AUTHORITY, COLLECTION_ID};
+use meep::{
+    instruction::MAX_BASIS_POINTS,
+    state::{MeepSettings, MAX_DELEGATED_MINTERS, MAX_SIGNERS},
+    utils::Pda,
+};
+use metaplex_token_metadata::state::{Key, Metadata};
+use solana_program::{borsh::try_from_slice_unchecked, pubkey::Pubkey};
+
+/// Panics if a successful instruction broke what every program state must satisfy
+pub fn check(ledger: &Ledger, before: &[AccountState]) {
+    let after = ledger.snapshot();
+
+    let total = |states: &[AccountState]| -> u128 {
+        states.iter().map(|state| u128::from(state.lamports)).sum()
+    };
+    assert_eq!(
+        total(before),
+        total(&after),
+        "lamports created or destroyed"
+    );
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::id(), &AUTHORITY, COLLECTION_ID).0;
+    for (info, state) in ledger.accounts.iter().zip(after.iter()) {
+        if state.data.is_empty() {
+            continue;
+        }
+
+        if *info.key == settings {
+            assert_eq!(state.owner, meep::id(), "settings not owned by the program");
+        }
+
+        if state.owner == meep::id() && state.data.len() == MeepSettings::LEN {
+            check_settings(info.key, &state.data);
+        } else if state.owner == metaplex_token_metadata::id()
+            && state.data[0] == Key::MetadataV1 as u8
+        {
+            check_metadata(info.key, &state.data);
+        }
+    }
+}
+
+fn check_settings(pubkey: &Pubkey, data: &[u8]) {
+    let settings: MeepSettings = try_from_slice_unchecked(data).unwrap();
+
+    assert_eq!(
+        Pda::settings_pubkey(
+            &meep::id(),
+            &settings.original_authority,
+            settings.collection_id,
+            settings.bump,
+        )
+        .ok(),
+        Some(*pubkey),
+        "settings stored at another address"
+    );
+    assert!(settings.primary_wallet_percentage <= 100);
+    assert!(settings.treasury_fee_basis_points <= MAX_BASIS_POINTS);
+    assert!(settings.delegated_minters.len() <= MAX_DELEGATED_MINTERS);
+    assert!(settings.signers.len() <= MAX_SIGNERS);
+}
+
+fn check_metadata(pubkey: &Pubkey, data: &[u8]) {
+    let metadata: Metadata = try_from_slice_unchecked(data).unwrap();
+
+    if let Some(creators) = metadata.data.creators {
+        let shares: u16 = creators
+            .iter()
+            .map(|creator| u16::from(creator.share))
+            .sum();
+        assert_eq!(shares, 100, "creator shares of {} don't sum to 100", pubkey);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{runtime, FuzzAccountMeta, FuzzInstruction};
+use meep::utils::Pda;
+use solana_program::{
+    account_info::AccountInfo,
+    bpf_loader,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+    sysvar::{self, Sysvar},
+};
+use std::{cell::RefCell, collections::HashMap};
+
+pub const AUTHORITY: Pubkey = Pubkey::new_from_array([1; 32]);
+pub const SECONDARY_CREATOR: Pubkey = Pubkey::new_from_array([2; 32]);
+pub const MINTER: Pubkey = Pubkey::new_from_array([3; 32]);
+pub const MINTS: [Pubkey; 2] = [
+    Pubkey::new_from_array([4; 32]),
+    Pubkey::new_from_array([5; 32]),
+];
+pub const TOKEN_ACCOUNTS: [Pubkey; 2] = [
+    Pubkey::new_from_array([6; 32]),
+    Pubkey::new_from_array([7; 32]),
+];
+pub const COLLECTION_ID: u64 = 0;
+pub const PROPOSALS: u64 = 2;
+
+/// Lamports of a wallet without a configured balance
+const DEFAULT_LAMPORTS: u64 = 10_000_000_000;
+
+thread_local! {
+    /// Data buffers handed out to `AccountInfo`s, freed with the ledger
+    static ALLOCATIONS: RefCell<Vec<*mut [u8]>> = RefCell::new(Vec::new());
+}
+
+/// Zeroed buffer that lives as long as the ledger. Account data can grow during a CPI,
+/// while an `AccountInfo` only borrows the slice it was created with
+pub fn allocate_data(len: usize) -> &'static mut [u8] {
+    let data = Box::into_raw(vec![0; len].into_boxed_slice());
+    ALLOCATIONS.with(|allocations| allocations.borrow_mut().push(data));
+
+    // Safety: freed by `Ledger::drop` after all `AccountInfo`s borrowing it are gone
+    unsafe { &mut *data }
+}
+
+/// Changes the owner of an account the way the runtime does. `AccountInfo::owner` is a
+/// shared reference, this writes through it like solana-program-test
+pub fn set_owner(info: &AccountInfo, owner: &Pubkey) {
+    // Safety: owners point into `Ledger::owners`, which is never borrowed otherwise
+    unsafe { *(info.owner as *const Pubkey as *mut Pubkey) = *owner }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AccountKind {
+    /// Keypair account, can sign the transaction
+    Wallet,
+    Pda,
+    Program,
+    Sysvar,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountState {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+}
+
+impl AccountState {
+    pub fn of(info: &AccountInfo) -> Self {
+        AccountState {
+            lamports: info.lamports(),
+            data: info.data.borrow().to_vec(),
+            owner: *info.owner,
+        }
+    }
+
+    pub fn restore(&self, info: &AccountInfo) {
+        **info.lamports.borrow_mut() = self.lamports;
+
+        let mut data = info.data.borrow_mut();
+        if data.len() == self.data.len() {
+            data.copy_from_slice(&self.data);
+        } else {
+            let restored = allocate_data(self.data.len());
+            restored.copy_from_slice(&self.data);
+            *data = restored;
+        }
+
+        set_owner(info, &self.owner);
+    }
+}
+
+/// Every account the fuzzed instructions can reference: wallets, the PDAs of one settings
+/// account and its NFTs, and the programs Meep invokes
+fn pool() -> Vec<(Pubkey, AccountKind)> {
+    let program_id = meep::id();
+    let settings = Pda::settings_pubkey_with_bump(&program_id, &AUTHORITY, COLLECTION_ID).0;
+    let proposals: Vec<Pubkey> = (0..PROPOSALS)
+        .map(|index| Pda::proposal_pubkey_with_bump(&program_id, &settings, index).0)
+        .collect();
+
+    let mut pool = vec![
+        (AUTHORITY, AccountKind::Wallet),
+        (SECONDARY_CREATOR, AccountKind::Wallet),
+        (MINTER, AccountKind::Wallet),
+    ];
+    for (mint, token_account) in MINTS.iter().zip(TOKEN_ACCOUNTS.iter()) {
+        pool.push((*mint, AccountKind::Wallet));
+        pool.push((*token_account, AccountKind::Wallet));
+        pool.push((
+            Pda::mint_record_pubkey_with_bump(&program_id, mint).0,
+            AccountKind::Pda,
+        ));
+        pool.push((
+            Pda::stake_record_pubkey_with_bump(&program_id, mint).0,
+            AccountKind::Pda,
+        ));
+        pool.push((
+            Pda::stake_account_pubkey_with_bump(&program_id, mint).0,
+            AccountKind::Pda,
+        ));
+        pool.push((Pda::metadata_pubkey(mint), AccountKind::Pda));
+        pool.push((Pda::master_edition_pubkey(mint), AccountKind::Pda));
+        for proposal in proposals.iter() {
+            pool.push((
+                Pda::vote_record_pubkey_with_bump(&program_id, proposal, mint).0,
+                AccountKind::Pda,
+            ));
+        }
+    }
+
+    pool.push((settings, AccountKind::Pda));
+    pool.extend(
+        proposals
+            .iter()
+            .map(|proposal| (*proposal, AccountKind::Pda)),
+    );
+    pool.extend(
+        vec![
+            Pda::authority_pubkey_with_bump(&program_id, &settings).0,
+            Pda::royalty_vault_pubkey_with_bump(&program_id, &settings).0,
+            Pda::treasury_pubkey_with_bump(&program_id, &settings).0,
+            Pda::governance_pubkey_with_bump(&program_id, &settings).0,
+            Pda::locked_update_authority_with_bump(&program_id).0,
+        ]
+        .into_iter()
+        .map(|pubkey| (pubkey, AccountKind::Pda)),
+    );
+    pool.extend(
+        vec![
+            program_id,
+            metaplex_token_metadata::id(),
+            spl_token::id(),
+            system_program::id(),
+        ]
+        .into_iter()
+        .map(|pubkey| (pubkey, AccountKind::Program)),
+    );
+    pool.push((sysvar::rent::id(), AccountKind::Sysvar));
+
+    pool
+}
+
+/// Accounts kept between the instructions of one fuzz input. All `AccountInfo`s handed to
+/// programs are clones of these, so changes made during CPIs land here
+pub struct Ledger {
+    pub accounts: Vec<AccountInfo<'static>>,
+    pub kinds: Vec<AccountKind>,
+    keys: *mut [Pubkey],
+    lamports: *mut [u64],
+    owners: *mut [Pubkey],
+}
+
+impl Ledger {
+    /// Wallets and PDAs get `balances` in pool order, wallets past its end
+    /// `DEFAULT_LAMPORTS` and PDAs nothing
+    pub fn new(balances: &[u64]) -> Self {
+        let pool = pool();
+        let mut balances = balances.iter();
+
+        let mut keys = Vec::with_capacity(pool.len());
+        let mut initial_lamports = Vec::with_capacity(pool.len());
+        let mut owners = Vec::with_capacity(pool.len());
+        for (pubkey, kind) in pool.iter() {
+            keys.push(*pubkey);
+            match kind {
+                AccountKind::Wallet | AccountKind::Pda => {
+                    let default = if *kind == AccountKind::Wallet {
+                        DEFAULT_LAMPORTS
+                    } else {
+                        0
+                    };
+                    initial_lamports.push(balances.next().copied().unwrap_or(default));
+                    owners.push(system_program::id());
+                }
+                AccountKind::Program => {
+                    initial_lamports.push(1);
+                    owners.push(bpf_loader::id());
+                }
+                AccountKind::Sysvar => {
+                    initial_lamports.push(1);
+                    owners.push(sysvar::id());
+                }
+            }
+        }
+
+        let keys = Box::into_raw(keys.into_boxed_slice());
+        let lamports = Box::into_raw(initial_lamports.into_boxed_slice());
+        let owners = Box::into_raw(owners.into_boxed_slice());
+
+        // Safety: the boxes are freed by `Ledger::drop` after the `AccountInfo`s
+        let (key_refs, lamport_refs, owner_refs) = unsafe { (&*keys, &mut *lamports, &*owners) };
+
+        let mut accounts = Vec::with_capacity(pool.len());
+        for (((key, lamports), owner), (_, kind)) in key_refs
+            .iter()
+            .zip(lamport_refs.iter_mut())
+            .zip(owner_refs.iter())
+            .zip(pool.iter())
+        {
+            let data = match kind {
+                AccountKind::Sysvar => allocate_data(Rent::size_of()),
+                _ => allocate_data(0),
+            };
+            let mut info = AccountInfo::new(
+                key,
+                false,
+                false,
+                lamports,
+                data,
+                owner,
+                *kind == AccountKind::Program,
+                0,
+            );
+            if *kind == AccountKind::Sysvar {
+                Rent::default().to_account_info(&mut info).unwrap();
+            }
+            accounts.push(info);
+        }
+
+        Ledger {
+            accounts,
+            kinds: pool.into_iter().map(|(_, kind)| kind).collect(),
+            keys,
+            lamports,
+            owners,
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<AccountState> {
+        self.accounts.iter().map(AccountState::of).collect()
+    }
+
+    /// Discards the changes of a failed instruction
+    pub fn restore(&self, snapshot: &[AccountState]) {
+        for (info, state) in self.accounts.iter().zip(snapshot) {
+            state.restore(info);
+        }
+    }
+
+    /// Runs a top-level Meep instruction, the caller restores the ledger if it fails
+    pub fn process(&self, instruction: &FuzzInstruction) -> ProgramResult {
+        let accounts = self.instruction_accounts(&instruction.accounts);
+        runtime::execute(&meep::id(), &accounts, &instruction.data)
+    }
+
+    /// Accounts of a transaction instruction. Like the runtime, an account referenced more
+    /// than once gets the union of its flags, only wallets sign and programs and sysvars
+    /// are never writable
+    pub fn instruction_accounts(&self, metas: &[FuzzAccountMeta]) -> Vec<AccountInfo<'static>> {
+        let mut flags: HashMap<usize, (bool, bool)> = HashMap::new();
+        for meta in metas {
+            let index = meta.index as usize % self.accounts.len();
+            let entry = flags.entry(index).or_default();
+            entry.0 |= meta.is_signer;
+            entry.1 |= meta.is_writable;
+        }
+
+        metas
+            .iter()
+            .map(|meta| {
+                let index = meta.index as usize % self.accounts.len();
+                let (is_signer, is_writable) = flags[&index];
+                let kind = self.kinds[index];
+
+                let mut info = self.accounts[index].clone();
+                info.is_signer = is_signer && kind == AccountKind::Wallet;
+                info.is_writable =
+                    is_writable && (kind == AccountKind::Wallet || kind == AccountKind::Pda);
+                info
+            })
+            .collect()
+    }
+}
+
+impl Drop for Ledger {
+    fn drop(&mut self) {
+        self.accounts.clear();
+
+        // Safety: nothing borrows the buffers once the `AccountInfo`s are dropped
+        unsafe {
+            drop(Box::from_raw(self.keys));
+            drop(Box::from_raw(self.lamports));
+            drop(Box::from_raw(self.owners));
+            ALLOCATIONS.with(|allocations| {
+                for data in allocations.borrow_mut().drain(..) {
+                    drop(Box::from_raw(data));
+                }
+            });
+        }
+    }
+}
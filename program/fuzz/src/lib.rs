@@ -0,0 +1,40 @@
+//! Simulated runtime for fuzzing the Meep processor natively: CPIs to the system, SPL
+//! token and token-metadata programs run their processors against shared `AccountInfo`s,
+//! with the signer, writable and ownership checks of the runtime
+
+pub mod invariants;
+pub mod ledger;
+pub mod runtime;
+
+use arbitrary::Arbitrary;
+use solana_program::clock::UnixTimestamp;
+
+/// Instructions of one fuzz input beyond this are ignored
+pub const MAX_STEPS: usize = 16;
+
+/// Clock time of the first instruction
+pub const START_TIMESTAMP: UnixTimestamp = 1_640_000_000;
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzAccountMeta {
+    /// Account of the ledger, wraps around
+    pub index: u8,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Top-level Meep instruction, sent alone in a transaction
+#[derive(Arbitrary, Debug)]
+pub struct FuzzInstruction {
+    /// Seconds passed since the previous instruction
+    pub seconds: u16,
+    pub accounts: Vec<FuzzAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzInput {
+    /// Initial lamports of the wallets and PDAs
+    pub balances: Vec<u64>,
+    pub instructions: Vec<FuzzInstruction>,
+}
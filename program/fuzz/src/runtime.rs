@@ -0,0 +1,303 @@
+use crate::ledger::{allocate_data, set_owner, AccountState};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::{Clock, UnixTimestamp},
+    entrypoint::{ProgramResult, SUCCESS},
+    instruction::Instruction,
+    program_error::ProgramError,
+    program_stubs::{self, SyscallStubs},
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{SystemError, SystemInstruction, MAX_PERMITTED_DATA_LENGTH},
+    system_program,
+};
+use std::{cell::RefCell, collections::HashMap, sync::Once};
+
+/// Programs on the stack at once, the top-level instruction included
+const MAX_INVOKE_DEPTH: usize = 5;
+
+/// Program executing on the stack with the account states its changes are checked against
+struct Frame {
+    program_id: Pubkey,
+    accounts: HashMap<Pubkey, AccountState>,
+}
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+    static STACK: RefCell<Vec<Frame>> = RefCell::new(Vec::new());
+}
+
+/// Serves the clock and rent sysvars and CPIs to the system, SPL token, token-metadata
+/// and Meep programs, all executed natively
+struct FuzzSyscallStubs;
+
+impl SyscallStubs for FuzzSyscallStubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_compute_units(&self) {}
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_signed(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // Safety: the address comes from `Clock::get`
+        CLOCK.with(|clock| unsafe { *(var_addr as *mut Clock) = clock.borrow().clone() });
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // Safety: the address comes from `Rent::get`
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+}
+
+/// Installs the syscall stubs and starts the clock of a new fuzz input
+pub fn start(unix_timestamp: UnixTimestamp) {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(FuzzSyscallStubs));
+    });
+
+    CLOCK.with(|clock| {
+        *clock.borrow_mut() = Clock {
+            unix_timestamp,
+            ..Clock::default()
+        }
+    });
+    STACK.with(|stack| stack.borrow_mut().clear());
+}
+
+pub fn advance_clock(seconds: u16) {
+    CLOCK.with(|clock| {
+        let mut clock = clock.borrow_mut();
+        clock.slot += 1;
+        clock.unix_timestamp += UnixTimestamp::from(seconds);
+    });
+}
+
+/// Runs `program_id` with the checks the runtime does after every program it executes
+pub fn execute(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let frame = Frame {
+        program_id: *program_id,
+        accounts: snapshot(accounts),
+    };
+    let depth = STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.push(frame);
+        stack.len()
+    });
+
+    let result = if depth > MAX_INVOKE_DEPTH {
+        Err(ProgramError::InvalidArgument)
+    } else if *program_id == meep::id() {
+        meep::processor::process_instruction(program_id, accounts, data)
+    } else if *program_id == metaplex_token_metadata::id() {
+        metaplex_token_metadata::processor::process_instruction(program_id, accounts, data)
+    } else if *program_id == spl_token::id() {
+        spl_token::processor::Processor::process(program_id, accounts, data)
+    } else if *program_id == system_program::id() {
+        process_system_instruction(accounts, data)
+    } else {
+        Err(ProgramError::IncorrectProgramId)
+    };
+
+    let result = result.and_then(|()| verify_changes(accounts));
+    STACK.with(|stack| stack.borrow_mut().pop());
+    result
+}
+
+fn snapshot(accounts: &[AccountInfo]) -> HashMap<Pubkey, AccountState> {
+    accounts
+        .iter()
+        .map(|info| (*info.key, AccountState::of(info)))
+        .collect()
+}
+
+/// Rejects changes the executing program isn't allowed to make since the last check, as
+/// the runtime does when a program returns or invokes another one
+fn verify_changes(accounts: &[AccountInfo]) -> ProgramResult {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let frame = stack.last_mut().unwrap();
+
+        for info in accounts {
+            let post = AccountState::of(info);
+            let pre = match frame.accounts.get(info.key) {
+                Some(pre) if *pre != post => pre,
+                _ => continue,
+            };
+
+            let owned = pre.owner == frame.program_id;
+            let zeroed = pre.data.iter().all(|byte| *byte == 0);
+            if !info.is_writable
+                || info.executable
+                || (post.owner != pre.owner && !(owned && zeroed))
+                || (post.data != pre.data && !owned)
+                || (post.lamports < pre.lamports && !owned)
+            {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        for info in accounts {
+            frame.accounts.insert(*info.key, AccountState::of(info));
+        }
+        Ok(())
+    })
+}
+
+/// Forgets the callee's changes, the caller is only checked for its own
+fn refresh_frame(accounts: &[AccountInfo]) {
+    STACK.with(|stack| {
+        if let Some(frame) = stack.borrow_mut().last_mut() {
+            for info in accounts {
+                frame.accounts.insert(*info.key, AccountState::of(info));
+            }
+        }
+    });
+}
+
+fn current_program() -> Pubkey {
+    STACK.with(|stack| stack.borrow().last().unwrap().program_id)
+}
+
+fn invoke_signed(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let caller = current_program();
+    let signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !account_infos
+        .iter()
+        .any(|info| *info.key == instruction.program_id)
+    {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut accounts = Vec::with_capacity(instruction.accounts.len());
+    for meta in instruction.accounts.iter() {
+        let info = account_infos
+            .iter()
+            .find(|info| *info.key == meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        if meta.is_signer && !info.is_signer && !signers.contains(&meta.pubkey) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if meta.is_writable && !info.is_writable {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut info = info.clone();
+        info.is_signer = meta.is_signer;
+        info.is_writable = meta.is_writable;
+        accounts.push(info);
+    }
+
+    verify_changes(account_infos)?;
+    execute(&instruction.program_id, &accounts, &instruction.data)?;
+    refresh_frame(account_infos);
+    Ok(())
+}
+
+fn process_system_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let account = |index: usize| {
+        accounts
+            .get(index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+
+    match limited_deserialize::<SystemInstruction>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if to.lamports() > 0 || !to.data_is_empty() || *to.owner != system_program::id() {
+                return Err(ProgramError::Custom(
+                    SystemError::AccountAlreadyInUse as u32,
+                ));
+            }
+
+            transfer(from, to, lamports)?;
+            allocate(to, space)?;
+            assign(to, &owner)
+        }
+        SystemInstruction::Transfer { lamports } => transfer(account(0)?, account(1)?, lamports),
+        SystemInstruction::Allocate { space } => allocate(account(0)?, space),
+        SystemInstruction::Assign { owner } => assign(account(0)?, &owner),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !from.data_is_empty() || *from.owner != system_program::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if from.lamports() < lamports {
+        return Err(ProgramError::Custom(
+            SystemError::ResultWithNegativeLamports as u32,
+        ));
+    }
+
+    **from.lamports.borrow_mut() -= lamports;
+    let balance = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::InvalidArgument)?;
+    **to.lamports.borrow_mut() = balance;
+    Ok(())
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !account.data_is_empty() || *account.owner != system_program::id() {
+        return Err(ProgramError::Custom(
+            SystemError::AccountAlreadyInUse as u32,
+        ));
+    }
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(ProgramError::Custom(
+            SystemError::InvalidAccountDataLength as u32,
+        ));
+    }
+
+    *account.data.borrow_mut() = allocate_data(space as usize);
+    Ok(())
+}
+
+fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner == owner {
+        return Ok(());
+    }
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *account.owner != system_program::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    set_owner(account, owner);
+    Ok(())
+}
@@ -6,25 +6,213 @@ use solana_program::{
 };
 use thiserror::Error;
 
-#[derive(Clone, Debug, Error, FromPrimitive)]
+/// Codes are part of the interface, clients match on them. Never renumber or reuse a
+/// code, append new errors with the next free one
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum MeepError {
     #[error("Primary and secondary creators must be different")]
-    PrimareAndSecondaryAreSame,
+    PrimaryAndSecondaryAreSame = 0,
 
     #[error("Percentage is in the range [0; 100]")]
-    PercentageLimitExceeded,
+    PercentageLimitExceeded = 1,
 
     #[error("Settings account has wrong pubkey")]
-    WrongSettingsAccount,
+    WrongSettingsAccount = 2,
 
     #[error("Wrong authority")]
-    WrongAuthority,
+    WrongAuthority = 3,
 
     #[error("Wrong primary creator")]
-    WrongPrimaryCreator,
+    WrongPrimaryCreator = 4,
 
     #[error("Wrong secondary creator")]
-    WrongSecondaryCreator,
+    WrongSecondaryCreator = 5,
+
+    #[error("Reveal mode is already enabled")]
+    RevealAlreadyEnabled = 6,
+
+    #[error("Reveal mode is not enabled")]
+    RevealNotEnabled = 7,
+
+    #[error("NFT was not minted in reveal mode")]
+    NotRevealable = 8,
+
+    #[error("NFT is already revealed")]
+    AlreadyRevealed = 9,
+
+    #[error("URI does not match the reveal commitment")]
+    InvalidRevealProof = 10,
+
+    #[error("Metadata account has wrong pubkey")]
+    WrongMetadataAccount = 11,
+
+    #[error("Mint record account has wrong pubkey")]
+    WrongMintRecord = 12,
+
+    #[error("Numerical overflow")]
+    NumericalOverflow = 13,
+
+    #[error("Signer is neither the authority nor a delegated minter, and there is no public mint")]
+    WrongMinter = 14,
+
+    #[error("Delegated minter is already added")]
+    DelegatedMinterAlreadyAdded = 15,
+
+    #[error("Delegated minter is not found")]
+    DelegatedMinterNotFound = 16,
+
+    #[error("Delegated minters limit is reached")]
+    DelegatedMintersLimitExceeded = 17,
+
+    #[error("Secondary creator hasn't signed and there is no mint approval")]
+    MintApprovalMissing = 18,
+
+    #[error("Mint approval has expired")]
+    MintApprovalExpired = 19,
+
+    #[error("Mint approval has no mints left")]
+    MintApprovalExhausted = 20,
+
+    #[error("Token name is too long")]
+    NameTooLong = 21,
+
+    #[error("Token symbol is too long")]
+    SymbolTooLong = 22,
+
+    #[error("URI is too long")]
+    UriTooLong = 23,
+
+    #[error("Seller fee basis points are in the range [0; 10000]")]
+    InvalidSellerFee = 24,
+
+    #[error("NFT metadata is immutable")]
+    MetadataImmutable = 25,
+
+    #[error("Authority PDA has wrong pubkey")]
+    WrongAuthorityPda = 26,
+
+    #[error("Royalty vault has wrong pubkey")]
+    WrongRoyaltyVault = 27,
+
+    #[error("Payment mint is not set")]
+    PaymentMintNotSet = 28,
+
+    #[error("Wrong payment mint")]
+    WrongPaymentMint = 29,
+
+    #[error("Payment account is not a token account")]
+    WrongPaymentAccount = 30,
+
+    #[error("Payment account has wrong owner")]
+    WrongPaymentAccountOwner = 31,
+
+    #[error("Payment is below the minimum price")]
+    InsufficientPayment = 32,
+
+    #[error("Treasury fee basis points are in the range [0; 10000]")]
+    InvalidTreasuryFee = 33,
+
+    #[error("Treasury has wrong pubkey")]
+    WrongTreasury = 34,
+
+    #[error("Governance PDA has wrong pubkey")]
+    WrongGovernance = 35,
+
+    #[error("Governance quorum, threshold or voting period is invalid")]
+    InvalidGovernanceConfig = 36,
+
+    #[error("Governance is not configured")]
+    GovernanceNotConfigured = 37,
+
+    #[error("Token account doesn't hold the NFT")]
+    NotNftHolder = 38,
+
+    #[error("NFT has no verified creator of these settings")]
+    UnverifiedNft = 39,
+
+    #[error("Proposal account has wrong pubkey")]
+    WrongProposal = 40,
+
+    #[error("Vote record account has wrong pubkey")]
+    WrongVoteRecord = 41,
+
+    #[error("NFT has already voted on this proposal")]
+    AlreadyVoted = 42,
+
+    #[error("Voting on this proposal is closed")]
+    VotingClosed = 43,
+
+    #[error("Voting on this proposal hasn't ended yet")]
+    VotingNotEnded = 44,
+
+    #[error("Proposal is not approved")]
+    ProposalNotApproved = 45,
+
+    #[error("Proposal execution delay hasn't passed yet")]
+    ExecutionDelayNotPassed = 46,
+
+    #[error("Recipient doesn't match the proposal")]
+    WrongRecipient = 47,
+
+    #[error("Signer set or threshold is invalid")]
+    InvalidSignerSet = 48,
+
+    #[error("Not enough signers of the settings multisig")]
+    NotEnoughSigners = 49,
+
+    #[error("Settings delay can't be negative")]
+    InvalidSettingsDelay = 50,
+
+    #[error("There are no pending settings")]
+    NoPendingSettings = 51,
+
+    #[error("Pending settings are not effective yet")]
+    PendingSettingsNotEffective = 52,
+
+    #[error("Pending settings are already effective")]
+    PendingSettingsEffective = 53,
+
+    #[error("NFT is not a membership badge of the settings")]
+    NotMembershipNft = 54,
+
+    #[error("Membership NFTs are revoked by the authority")]
+    MembershipNftNotBurnable = 55,
+
+    #[error("Staking isn't configured for the settings")]
+    StakingNotConfigured = 56,
+
+    #[error("Wrong stake record account")]
+    WrongStakeRecord = 57,
+
+    #[error("Signer didn't stake the NFT")]
+    NotStaker = 58,
+
+    #[error("Reward mint doesn't match the staking settings")]
+    WrongRewardMint = 59,
+
+    #[error("Reward vault must be a token account of the reward mint owned by the authority PDA")]
+    WrongRewardVault = 60,
+
+    #[error("Membership NFTs can't be staked")]
+    MembershipNftNotStakable = 61,
+
+    #[error("Reveal URI count must be positive")]
+    InvalidRevealUriCount = 62,
+
+    #[error("Every URI of the reveal commitment is already minted")]
+    RevealUrisExhausted = 63,
+
+    #[error("Secondary approval account has wrong pubkey")]
+    WrongSecondaryApproval = 64,
+
+    #[error("Reward mint can't change while NFTs are staked")]
+    RewardMintInUse = 65,
+
+    #[error("NFT was minted after the proposal was created")]
+    MintedAfterProposal = 66,
+
+    #[error("Treasury token account doesn't match the proposal")]
+    WrongTreasuryTokenAccount = 67,
 }
 
 impl From<MeepError> for ProgramError {
@@ -0,0 +1,112 @@
+use crate::instruction::SettingsArgs;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::UnixTimestamp, log::sol_log_data, pubkey::Pubkey};
+
+/// Borsh-encoded record logged as program data ("Program data: <base64>") by the
+/// instructions changing a collection, `meep_client::events` decodes them from the logs
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum MeepEvent {
+    Initialized {
+        settings: Pubkey,
+        authority: Pubkey,
+        secondary_creator: Pubkey,
+        collection_id: u64,
+        args: SettingsArgs,
+        pda_authority: bool,
+        royalty_vault: bool,
+        payment_mint: Option<Pubkey>,
+        membership: bool,
+    },
+
+    /// `args` are active from `effective_at` on, they may still be vetoed until then
+    SettingsUpdated {
+        settings: Pubkey,
+        authority: Pubkey,
+        args: SettingsArgs,
+        effective_at: UnixTimestamp,
+    },
+
+    Minted {
+        settings: Pubkey,
+        minter: Pubkey,
+        mint: Pubkey,
+        token_account: Pubkey,
+        metadata: Pubkey,
+        owner: Pubkey,
+        update_authority: Pubkey,
+        uri: String,
+        reveal_index: Option<u32>,
+        treasury_fee: u64,
+    },
+
+    /// Primary sale of a paid `MintNft`, `price` is in base units of `payment_mint` or lamports
+    Sold {
+        settings: Pubkey,
+        mint: Pubkey,
+        seller: Pubkey,
+        buyer: Pubkey,
+        price: u64,
+        payment_mint: Option<Pubkey>,
+        treasury_fee: u64,
+    },
+
+    /// The mint record is closed, the metadata stays
+    Burned {
+        settings: Pubkey,
+        holder: Pubkey,
+        mint: Pubkey,
+    },
+
+    /// A membership badge moved from the owner of one token account to another
+    MembershipRecovered {
+        settings: Pubkey,
+        mint: Pubkey,
+        from: Pubkey,
+        to: Pubkey,
+    },
+
+    Staked {
+        settings: Pubkey,
+        mint: Pubkey,
+        staker: Pubkey,
+    },
+
+    Unstaked {
+        settings: Pubkey,
+        mint: Pubkey,
+        staker: Pubkey,
+    },
+
+    /// `amount` is in base units of `reward_mint`, paid up to `claimed_at`
+    RewardsClaimed {
+        settings: Pubkey,
+        mint: Pubkey,
+        staker: Pubkey,
+        reward_mint: Pubkey,
+        amount: u64,
+        claimed_at: UnixTimestamp,
+    },
+
+    /// The secondary creator dropped the pending `args` before `effective_at`
+    SettingsVetoed {
+        settings: Pubkey,
+        secondary_creator: Pubkey,
+        args: SettingsArgs,
+        effective_at: UnixTimestamp,
+    },
+
+    /// Pending `args` were written to the settings by `ApplyPendingSettings`
+    SettingsApplied {
+        settings: Pubkey,
+        args: SettingsArgs,
+        effective_at: UnixTimestamp,
+    },
+}
+
+impl MeepEvent {
+    pub fn emit(&self) {
+        if let Ok(data) = self.try_to_vec() {
+            sol_log_data(&[&data]);
+        }
+    }
+}
@@ -1,81 +1,662 @@
-use crate::utils::Pda;
+use crate::{error::MeepError, state::MAX_SIGNERS, utils::Pda};
 use borsh::{BorshDeserialize, BorshSerialize};
+use metaplex_token_metadata::state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
 use solana_program::{
+    clock::UnixTimestamp,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     system_program,
     sysvar::{self, rent},
 };
 
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10000;
+pub const MAX_BASIS_POINTS: u16 = 10000;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 pub struct SettingsArgs {
     pub primary_wallet_percentage: u8, // [0-100]
     pub minimum_price: u64,
+    pub treasury_fee_basis_points: u16, // cut of primary sales paid to the treasury
+    pub treasury_mint_fee: u64,         // lamports the minter pays to the treasury per mint
+    pub settings_delay: UnixTimestamp,  // seconds an update waits before it becomes active
+}
+
+impl SettingsArgs {
+    pub const LEN: usize = 1 + 8 + 2 + 8 + 8;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct InitializeMeepArgs {
+    pub collection_id: u64, // chosen by the authority to run several configurations
+    pub settings: SettingsArgs,
+    pub pda_authority: bool, // NFTs are minted and updated by PDA("authority_meep", settings)
+    pub royalty_vault: bool, // royalties are paid to PDA("royalty_vault_meep", settings)
+    pub payment_mint: Option<Pubkey>, // prices are in this SPL token instead of lamports
+    pub membership: bool,    // NFTs are soulbound badges frozen by PDA("authority_meep", settings)
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub struct MintNftArgs {
     pub seller_fee_basis_points: u16,
     pub token_name: String,
     pub token_symbol: String,
     pub uri: String,
+    pub is_mutable: bool,     // allows UpdateNftMetadata
+    pub mint_record_bump: u8, // of PDA("mint_record_meep", mint), set by the `mint_nft` builder
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct UpdateNftMetadataArgs {
+    pub token_name: Option<String>,
+    pub uri: Option<String>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub lock: bool, // makes the metadata immutable for good
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct MintApprovalArgs {
+    pub max_mints: Option<u32>,            // unlimited if None
+    pub expires_at: Option<UnixTimestamp>, // never expires if None
+    pub delegate: Option<Pubkey>,          // may run SignAsSecondary for the secondary creator
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct RevealModeArgs {
+    pub placeholder_uri: String,
+    pub uri_commitment: [u8; 32], // utils::reveal_commitment of the final URI list
+    pub uri_count: u32,           // length of the final URI list, mints stop after it
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct RevealArgs {
+    pub uri: String,
+    pub proof: Vec<[u8; 32]>, // utils::reveal_proof for the NFT's reveal index
+}
+
+pub fn validate_uri(uri: &str) -> Result<(), MeepError> {
+    if uri.len() > MAX_URI_LENGTH {
+        return Err(MeepError::UriTooLong);
+    }
+
+    Ok(())
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct TreasuryTransferArgs {
+    pub amount: u64, // lamports, or base units of the token when token accounts are passed
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct GovernanceArgs {
+    pub quorum: u32,
+    pub threshold_percentage: u8,       // [1-100]
+    pub voting_period: UnixTimestamp,   // seconds
+    pub execution_delay: UnixTimestamp, // seconds between the end of voting and execution
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct StakingArgs {
+    pub reward_rate: u64, // base units of the reward mint per staked NFT and second
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct StakeArgs {
+    pub stake_record_bump: u8,  // of PDA("stake_meep", mint)
+    pub stake_account_bump: u8, // of PDA("stake_account_meep", mint)
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct UnstakeArgs {
+    /// Returns the NFT without paying the accrued rewards, e.g. when the reward vault is empty
+    pub forfeit_rewards: bool,
+}
+
+/// Change applied by `ExecuteProposal` once the proposal is approved
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum ProposalAction {
+    /// Signaling proposal
+    None,
+    UpdateSettings(SettingsArgs),
+    /// Lamports, or base units of the token held in `treasury_token_account`
+    TreasuryTransfer {
+        recipient: Pubkey,
+        amount: u64,
+        treasury_token_account: Option<Pubkey>,
+    },
+    AddDelegatedMinter(Pubkey),
+    RevokeDelegatedMinter(Pubkey),
+    /// Hands the settings to a new authority, e.g. the governance PDA, and clears the signer set
+    RotateAuthority(Pubkey),
+}
+
+impl ProposalAction {
+    pub const MAX_LEN: usize = 1 + 32 + 8 + (1 + 32);
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct CreateProposalArgs {
+    pub description_uri: String,
+    pub action: ProposalAction,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct CastVoteArgs {
+    pub approve: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct UpdateSignersArgs {
+    pub signers: Vec<Pubkey>, // empty to let the authority sign alone again
+    pub threshold: u8,        // [1; signers.len()]
+}
+
+impl GovernanceArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        if self.quorum == 0
+            || self.threshold_percentage == 0
+            || self.threshold_percentage > 100
+            || self.voting_period <= 0
+            || self.execution_delay < 0
+        {
+            return Err(MeepError::InvalidGovernanceConfig);
+        }
+
+        Ok(())
+    }
+}
+
+impl CreateProposalArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        validate_uri(&self.description_uri)?;
+
+        if let ProposalAction::UpdateSettings(settings) = &self.action {
+            settings.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl UpdateSignersArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        if self.signers.is_empty() {
+            if self.threshold != 0 {
+                return Err(MeepError::InvalidSignerSet);
+            }
+
+            return Ok(());
+        }
+
+        if self.signers.len() > MAX_SIGNERS
+            || self.threshold == 0
+            || self.threshold as usize > self.signers.len()
+        {
+            return Err(MeepError::InvalidSignerSet);
+        }
+
+        for (index, signer) in self.signers.iter().enumerate() {
+            if self.signers[..index].contains(signer) {
+                return Err(MeepError::InvalidSignerSet);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SettingsArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        if self.primary_wallet_percentage > 100 {
+            return Err(MeepError::PercentageLimitExceeded);
+        }
+
+        if self.treasury_fee_basis_points > MAX_BASIS_POINTS {
+            return Err(MeepError::InvalidTreasuryFee);
+        }
+
+        if self.settings_delay < 0 {
+            return Err(MeepError::InvalidSettingsDelay);
+        }
+
+        Ok(())
+    }
 }
 
-#[derive(BorshSerialize, BorshDeserialize)]
+impl MintNftArgs {
+    /// Checks the arguments against the Metaplex limits. The program runs it before any
+    /// CPI, clients can run it to catch bad rows before sending.
+    pub fn validate(&self) -> Result<(), MeepError> {
+        if self.token_name.len() > MAX_NAME_LENGTH {
+            return Err(MeepError::NameTooLong);
+        }
+
+        if self.token_symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(MeepError::SymbolTooLong);
+        }
+
+        validate_uri(&self.uri)?;
+
+        if self.seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+            return Err(MeepError::InvalidSellerFee);
+        }
+
+        Ok(())
+    }
+}
+
+impl UpdateNftMetadataArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        if let Some(token_name) = &self.token_name {
+            if token_name.len() > MAX_NAME_LENGTH {
+                return Err(MeepError::NameTooLong);
+            }
+        }
+
+        if let Some(uri) = &self.uri {
+            validate_uri(uri)?;
+        }
+
+        if let Some(seller_fee_basis_points) = self.seller_fee_basis_points {
+            if seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+                return Err(MeepError::InvalidSellerFee);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RevealModeArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        validate_uri(&self.placeholder_uri)?;
+
+        if self.uri_count == 0 {
+            return Err(MeepError::InvalidRevealUriCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl RevealArgs {
+    pub fn validate(&self) -> Result<(), MeepError> {
+        validate_uri(&self.uri)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub enum MeepInstructions {
     /// 0. `[signer, writable]` Authority (Primary creator, Payer)
     /// 1. `[signer]` Secondary creator
-    /// 2. `[writable]` Settings account, PDA("settings_meep", authority, program_id)
+    /// 2. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
     /// 3. `[]` System program
     /// 4. `[]` Rent program
-    InitializeMeep(SettingsArgs),
+    /// 5. `[]` Payment mint (Only if `payment_mint` is set)
+    InitializeMeep(InitializeMeepArgs),
 
     /// 0. `[signer]` Authority (Primary creator, Payer)
-    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, program_id)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// With a signer set the authority's signature only counts if it is a member,
+    /// distinct member signatures have to reach the threshold.
+    /// The new settings become active after the active `settings_delay`, until then the
+    /// secondary creator can veto them. A new update replaces the pending one
     UpdateSettings(SettingsArgs),
 
-    /// 0. `[signer, writable]` Authority (Primary creator, Payer)
-    /// 1. `[signer]` Secondary creator
-    /// 2. `[]` Settings account, PDA("settings_meep", authority, program_id)
+    /// 0. `[signer, writable]` Minter (Authority, delegated minter or buyer, Payer)
+    /// 1. `[signer]` Secondary creator (Not a signer if the mint is covered by a mint approval)
+    /// 2. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
     /// 3. `[signer, writable]` Mint account  (Uninitialized)
     /// 4. `[signer, writable]` Token account (Uninitialized)
     /// 5. `[writable]` TokenMetadata account (Uninitialized)
-    /// 6. `[writable]` MasterEdition account (Uninitialized)
+    /// 6. `[writable]` MasterEdition account (Uninitialized, left unused with `membership`)
     /// 7. `[]` System program
     /// 8. `[]` Token program
     /// 9. `[]` Rent program
     /// 10. `[]` Metaplex program
+    /// 11. `[writable]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 12. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 13. `[]` Royalty vault, PDA("royalty_vault_meep", settings, program_id)
+    /// 14. `[writable]` Treasury, PDA("treasury_meep", settings, program_id)
+    /// 15+. `[signer]` Members of the signer set (Only with a signer set and the authority as minter)
+    /// 15. `[writable]` Authority, or its payment mint token account (Only for a buyer)
+    /// 16. `[writable]` Buyer's payment mint token account (Only for a buyer with `payment_mint`)
+    /// 17. `[writable]` Treasury's payment mint token account (Only for a buyer with `payment_mint`)
+    ///
+    /// In reveal mode `uri` is replaced with the placeholder URI and metadata is mutable,
+    /// minting fails once every committed URI has an NFT.
+    /// The token is owned by the authority, or by the buyer. With `membership` the mint's freeze authority
+    /// is the authority PDA, which owns the token account and freezes it. Such badges have
+    /// no master edition, the mint authority is removed instead. A delegated minter is added as a creator
+    /// with zero share and hands the update authority over to the authority.
+    /// With `pda_authority` the authority PDA is the mint and update authority and a
+    /// zero share creator instead, and the authority verifies itself when minting.
+    /// Without the secondary creator's signature their creator entry stays unverified
+    /// until `SignAsSecondary`, and the secondary approval PDA is added as an unverified
+    /// creator with zero share for their delegate to verify.
+    /// Once `minimum_price` is set anyone else can mint as a buyer, except membership badges.
+    /// The buyer pays `minimum_price` to the authority for the primary sale, in the payment
+    /// mint or in lamports, and `treasury_fee_basis_points` of it to the treasury. The authority
+    /// PDA mints for the buyer and hands the update authority to the authority without
+    /// `pda_authority`. Mints of the authority and delegated minters are free.
     MintNft(MintNftArgs),
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// `MintNft` fails once `uri_count` NFTs are minted in reveal mode
+    EnableRevealMode(RevealModeArgs),
+
+    /// 0. `[signer]` Authority (Primary creator, Update authority)
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[]` Mint account
+    /// 3. `[writable]` TokenMetadata account
+    /// 4. `[writable]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 5. `[]` Metaplex program
+    /// 6. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 7+. `[signer]` Members of the signer set (Only with a signer set)
+    Reveal(RevealArgs),
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[]` Delegated minter
+    /// 3+. `[signer]` Members of the signer set (Only with a signer set)
+    AddDelegatedMinter,
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[]` Delegated minter
+    /// 3+. `[signer]` Members of the signer set (Only with a signer set)
+    RevokeDelegatedMinter,
+
+    /// 0. `[signer]` Secondary creator
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    ///
+    /// Replaces the current approval and its delegate
+    GrantMintApproval(MintApprovalArgs),
+
+    /// 0. `[signer]` Secondary creator
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    RevokeMintApproval,
+
+    /// 0. `[signer]` Secondary creator or the delegate of their mint approval
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[]` Metaplex program
+    /// 3. `[]` Secondary approval PDA, PDA("secondary_approval_meep", settings, program_id)
+    /// 4+3n. `[]` Mint account of the n-th NFT
+    /// 5+3n. `[writable]` TokenMetadata account of the n-th NFT
+    /// 6+3n. `[]` Mint record account of the n-th NFT, PDA("mint_record_meep", mint, program_id)
+    ///
+    /// Verifies the secondary approval PDA listed on NFTs minted under the mint approval.
+    /// Fails for NFTs without a mint record of the settings.
+    /// Metaplex only accepts the creator's own signature, so the secondary creator's own
+    /// entry is verified only when they sign themselves, not by the delegate.
+    SignAsSecondary,
+
+    /// 0. `[signer]` Authority (Primary creator, Update authority)
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[]` Mint account
+    /// 3. `[writable]` TokenMetadata account
+    /// 4. `[]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 5. `[]` Metaplex program
+    /// 6. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 7+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// Locking hands the update authority to PDA("locked_meep", program_id), which the
+    /// program never signs for
+    UpdateNftMetadata(UpdateNftMetadataArgs),
+
+    /// 0. `[writable]` Royalty vault, PDA("royalty_vault_meep", settings, program_id)
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[writable]` Authority (Primary creator)
+    /// 3. `[writable]` Secondary creator
+    /// 4. `[]` System program
+    ///
+    /// Permissionless. Splits the vault balance by `primary_wallet_percentage`
+    DistributeRoyalties,
+
+    /// 0. `[signer, writable]` Depositor
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[writable]` Treasury, PDA("treasury_meep", settings, program_id)
+    /// 3. `[]` System program
+    /// 4. `[writable]` Depositor token account (Only for SPL token deposits)
+    /// 5. `[writable]` Treasury token account, owned by the treasury
+    /// 6. `[]` Token program
+    Deposit(TreasuryTransferArgs),
+
+    /// 0. `[signer]` Governance PDA, PDA("governance_meep", settings, program_id)
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[writable]` Treasury, PDA("treasury_meep", settings, program_id)
+    /// 3. `[writable]` Recipient (Recipient token account for SPL token withdrawals)
+    /// 4. `[]` System program
+    /// 5. `[writable]` Treasury token account (Only for SPL token withdrawals)
+    /// 6. `[]` Token program
+    ///
+    /// Only the program signs for the governance PDA, so this is invoked by approved
+    /// governance actions only
+    Withdraw(TreasuryTransferArgs),
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// Applies to proposals created afterwards
+    ConfigureGovernance(GovernanceArgs),
+
+    /// 0. `[signer, writable]` Proposer (NFT holder, Payer)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[writable]` Proposal account, PDA("proposal_meep", settings, proposal_count, program_id)
+    /// 3. `[]` Proposer token account holding the NFT
+    /// 4. `[]` NFT mint account
+    /// 5. `[]` TokenMetadata account
+    /// 6. `[]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 7. `[]` System program
+    /// 8. `[]` Rent program
+    CreateProposal(CreateProposalArgs),
+
+    /// 0. `[signer, writable]` Voter (NFT holder, Payer)
+    /// 1. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2. `[writable]` Proposal account, PDA("proposal_meep", settings, index, program_id)
+    /// 3. `[writable]` Vote record account, PDA("vote_meep", proposal, mint, program_id)
+    /// 4. `[]` Voter token account holding the NFT
+    /// 5. `[]` NFT mint account
+    /// 6. `[]` TokenMetadata account
+    /// 7. `[]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 8. `[]` System program
+    /// 9. `[]` Rent program
+    ///
+    /// One vote per NFT, the vote record stays with the NFT when it changes hands. NFTs
+    /// minted after the proposal was created don't vote
+    CastVote(CastVoteArgs),
+
+    /// 0. `[]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 1. `[writable]` Proposal account, PDA("proposal_meep", settings, index, program_id)
+    ///
+    /// Permissionless once voting has ended. Uses the quorum and threshold the proposal
+    /// was created under
+    FinalizeProposal,
+
+    /// 0. `[writable]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 1. `[writable]` Proposal account, PDA("proposal_meep", settings, index, program_id)
+    /// 2. `[]` Governance PDA, PDA("governance_meep", settings, program_id)
+    ///
+    /// For `TreasuryTransfer` only:
+    /// 3. `[writable]` Treasury, PDA("treasury_meep", settings, program_id)
+    /// 4. `[writable]` Recipient
+    /// 5. `[]` System program
+    /// 6. `[]` Meep program
+    /// 7. `[writable]` Treasury token account (Only for SPL token transfers)
+    /// 8. `[]` Token program
+    ///
+    /// Permissionless once the proposal is approved and the execution delay has passed.
+    /// Treasury transfers invoke `Withdraw` signed by the governance PDA, settings updates
+    /// are pending for the settings delay like `UpdateSettings`
+    ExecuteProposal,
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    /// 2+. `[signer]` Members of the current signer set (Only with a signer set)
+    ///
+    /// Replaces the signer set and threshold, requires the current threshold
+    UpdateSigners(UpdateSignersArgs),
+
+    /// 0. `[writable]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    ///
+    /// Permissionless once the pending settings are effective. Mints use them from the
+    /// effective time on either way
+    ApplyPendingSettings,
+
+    /// 0. `[signer]` Secondary creator
+    /// 1. `[writable]` Settings account, PDA("settings_meep", authority, collection_id, program_id)
+    ///
+    /// Drops the pending settings before they become effective
+    VetoPendingSettings,
+
+    /// 0. `[signer, writable]` Holder (Owner of the token account, receives the reclaimed rent)
+    /// 1. `[]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[writable]` Mint account
+    /// 3. `[writable]` Token account holding the NFT
+    /// 4. `[writable]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 5. `[writable]` TokenMetadata account
+    /// 6. `[writable]` MasterEdition account
+    /// 7. `[]` Token program
+    /// 8. `[]` Metaplex program
+    ///
+    /// Burns the token through token-metadata's `BurnNft` and closes the token account, the
+    /// metadata, the master edition and the mint record.
+    /// Membership badges are only burned by `RevokeMembership`
+    BurnNft,
+
+    /// 0. `[signer, writable]` Authority (Primary creator, receives the reclaimed rent)
+    /// 1. `[]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[writable]` Mint account
+    /// 3. `[writable]` Token account holding the badge
+    /// 4. `[writable]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 5. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 6. `[]` Token program
+    /// 7+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// Thaws and burns a membership badge as the PDA, its owner or delegate, and closes
+    /// the mint record. A token account owned by the PDA is closed as well, a member's
+    /// empty token account stays with them
+    RevokeMembership,
+
+    /// 0. `[signer, writable]` Authority (Primary creator, receives the reclaimed rent)
+    /// 1. `[]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[]` Mint account
+    /// 3. `[writable]` Token account holding the badge
+    /// 4. `[]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 5. `[signer]` Member
+    /// 6. `[writable]` Member token account (Empty, for the mint, owned by the member)
+    /// 7. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 8. `[]` Token program
+    /// 9+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// Moves a membership badge to a member's wallet, both to hand out a freshly minted
+    /// badge and to recover one from a lost wallet. The member approves the PDA as
+    /// delegate of the new token account before it is frozen, so the badge can be
+    /// recovered or revoked again
+    RecoverMembership,
+
+    /// 0. `[signer]` Authority (Primary creator)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[]` Reward mint
+    /// 3+. `[signer]` Members of the signer set (Only with a signer set)
+    ///
+    /// Sets the reward mint and rate of staked NFTs. Rewards accrued until now keep the
+    /// previous rate, and the reward mint can't change while NFTs are staked
+    ConfigureStaking(StakingArgs),
+
+    /// 0. `[signer, writable]` Staker (NFT holder, Payer)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[writable]` Stake record account, PDA("stake_meep", mint, program_id)
+    /// 3. `[writable]` Stake token account, PDA("stake_account_meep", mint, program_id)
+    /// 4. `[writable]` Staker token account holding the NFT
+    /// 5. `[]` NFT mint account
+    /// 6. `[]` TokenMetadata account
+    /// 7. `[]` Mint record account, PDA("mint_record_meep", mint, program_id)
+    /// 8. `[]` System program
+    /// 9. `[]` Token program
+    /// 10. `[]` Rent program
+    ///
+    /// Moves the NFT into a token account owned by its stake record until `Unstake`. The
+    /// bumps of both PDAs are passed in and stored in the stake record
+    Stake(StakeArgs),
+
+    /// 0. `[signer, writable]` Staker (receives the reclaimed rent)
+    /// 1. `[writable]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[writable]` Stake record account, PDA("stake_meep", mint, program_id)
+    /// 3. `[writable]` Stake token account, PDA("stake_account_meep", mint, program_id)
+    /// 4. `[writable]` Staker token account (For the mint, owned by the staker)
+    /// 5. `[]` NFT mint account
+    /// 6. `[]` Token program
+    /// 7. `[writable]` Staker reward token account (For the reward mint)
+    /// 8. `[writable]` Reward mint
+    /// 9. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 10. `[writable]` Reward vault (Only if the authority PDA isn't the reward mint's mint authority)
+    ///
+    /// Pays the unclaimed rewards like `ClaimRewards`, then returns the NFT and closes the
+    /// stake accounts. With `forfeit_rewards` nothing is paid, so the NFT comes back even
+    /// when the rewards can't be paid
+    Unstake(UnstakeArgs),
+
+    /// 0. `[signer]` Staker
+    /// 1. `[]` Settings account, PDA("settings_meep", original_authority, collection_id, program_id)
+    /// 2. `[writable]` Stake record account, PDA("stake_meep", mint, program_id)
+    /// 3. `[writable]` Staker reward token account (For the reward mint)
+    /// 4. `[writable]` Reward mint
+    /// 5. `[]` Authority PDA, PDA("authority_meep", settings, program_id)
+    /// 6. `[]` Token program
+    /// 7. `[writable]` Reward vault (Only if the authority PDA isn't the reward mint's mint authority)
+    ///
+    /// Pays `reward_rate` for every second since staking or the last claim, at the rates in
+    /// effect during that time. Rewards are minted by the authority PDA when it is the mint
+    /// authority of the reward mint, otherwise they are transferred from a reward mint token
+    /// account owned by the PDA
+    ClaimRewards,
 }
 
+/// Builders derive the settings from `original_authority`, the authority that initialized
+/// them. `authority` is the current one, it differs after `RotateAuthority`
 impl MeepInstructions {
     pub fn initialize_meep(
         program_id: &Pubkey,
         authority: &Pubkey,
         secondary_creator: &Pubkey,
-        args: &SettingsArgs,
+        args: &InitializeMeepArgs,
     ) -> Instruction {
-        let settings_pubkey = Pda::settings_pubkey_with_bump(program_id, authority).0;
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, authority, args.collection_id).0;
+
+        let mut accounts = vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*secondary_creator, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(rent::ID, false),
+        ];
+        if let Some(payment_mint) = args.payment_mint {
+            accounts.push(AccountMeta::new_readonly(payment_mint, false));
+        }
 
         Instruction::new_with_borsh(
             *program_id,
             &MeepInstructions::InitializeMeep(args.clone()),
-            vec![
-                AccountMeta::new(*authority, true),
-                AccountMeta::new_readonly(*secondary_creator, true),
-                AccountMeta::new(settings_pubkey, false),
-                AccountMeta::new_readonly(system_program::ID, false),
-                AccountMeta::new_readonly(rent::ID, false),
-            ],
+            accounts,
         )
     }
 
     pub fn update_settings(
         program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
         authority: &Pubkey,
         args: &SettingsArgs,
     ) -> Instruction {
-        let settings_pubkey = Pda::settings_pubkey_with_bump(program_id, authority).0;
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
 
         Instruction::new_with_borsh(
             *program_id,
@@ -87,25 +668,37 @@ impl MeepInstructions {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn mint_nft(
         program_id: &Pubkey,
-        authority: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        minter: &Pubkey,
         secondary_creator: &Pubkey,
+        secondary_creator_signs: bool,
         mint: &Pubkey,
         token_account: &Pubkey,
         mint_args: &MintNftArgs,
     ) -> Instruction {
         let metadata = Pda::metadata_pubkey(mint);
         let edition = Pda::master_edition_pubkey(mint);
-        let settings = Pda::settings_pubkey_with_bump(program_id, authority).0;
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let (mint_record, mint_record_bump) = Pda::mint_record_pubkey_with_bump(program_id, mint);
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+        let royalty_vault = Pda::royalty_vault_pubkey_with_bump(program_id, &settings).0;
+        let treasury = Pda::treasury_pubkey_with_bump(program_id, &settings).0;
 
         Instruction::new_with_borsh(
             *program_id,
-            &MeepInstructions::MintNft(mint_args.clone()),
+            &MeepInstructions::MintNft(MintNftArgs {
+                mint_record_bump,
+                ..mint_args.clone()
+            }),
             vec![
-                AccountMeta::new(*authority, true),
-                AccountMeta::new_readonly(*secondary_creator, true),
-                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*minter, true),
+                AccountMeta::new_readonly(*secondary_creator, secondary_creator_signs),
+                AccountMeta::new(settings, false),
                 AccountMeta::new(*mint, true),
                 AccountMeta::new(*token_account, true),
                 AccountMeta::new(metadata, false),
@@ -114,7 +707,749 @@ impl MeepInstructions {
                 AccountMeta::new_readonly(spl_token::ID, false),
                 AccountMeta::new_readonly(sysvar::rent::ID, false),
                 AccountMeta::new_readonly(metaplex_token_metadata::ID, false),
+                AccountMeta::new(mint_record, false),
+                AccountMeta::new_readonly(authority_pda, false),
+                AccountMeta::new_readonly(royalty_vault, false),
+                AccountMeta::new(treasury, false),
+            ],
+        )
+    }
+
+    pub fn enable_reveal_mode(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        args: &RevealModeArgs,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::EnableRevealMode(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        )
+    }
+
+    pub fn reveal(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        args: &RevealArgs,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let metadata = Pda::metadata_pubkey(mint);
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Reveal(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(metadata, false),
+                AccountMeta::new(mint_record, false),
+                AccountMeta::new_readonly(metaplex_token_metadata::ID, false),
+                AccountMeta::new_readonly(authority_pda, false),
+            ],
+        )
+    }
+
+    pub fn add_delegated_minter(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        minter: &Pubkey,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::AddDelegatedMinter,
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings_pubkey, false),
+                AccountMeta::new_readonly(*minter, false),
+            ],
+        )
+    }
+
+    pub fn revoke_delegated_minter(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        minter: &Pubkey,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::RevokeDelegatedMinter,
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings_pubkey, false),
+                AccountMeta::new_readonly(*minter, false),
+            ],
+        )
+    }
+
+    pub fn grant_mint_approval(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Pubkey,
+        args: &MintApprovalArgs,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::GrantMintApproval(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*secondary_creator, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        )
+    }
+
+    pub fn revoke_mint_approval(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Pubkey,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::RevokeMintApproval,
+            vec![
+                AccountMeta::new_readonly(*secondary_creator, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        )
+    }
+
+    /// `signer` is the secondary creator or the delegate of their mint approval
+    pub fn sign_as_secondary(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        signer: &Pubkey,
+        mints: &[Pubkey],
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let secondary_approval =
+            Pda::secondary_approval_pubkey_with_bump(program_id, &settings_pubkey).0;
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(metaplex_token_metadata::ID, false),
+            AccountMeta::new_readonly(secondary_approval, false),
+        ];
+        for mint in mints {
+            accounts.push(AccountMeta::new_readonly(*mint, false));
+            accounts.push(AccountMeta::new(Pda::metadata_pubkey(mint), false));
+            accounts.push(AccountMeta::new_readonly(
+                Pda::mint_record_pubkey_with_bump(program_id, mint).0,
+                false,
+            ));
+        }
+
+        Instruction::new_with_borsh(*program_id, &MeepInstructions::SignAsSecondary, accounts)
+    }
+
+    pub fn update_nft_metadata(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        args: &UpdateNftMetadataArgs,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let metadata = Pda::metadata_pubkey(mint);
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::UpdateNftMetadata(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(metadata, false),
+                AccountMeta::new_readonly(mint_record, false),
+                AccountMeta::new_readonly(metaplex_token_metadata::ID, false),
+                AccountMeta::new_readonly(authority_pda, false),
+            ],
+        )
+    }
+
+    pub fn distribute_royalties(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        secondary_creator: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let royalty_vault = Pda::royalty_vault_pubkey_with_bump(program_id, &settings).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::DistributeRoyalties,
+            vec![
+                AccountMeta::new(royalty_vault, false),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*authority, false),
+                AccountMeta::new(*secondary_creator, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+        )
+    }
+
+    /// `token_accounts` are the depositor's and the treasury's token accounts for
+    /// SPL token deposits, `None` deposits lamports
+    pub fn deposit(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        depositor: &Pubkey,
+        token_accounts: Option<(&Pubkey, &Pubkey)>,
+        amount: u64,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let treasury = Pda::treasury_pubkey_with_bump(program_id, &settings).0;
+
+        let mut accounts = vec![
+            AccountMeta::new(*depositor, true),
+            AccountMeta::new_readonly(settings, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        if let Some((source, treasury_token_account)) = token_accounts {
+            accounts.push(AccountMeta::new(*source, false));
+            accounts.push(AccountMeta::new(*treasury_token_account, false));
+            accounts.push(AccountMeta::new_readonly(spl_token::ID, false));
+        }
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Deposit(TreasuryTransferArgs { amount }),
+            accounts,
+        )
+    }
+
+    /// `recipient` is a token account when `treasury_token_account` is set
+    pub fn withdraw(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        recipient: &Pubkey,
+        treasury_token_account: Option<&Pubkey>,
+        amount: u64,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let governance = Pda::governance_pubkey_with_bump(program_id, &settings).0;
+        let treasury = Pda::treasury_pubkey_with_bump(program_id, &settings).0;
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(governance, true),
+            AccountMeta::new_readonly(settings, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(*recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        if let Some(treasury_token_account) = treasury_token_account {
+            accounts.push(AccountMeta::new(*treasury_token_account, false));
+            accounts.push(AccountMeta::new_readonly(spl_token::ID, false));
+        }
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Withdraw(TreasuryTransferArgs { amount }),
+            accounts,
+        )
+    }
+
+    pub fn configure_governance(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        args: &GovernanceArgs,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::ConfigureGovernance(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        )
+    }
+
+    /// `proposal_index` is the current `MeepSettings::proposal_count`
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_proposal(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        proposal_index: u64,
+        proposer: &Pubkey,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        args: &CreateProposalArgs,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let proposal = Pda::proposal_pubkey_with_bump(program_id, &settings, proposal_index).0;
+        let metadata = Pda::metadata_pubkey(mint);
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::CreateProposal(args.clone()),
+            vec![
+                AccountMeta::new(*proposer, true),
+                AccountMeta::new(settings, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new_readonly(*token_account, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(metadata, false),
+                AccountMeta::new_readonly(mint_record, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(rent::ID, false),
             ],
         )
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_vote(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+        voter: &Pubkey,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        approve: bool,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let vote_record = Pda::vote_record_pubkey_with_bump(program_id, proposal, mint).0;
+        let metadata = Pda::metadata_pubkey(mint);
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::CastVote(CastVoteArgs { approve }),
+            vec![
+                AccountMeta::new(*voter, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*proposal, false),
+                AccountMeta::new(vote_record, false),
+                AccountMeta::new_readonly(*token_account, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(metadata, false),
+                AccountMeta::new_readonly(mint_record, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(rent::ID, false),
+            ],
+        )
+    }
+
+    pub fn finalize_proposal(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::FinalizeProposal,
+            vec![
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*proposal, false),
+            ],
+        )
+    }
+
+    pub fn execute_proposal(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+        action: &ProposalAction,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let governance = Pda::governance_pubkey_with_bump(program_id, &settings).0;
+
+        let mut accounts = vec![
+            AccountMeta::new(settings, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(governance, false),
+        ];
+        if let ProposalAction::TreasuryTransfer {
+            recipient,
+            treasury_token_account,
+            ..
+        } = action
+        {
+            let treasury = Pda::treasury_pubkey_with_bump(program_id, &settings).0;
+            accounts.push(AccountMeta::new(treasury, false));
+            accounts.push(AccountMeta::new(*recipient, false));
+            accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+            accounts.push(AccountMeta::new_readonly(*program_id, false));
+            if let Some(treasury_token_account) = treasury_token_account {
+                accounts.push(AccountMeta::new(*treasury_token_account, false));
+                accounts.push(AccountMeta::new_readonly(spl_token::ID, false));
+            }
+        }
+
+        Instruction::new_with_borsh(*program_id, &MeepInstructions::ExecuteProposal, accounts)
+    }
+
+    pub fn update_signers(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        signers: &[Pubkey],
+        args: &UpdateSignersArgs,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        let mut instruction = Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::UpdateSigners(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        );
+        MeepInstructions::append_signers(&mut instruction, signers);
+        instruction
+    }
+
+    pub fn apply_pending_settings(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::ApplyPendingSettings,
+            vec![AccountMeta::new(settings, false)],
+        )
+    }
+
+    pub fn veto_pending_settings(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Pubkey,
+    ) -> Instruction {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::VetoPendingSettings,
+            vec![
+                AccountMeta::new_readonly(*secondary_creator, true),
+                AccountMeta::new(settings_pubkey, false),
+            ],
+        )
+    }
+
+    pub fn burn_nft(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        holder: &Pubkey,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+        let token_metadata = Pda::metadata_pubkey(mint);
+        let master_edition = Pda::master_edition_pubkey(mint);
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::BurnNft,
+            vec![
+                AccountMeta::new(*holder, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(*token_account, false),
+                AccountMeta::new(mint_record, false),
+                AccountMeta::new(token_metadata, false),
+                AccountMeta::new(master_edition, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(metaplex_token_metadata::ID, false),
+            ],
+        )
+    }
+
+    pub fn revoke_membership(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::RevokeMembership,
+            vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(*token_account, false),
+                AccountMeta::new(mint_record, false),
+                AccountMeta::new_readonly(authority_pda, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn recover_membership(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        member: &Pubkey,
+        member_token_account: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::RecoverMembership,
+            vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new_readonly(settings, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(*token_account, false),
+                AccountMeta::new_readonly(mint_record, false),
+                AccountMeta::new_readonly(*member, true),
+                AccountMeta::new(*member_token_account, false),
+                AccountMeta::new_readonly(authority_pda, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+        )
+    }
+
+    pub fn configure_staking(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Pubkey,
+        reward_mint: &Pubkey,
+        args: &StakingArgs,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::ConfigureStaking(args.clone()),
+            vec![
+                AccountMeta::new_readonly(*authority, true),
+                AccountMeta::new(settings, false),
+                AccountMeta::new_readonly(*reward_mint, false),
+            ],
+        )
+    }
+
+    pub fn stake(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        staker: &Pubkey,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let (stake_record, stake_record_bump) =
+            Pda::stake_record_pubkey_with_bump(program_id, mint);
+        let (stake_account, stake_account_bump) =
+            Pda::stake_account_pubkey_with_bump(program_id, mint);
+        let mint_record = Pda::mint_record_pubkey_with_bump(program_id, mint).0;
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Stake(StakeArgs {
+                stake_record_bump,
+                stake_account_bump,
+            }),
+            vec![
+                AccountMeta::new(*staker, true),
+                AccountMeta::new(settings, false),
+                AccountMeta::new(stake_record, false),
+                AccountMeta::new(stake_account, false),
+                AccountMeta::new(*token_account, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(Pda::metadata_pubkey(mint), false),
+                AccountMeta::new_readonly(mint_record, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(rent::ID, false),
+            ],
+        )
+    }
+
+    /// `reward_vault` is needed unless the authority PDA mints the rewards
+    #[allow(clippy::too_many_arguments)]
+    pub fn unstake(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        staker: &Pubkey,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        reward_token_account: &Pubkey,
+        reward_mint: &Pubkey,
+        reward_vault: Option<&Pubkey>,
+        forfeit_rewards: bool,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let stake_record = Pda::stake_record_pubkey_with_bump(program_id, mint).0;
+        let stake_account = Pda::stake_account_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        let mut accounts = vec![
+            AccountMeta::new(*staker, true),
+            AccountMeta::new(settings, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(stake_account, false),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new(*reward_token_account, false),
+            AccountMeta::new(*reward_mint, false),
+            AccountMeta::new_readonly(authority_pda, false),
+        ];
+        if let Some(reward_vault) = reward_vault {
+            accounts.push(AccountMeta::new(*reward_vault, false));
+        }
+
+        Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Unstake(UnstakeArgs { forfeit_rewards }),
+            accounts,
+        )
+    }
+
+    /// `reward_vault` is needed unless the authority PDA mints the rewards
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_rewards(
+        program_id: &Pubkey,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        staker: &Pubkey,
+        mint: &Pubkey,
+        reward_token_account: &Pubkey,
+        reward_mint: &Pubkey,
+        reward_vault: Option<&Pubkey>,
+    ) -> Instruction {
+        let settings =
+            Pda::settings_pubkey_with_bump(program_id, original_authority, collection_id).0;
+        let stake_record = Pda::stake_record_pubkey_with_bump(program_id, mint).0;
+        let authority_pda = Pda::authority_pubkey_with_bump(program_id, &settings).0;
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*staker, true),
+            AccountMeta::new_readonly(settings, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(*reward_token_account, false),
+            AccountMeta::new(*reward_mint, false),
+            AccountMeta::new_readonly(authority_pda, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ];
+        if let Some(reward_vault) = reward_vault {
+            accounts.push(AccountMeta::new(*reward_vault, false));
+        }
+
+        Instruction::new_with_borsh(*program_id, &MeepInstructions::ClaimRewards, accounts)
+    }
+
+    /// Adds the accounts paying `minimum_price` to a buyer's `MintNft`.
+    /// `recipient` is the authority, or its payment mint token account when `token_accounts`,
+    /// the buyer's and the treasury's token accounts, are set
+    pub fn append_mint_payment(
+        instruction: &mut Instruction,
+        recipient: &Pubkey,
+        token_accounts: Option<(&Pubkey, &Pubkey)>,
+    ) {
+        instruction
+            .accounts
+            .push(AccountMeta::new(*recipient, false));
+        if let Some((source, treasury_token_account)) = token_accounts {
+            instruction.accounts.push(AccountMeta::new(*source, false));
+            instruction
+                .accounts
+                .push(AccountMeta::new(*treasury_token_account, false));
+        }
+    }
+
+    /// Adds signer set members to an authority instruction, see `UpdateSigners`
+    pub fn append_signers(instruction: &mut Instruction, signers: &[Pubkey]) {
+        instruction.accounts.extend(
+            signers
+                .iter()
+                .map(|signer| AccountMeta::new_readonly(*signer, true)),
+        );
+    }
 }
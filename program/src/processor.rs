@@ -1,14 +1,55 @@
 use self::{
+    add_delegated_minter::process_add_delegated_minter,
+    apply_pending_settings::process_apply_pending_settings, burn_nft::process_burn_nft,
+    cast_vote::process_cast_vote, claim_rewards::process_claim_rewards,
+    configure_governance::process_configure_governance,
+    configure_staking::process_configure_staking, create_proposal::process_create_proposal,
+    deposit::process_deposit, distribute_royalties::process_distribute_royalties,
+    enable_reveal_mode::process_enable_reveal_mode, execute_proposal::process_execute_proposal,
+    finalize_proposal::process_finalize_proposal, grant_mint_approval::process_grant_mint_approval,
     initialize_meep::process_initialize, mint_nft::process_mint,
-    update_settings::process_update_settings,
+    recover_membership::process_recover_membership, reveal::process_reveal,
+    revoke_delegated_minter::process_revoke_delegated_minter,
+    revoke_membership::process_revoke_membership,
+    revoke_mint_approval::process_revoke_mint_approval,
+    sign_as_secondary::process_sign_as_secondary, stake::process_stake, unstake::process_unstake,
+    update_nft_metadata::process_update_nft_metadata, update_settings::process_update_settings,
+    update_signers::process_update_signers, veto_pending_settings::process_veto_pending_settings,
+    withdraw::process_withdraw,
 };
 use crate::instruction::MeepInstructions;
 use borsh::BorshDeserialize;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
+mod add_delegated_minter;
+mod apply_pending_settings;
+mod burn_nft;
+mod cast_vote;
+mod claim_rewards;
+mod configure_governance;
+mod configure_staking;
+mod create_proposal;
+mod deposit;
+mod distribute_royalties;
+mod enable_reveal_mode;
+mod execute_proposal;
+mod finalize_proposal;
+mod grant_mint_approval;
 mod initialize_meep;
 mod mint_nft;
+mod recover_membership;
+mod reveal;
+mod revoke_delegated_minter;
+mod revoke_membership;
+mod revoke_mint_approval;
+mod sign_as_secondary;
+mod stake;
+mod unstake;
+mod update_nft_metadata;
 mod update_settings;
+mod update_signers;
+mod veto_pending_settings;
+mod withdraw;
 
 pub fn process_instruction(
     program_id: &Pubkey,
@@ -17,12 +58,54 @@ pub fn process_instruction(
 ) -> ProgramResult {
     let instruction = MeepInstructions::try_from_slice(instruction_data)?;
     match instruction {
-        MeepInstructions::InitializeMeep(settings) => {
-            process_initialize(program_id, accounts, settings)
-        }
+        MeepInstructions::InitializeMeep(args) => process_initialize(program_id, accounts, args),
         MeepInstructions::UpdateSettings(settings) => {
             process_update_settings(program_id, accounts, settings)
         }
         MeepInstructions::MintNft(mint_args) => process_mint(program_id, accounts, mint_args),
+        MeepInstructions::EnableRevealMode(args) => {
+            process_enable_reveal_mode(program_id, accounts, args)
+        }
+        MeepInstructions::Reveal(args) => process_reveal(program_id, accounts, args),
+        MeepInstructions::AddDelegatedMinter => process_add_delegated_minter(program_id, accounts),
+        MeepInstructions::RevokeDelegatedMinter => {
+            process_revoke_delegated_minter(program_id, accounts)
+        }
+        MeepInstructions::GrantMintApproval(args) => {
+            process_grant_mint_approval(program_id, accounts, args)
+        }
+        MeepInstructions::RevokeMintApproval => process_revoke_mint_approval(program_id, accounts),
+        MeepInstructions::SignAsSecondary => process_sign_as_secondary(program_id, accounts),
+        MeepInstructions::UpdateNftMetadata(args) => {
+            process_update_nft_metadata(program_id, accounts, args)
+        }
+        MeepInstructions::DistributeRoyalties => process_distribute_royalties(program_id, accounts),
+        MeepInstructions::Deposit(args) => process_deposit(program_id, accounts, args),
+        MeepInstructions::Withdraw(args) => process_withdraw(program_id, accounts, args),
+        MeepInstructions::ConfigureGovernance(args) => {
+            process_configure_governance(program_id, accounts, args)
+        }
+        MeepInstructions::CreateProposal(args) => {
+            process_create_proposal(program_id, accounts, args)
+        }
+        MeepInstructions::CastVote(args) => process_cast_vote(program_id, accounts, args),
+        MeepInstructions::FinalizeProposal => process_finalize_proposal(program_id, accounts),
+        MeepInstructions::ExecuteProposal => process_execute_proposal(program_id, accounts),
+        MeepInstructions::UpdateSigners(args) => process_update_signers(program_id, accounts, args),
+        MeepInstructions::ApplyPendingSettings => {
+            process_apply_pending_settings(program_id, accounts)
+        }
+        MeepInstructions::VetoPendingSettings => {
+            process_veto_pending_settings(program_id, accounts)
+        }
+        MeepInstructions::BurnNft => process_burn_nft(program_id, accounts),
+        MeepInstructions::RevokeMembership => process_revoke_membership(program_id, accounts),
+        MeepInstructions::RecoverMembership => process_recover_membership(program_id, accounts),
+        MeepInstructions::ConfigureStaking(args) => {
+            process_configure_staking(program_id, accounts, args)
+        }
+        MeepInstructions::Stake(args) => process_stake(program_id, accounts, args),
+        MeepInstructions::Unstake(args) => process_unstake(program_id, accounts, args),
+        MeepInstructions::ClaimRewards => process_claim_rewards(program_id, accounts),
     }
 }
@@ -0,0 +1,27 @@
+use crate::utils::{assert_authority_signers, get_settings_checked};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+pub fn process_add_delegated_minter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let minter_info = next_account_info(account_iter)?;
+    let mut settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    // A delegated minter mints alone, so adding one takes the multisig
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+
+    settings.add_delegated_minter(minter_info.key)?;
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{error::MeepError, event::MeepEvent, utils::get_settings};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_apply_pending_settings(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings(program_id, settings_info)?;
+
+    let pending = settings
+        .pending_settings
+        .clone()
+        .ok_or(MeepError::NoPendingSettings)?;
+
+    if !settings.activate_pending_settings(Clock::get()?.unix_timestamp) {
+        return Err(MeepError::PendingSettingsNotEffective.into());
+    }
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    MeepEvent::SettingsApplied {
+        settings: *settings_info.key,
+        args: pending.settings,
+        effective_at: pending.effective_at,
+    }
+    .emit();
+
+    Ok(())
+}
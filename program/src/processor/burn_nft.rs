@@ -0,0 +1,99 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    utils::{assert_metadata, close_program_account, get_mint_record_checked, get_settings},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token::state::Account;
+
+/// Index of token-metadata's `BurnNft`, which the pinned `metaplex-token-metadata` crate
+/// predates, so the instruction is built here instead of by the crate
+const BURN_NFT_INSTRUCTION: u8 = 29;
+
+pub fn process_burn_nft(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let holder_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let token_metadata_info = next_account_info(account_iter)?;
+    let master_edition_info = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let metaplex_program = next_account_info(account_iter)?;
+
+    if !holder_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let settings = get_settings(program_id, settings_info)?;
+    if settings.membership {
+        return Err(MeepError::MembershipNftNotBurnable.into());
+    }
+
+    get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+    assert_metadata(mint_info, token_metadata_info)?;
+
+    if *token_account_info.owner != spl_token::ID {
+        return Err(MeepError::NotNftHolder.into());
+    }
+
+    let token_account = Account::unpack(&token_account_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::NotNftHolder))?;
+    if token_account.mint != *mint_info.key
+        || token_account.owner != *holder_info.key
+        || token_account.amount != 1
+    {
+        return Err(MeepError::NotNftHolder.into());
+    }
+
+    // Burns the token and closes the token account, metadata and master edition, their rent
+    // goes to the holder
+    msg!("Burn NFT");
+    invoke(
+        &Instruction {
+            program_id: metaplex_token_metadata::ID,
+            accounts: vec![
+                AccountMeta::new(*token_metadata_info.key, false),
+                AccountMeta::new(*holder_info.key, true),
+                AccountMeta::new(*mint_info.key, false),
+                AccountMeta::new(*token_account_info.key, false),
+                AccountMeta::new(*master_edition_info.key, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data: vec![BURN_NFT_INSTRUCTION],
+        },
+        &[
+            token_metadata_info.clone(),
+            holder_info.clone(),
+            mint_info.clone(),
+            token_account_info.clone(),
+            master_edition_info.clone(),
+            token_program.clone(),
+            metaplex_program.clone(),
+        ],
+    )?;
+
+    // Without the mint record the NFT no longer counts as minted under the settings
+    msg!("Close mint record account");
+    close_program_account(mint_record_info, holder_info)?;
+
+    MeepEvent::Burned {
+        settings: *settings_info.key,
+        holder: *holder_info.key,
+        mint: *mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
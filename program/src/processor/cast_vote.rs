@@ -0,0 +1,107 @@
+use crate::{
+    error::MeepError,
+    instruction::CastVoteArgs,
+    state::{ProposalState, VoteRecord},
+    utils::{
+        assert_nft_holder, create_program_account, get_proposal_checked, get_settings, Pda,
+        VOTE_RECORD_SEED,
+    },
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_cast_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CastVoteArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let voter_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let proposal_info = next_account_info(account_iter)?;
+    let vote_record_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let metadata_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let rent_program = next_account_info(account_iter)?;
+
+    let settings = get_settings(program_id, settings_info)?;
+    let mut proposal = get_proposal_checked(program_id, settings_info, proposal_info)?;
+
+    if proposal.state != ProposalState::Voting
+        || Clock::get()?.unix_timestamp >= proposal.voting_ends_at
+    {
+        return Err(MeepError::VotingClosed.into());
+    }
+
+    let mint_record = assert_nft_holder(
+        program_id,
+        &settings,
+        settings_info,
+        voter_info,
+        token_account_info,
+        mint_info,
+        metadata_info,
+        mint_record_info,
+    )?;
+
+    if mint_record.minted_at > proposal.created_at {
+        return Err(MeepError::MintedAfterProposal.into());
+    }
+
+    let (vote_record_pubkey, bump) =
+        Pda::vote_record_pubkey_with_bump(program_id, proposal_info.key, mint_info.key);
+    if *vote_record_info.key != vote_record_pubkey {
+        return Err(MeepError::WrongVoteRecord.into());
+    }
+
+    if vote_record_info.owner == program_id {
+        return Err(MeepError::AlreadyVoted.into());
+    }
+
+    let seeds = &[
+        VOTE_RECORD_SEED.as_bytes(),
+        proposal_info.key.as_ref(),
+        mint_info.key.as_ref(),
+        &[bump],
+    ];
+
+    msg!("Create vote record account");
+    create_program_account(
+        voter_info,
+        vote_record_info,
+        system_program,
+        rent_program,
+        program_id,
+        VoteRecord::LEN,
+        seeds,
+    )?;
+
+    VoteRecord {
+        proposal: *proposal_info.key,
+        mint: *mint_info.key,
+        voter: *voter_info.key,
+        approve: args.approve,
+    }
+    .serialize(&mut *vote_record_info.data.borrow_mut())?;
+
+    let votes = if args.approve {
+        &mut proposal.yes_votes
+    } else {
+        &mut proposal.no_votes
+    };
+    *votes = votes.checked_add(1).ok_or(MeepError::NumericalOverflow)?;
+    proposal.serialize(&mut *proposal_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    utils::{get_settings, get_stake_record_checked, pay_staking_rewards},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_claim_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let staker_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let stake_record_info = next_account_info(account_iter)?;
+    let reward_token_account_info = next_account_info(account_iter)?;
+    let reward_mint_info = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+
+    if !staker_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let settings = get_settings(program_id, settings_info)?;
+    let staking = settings
+        .staking
+        .as_ref()
+        .ok_or(MeepError::StakingNotConfigured)?;
+
+    let mut stake_record = get_stake_record_checked(program_id, settings_info, stake_record_info)?;
+    if stake_record.staker != *staker_info.key {
+        return Err(MeepError::NotStaker.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let amount = stake_record.settle(staking, now)?;
+
+    pay_staking_rewards(
+        program_id,
+        &settings,
+        settings_info,
+        reward_token_account_info,
+        reward_mint_info,
+        authority_pda_info,
+        token_program,
+        account_iter.next(),
+        amount,
+    )?;
+
+    stake_record.serialize(&mut *stake_record_info.data.borrow_mut())?;
+
+    MeepEvent::RewardsClaimed {
+        settings: *settings_info.key,
+        mint: stake_record.mint,
+        staker: *staker_info.key,
+        reward_mint: staking.reward_mint,
+        amount,
+        claimed_at: now,
+    }
+    .emit();
+
+    Ok(())
+}
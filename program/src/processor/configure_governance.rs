@@ -0,0 +1,36 @@
+use crate::{
+    instruction::GovernanceArgs,
+    state::GovernanceConfig,
+    utils::{assert_authority_signers, get_settings_checked},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+pub fn process_configure_governance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: GovernanceArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    args.validate()?;
+
+    settings.governance = Some(GovernanceConfig {
+        quorum: args.quorum,
+        threshold_percentage: args.threshold_percentage,
+        voting_period: args.voting_period,
+        execution_delay: args.execution_delay,
+    });
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
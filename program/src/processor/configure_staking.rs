@@ -0,0 +1,65 @@
+use crate::{
+    error::MeepError,
+    instruction::StakingArgs,
+    state::StakingConfig,
+    utils::{assert_authority_signers, get_settings},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::state::Mint;
+
+pub fn process_configure_staking(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: StakingArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let reward_mint_info = next_account_info(account_iter)?;
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+
+    if *reward_mint_info.owner != spl_token::ID {
+        return Err(MeepError::WrongRewardMint.into());
+    }
+    Mint::unpack(&reward_mint_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongRewardMint))?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let staking = match settings.staking.take() {
+        Some(mut staking) => {
+            // Open stakes are paid in the reward mint they accrued in
+            if staking.reward_mint != *reward_mint_info.key && staking.staked_count > 0 {
+                return Err(MeepError::RewardMintInUse.into());
+            }
+
+            // Rewards accrued so far keep the old rate
+            staking.checkpoint(now)?;
+            staking.reward_mint = *reward_mint_info.key;
+            staking.reward_rate = args.reward_rate;
+            staking
+        }
+        None => StakingConfig {
+            reward_mint: *reward_mint_info.key,
+            reward_rate: args.reward_rate,
+            staked_count: 0,
+            reward_per_nft: 0,
+            updated_at: now,
+        },
+    };
+    settings.staking = Some(staking);
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
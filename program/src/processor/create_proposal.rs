@@ -0,0 +1,103 @@
+use crate::{
+    error::MeepError,
+    instruction::CreateProposalArgs,
+    state::{Proposal, ProposalState},
+    utils::{assert_nft_holder, create_program_account, get_settings, Pda, PROPOSAL_SEED},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_create_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: CreateProposalArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let proposer_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let proposal_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let metadata_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let rent_program = next_account_info(account_iter)?;
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    let governance = settings
+        .governance
+        .clone()
+        .ok_or(MeepError::GovernanceNotConfigured)?;
+
+    args.validate()?;
+    assert_nft_holder(
+        program_id,
+        &settings,
+        settings_info,
+        proposer_info,
+        token_account_info,
+        mint_info,
+        metadata_info,
+        mint_record_info,
+    )?;
+
+    let index = settings.proposal_count;
+    let (proposal_pubkey, bump) =
+        Pda::proposal_pubkey_with_bump(program_id, settings_info.key, index);
+    if *proposal_info.key != proposal_pubkey {
+        return Err(MeepError::WrongProposal.into());
+    }
+
+    let index_bytes = index.to_le_bytes();
+    let seeds = &[
+        PROPOSAL_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &index_bytes,
+        &[bump],
+    ];
+
+    msg!("Create proposal account");
+    create_program_account(
+        proposer_info,
+        proposal_info,
+        system_program,
+        rent_program,
+        program_id,
+        Proposal::LEN,
+        seeds,
+    )?;
+
+    let created_at = Clock::get()?.unix_timestamp;
+    let voting_ends_at = created_at
+        .checked_add(governance.voting_period)
+        .ok_or(MeepError::NumericalOverflow)?;
+
+    Proposal {
+        settings: *settings_info.key,
+        proposer: *proposer_info.key,
+        index,
+        description_uri: args.description_uri,
+        voting_ends_at,
+        yes_votes: 0,
+        no_votes: 0,
+        state: ProposalState::Voting,
+        governance,
+        action: args.action,
+        created_at,
+        bump,
+    }
+    .serialize(&mut *proposal_info.data.borrow_mut())?;
+
+    settings.proposal_count = index.checked_add(1).ok_or(MeepError::NumericalOverflow)?;
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
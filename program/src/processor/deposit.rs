@@ -0,0 +1,60 @@
+use crate::{
+    instruction::TreasuryTransferArgs,
+    utils::{assert_treasury, assert_treasury_token_account, get_settings, transfer_payment},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+};
+
+pub fn process_deposit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: TreasuryTransferArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let depositor_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let treasury_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    if !depositor_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let settings = get_settings(program_id, settings_info)?;
+    assert_treasury(program_id, &settings, settings_info, treasury_info)?;
+
+    if let Some(source_info) = account_iter.next() {
+        let treasury_token_info = next_account_info(account_iter)?;
+        let token_program = next_account_info(account_iter)?;
+
+        assert_treasury_token_account(treasury_info, treasury_token_info)?;
+
+        msg!("Deposit {} tokens to the treasury", args.amount);
+        return transfer_payment(
+            source_info,
+            treasury_token_info,
+            depositor_info,
+            token_program,
+            args.amount,
+            &[],
+        );
+    }
+
+    msg!("Deposit {} lamports to the treasury", args.amount);
+    invoke(
+        &system_instruction::transfer(depositor_info.key, treasury_info.key, args.amount),
+        &[
+            depositor_info.clone(),
+            treasury_info.clone(),
+            system_program.clone(),
+        ],
+    )
+}
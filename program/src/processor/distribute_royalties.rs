@@ -0,0 +1,89 @@
+use crate::{
+    error::MeepError,
+    utils::{
+        assert_primary_creator, assert_royalty_vault, assert_secondary_creator, get_settings,
+        ROYALTY_VAULT_SEED,
+    },
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::Sysvar,
+};
+
+fn transfer_from_vault<'info>(
+    royalty_vault_info: &AccountInfo<'info>,
+    recipient_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    invoke_signed(
+        &system_instruction::transfer(royalty_vault_info.key, recipient_info.key, lamports),
+        &[
+            royalty_vault_info.clone(),
+            recipient_info.clone(),
+            system_program.clone(),
+        ],
+        &[seeds],
+    )
+}
+
+pub fn process_distribute_royalties(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let royalty_vault_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let authority_info = next_account_info(account_iter)?;
+    let secondary_creator_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    settings.activate_pending_settings(Clock::get()?.unix_timestamp);
+
+    assert_primary_creator(&settings, authority_info)?;
+    assert_secondary_creator(&settings, secondary_creator_info)?;
+    let bump = assert_royalty_vault(program_id, &settings, settings_info, royalty_vault_info)?;
+
+    let total = royalty_vault_info.lamports();
+    let primary = (total as u128)
+        .checked_mul(settings.primary_wallet_percentage as u128)
+        .ok_or(MeepError::NumericalOverflow)?
+        / 100;
+    let primary = primary as u64;
+    let secondary = total - primary;
+
+    let seeds = &[
+        ROYALTY_VAULT_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[bump],
+    ];
+
+    msg!("Distribute {} lamports of royalties", total);
+    transfer_from_vault(
+        royalty_vault_info,
+        authority_info,
+        system_program,
+        primary,
+        seeds,
+    )?;
+    transfer_from_vault(
+        royalty_vault_info,
+        secondary_creator_info,
+        system_program,
+        secondary,
+        seeds,
+    )
+}
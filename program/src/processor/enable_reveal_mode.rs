@@ -0,0 +1,43 @@
+use crate::{
+    error::MeepError,
+    instruction::RevealModeArgs,
+    state::RevealSettings,
+    utils::{assert_authority_signers, get_settings_checked},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+pub fn process_enable_reveal_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: RevealModeArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    args.validate()?;
+
+    // The commitment can't be replaced, otherwise already minted NFTs could be
+    // revealed with different art
+    if settings.reveal.is_some() {
+        return Err(MeepError::RevealAlreadyEnabled.into());
+    }
+
+    settings.reveal = Some(RevealSettings {
+        placeholder_uri: args.placeholder_uri,
+        uri_commitment: args.uri_commitment,
+        uri_count: args.uri_count,
+        minted: 0,
+    });
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
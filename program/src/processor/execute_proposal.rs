@@ -0,0 +1,190 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    instruction::{MeepInstructions, ProposalAction, TreasuryTransferArgs},
+    state::{MeepSettings, ProposalState},
+    utils::{assert_governance_pda, get_proposal_checked, get_settings, GOVERNANCE_SEED},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+fn execute_treasury_transfer<'info>(
+    program_id: &Pubkey,
+    settings_info: &AccountInfo<'info>,
+    governance_info: &AccountInfo<'info>,
+    governance_bump: u8,
+    account_iter: &mut std::slice::Iter<AccountInfo<'info>>,
+    recipient: Pubkey,
+    amount: u64,
+    treasury_token_account: Option<Pubkey>,
+) -> ProgramResult {
+    let treasury_info = next_account_info(account_iter)?;
+    let recipient_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let meep_program = next_account_info(account_iter)?;
+
+    if *recipient_info.key != recipient {
+        return Err(MeepError::WrongRecipient.into());
+    }
+
+    let mut account_infos = vec![
+        governance_info.clone(),
+        settings_info.clone(),
+        treasury_info.clone(),
+        recipient_info.clone(),
+        system_program.clone(),
+    ];
+    if let Some(treasury_token_account) = treasury_token_account {
+        let treasury_token_info = next_account_info(account_iter)?;
+        let token_program = next_account_info(account_iter)?;
+
+        // `Withdraw` takes any token account of the treasury, the proposal approved one
+        if *treasury_token_info.key != treasury_token_account {
+            return Err(MeepError::WrongTreasuryTokenAccount.into());
+        }
+
+        if *token_program.key != spl_token::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        account_infos.push(treasury_token_info.clone());
+        account_infos.push(token_program.clone());
+    }
+
+    // Accounts of the `Withdraw` builder, taken from the passed ones to skip re-deriving
+    // the settings PDAs
+    let accounts: Vec<AccountMeta> = account_infos
+        .iter()
+        .map(|info| {
+            if info.is_writable {
+                AccountMeta::new(*info.key, info.key == governance_info.key)
+            } else {
+                AccountMeta::new_readonly(*info.key, info.key == governance_info.key)
+            }
+        })
+        .collect();
+    account_infos.push(meep_program.clone());
+
+    let seeds = &[
+        GOVERNANCE_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[governance_bump],
+    ];
+
+    msg!("Withdraw from the treasury");
+    invoke_signed(
+        &Instruction::new_with_borsh(
+            *program_id,
+            &MeepInstructions::Withdraw(TreasuryTransferArgs { amount }),
+            accounts,
+        ),
+        &account_infos,
+        &[seeds],
+    )
+}
+
+fn apply_settings_action(settings: &mut MeepSettings, action: ProposalAction) -> ProgramResult {
+    match action {
+        ProposalAction::AddDelegatedMinter(minter) => settings.add_delegated_minter(&minter)?,
+        ProposalAction::RevokeDelegatedMinter(minter) => {
+            settings.revoke_delegated_minter(&minter)?
+        }
+        ProposalAction::RotateAuthority(new_authority) => {
+            if new_authority == settings.secondary_creator {
+                return Err(MeepError::PrimaryAndSecondaryAreSame.into());
+            }
+
+            // The signer set belongs to the previous authority, the new one signs alone
+            // until it configures its own
+            settings.authority = new_authority;
+            settings.signers.clear();
+            settings.signer_threshold = 0;
+        }
+        ProposalAction::None
+        | ProposalAction::UpdateSettings(_)
+        | ProposalAction::TreasuryTransfer { .. } => {}
+    }
+
+    Ok(())
+}
+
+pub fn process_execute_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let settings_info = next_account_info(account_iter)?;
+    let proposal_info = next_account_info(account_iter)?;
+    let governance_info = next_account_info(account_iter)?;
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    let mut proposal = get_proposal_checked(program_id, settings_info, proposal_info)?;
+
+    if proposal.state != ProposalState::Approved {
+        return Err(MeepError::ProposalNotApproved.into());
+    }
+
+    let executable_at = proposal
+        .voting_ends_at
+        .checked_add(proposal.governance.execution_delay)
+        .ok_or(MeepError::NumericalOverflow)?;
+    let now = Clock::get()?.unix_timestamp;
+    if now < executable_at {
+        return Err(MeepError::ExecutionDelayNotPassed.into());
+    }
+
+    let governance_bump =
+        assert_governance_pda(program_id, &settings, settings_info, governance_info)?;
+
+    // Marked first, the proposal account isn't passed to the `Withdraw` invocation
+    proposal.state = ProposalState::Executed;
+    proposal.serialize(&mut *proposal_info.data.borrow_mut())?;
+
+    match proposal.action {
+        ProposalAction::TreasuryTransfer {
+            recipient,
+            amount,
+            treasury_token_account,
+        } => execute_treasury_transfer(
+            program_id,
+            settings_info,
+            governance_info,
+            governance_bump,
+            account_iter,
+            recipient,
+            amount,
+            treasury_token_account,
+        ),
+        // Same path as `UpdateSettings`, so the settings delay and the secondary creator's
+        // veto apply to governance updates too
+        ProposalAction::UpdateSettings(args) => {
+            args.validate()?;
+            let effective_at = settings.schedule_update(args.clone(), now)?;
+            settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+            MeepEvent::SettingsUpdated {
+                settings: *settings_info.key,
+                authority: *governance_info.key,
+                args,
+                effective_at,
+            }
+            .emit();
+
+            Ok(())
+        }
+        action => {
+            apply_settings_action(&mut settings, action)?;
+            settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    error::MeepError,
+    state::ProposalState,
+    utils::{get_proposal_checked, get_settings},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_finalize_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let settings_info = next_account_info(account_iter)?;
+    let proposal_info = next_account_info(account_iter)?;
+
+    get_settings(program_id, settings_info)?;
+    let mut proposal = get_proposal_checked(program_id, settings_info, proposal_info)?;
+
+    if proposal.state != ProposalState::Voting {
+        return Err(MeepError::VotingClosed.into());
+    }
+
+    if Clock::get()?.unix_timestamp < proposal.voting_ends_at {
+        return Err(MeepError::VotingNotEnded.into());
+    }
+
+    let governance = &proposal.governance;
+    let votes = proposal.yes_votes as u64 + proposal.no_votes as u64;
+    let approved = votes >= governance.quorum as u64
+        && proposal.yes_votes as u64 * 100 >= votes * governance.threshold_percentage as u64;
+
+    proposal.state = if approved {
+        ProposalState::Approved
+    } else {
+        ProposalState::Rejected
+    };
+    proposal.serialize(&mut *proposal_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::{
+    instruction::MintApprovalArgs,
+    state::MintApproval,
+    utils::{assert_secondary_creator, get_settings},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_grant_mint_approval(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: MintApprovalArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let secondary_creator_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings(program_id, settings_info)?;
+
+    if !secondary_creator_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_secondary_creator(&settings, secondary_creator_info)?;
+
+    settings.mint_approval = Some(MintApproval {
+        remaining: args.max_mints,
+        expires_at: args.expires_at,
+        delegate: args.delegate,
+    });
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -1,22 +1,18 @@
 use crate::{
     error::MeepError,
-    instruction::SettingsArgs,
+    event::MeepEvent,
+    instruction::InitializeMeepArgs,
     state::MeepSettings,
-    utils::{Pda, SETTINGS_SEED},
+    utils::{assert_payment_mint, create_program_account, Pda, SETTINGS_SEED},
 };
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
-    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
-    rent::Rent,
-    system_instruction,
-    sysvar::Sysvar,
 };
-use std::convert::TryInto;
 
 pub fn create_settings_account<'info>(
     authority_info: &AccountInfo<'info>,
@@ -26,32 +22,23 @@ pub fn create_settings_account<'info>(
     program_id: &Pubkey,
     settings: &MeepSettings,
 ) -> ProgramResult {
-    let rent = Rent::from_account_info(rent_program)?;
-    let space = settings.try_to_vec()?.len();
-    let lamports = rent.minimum_balance(space);
-
-    let (settings_pubkey, bump) = Pda::settings_pubkey_with_bump(program_id, authority_info.key);
+    let collection_id = settings.collection_id.to_le_bytes();
     let seeds = &[
         SETTINGS_SEED.as_bytes(),
         authority_info.key.as_ref(),
-        &[bump],
+        &collection_id,
+        &[settings.bump],
     ];
 
     msg!("Create settings account");
-    invoke_signed(
-        &system_instruction::create_account(
-            authority_info.key,
-            &settings_pubkey,
-            lamports,
-            space.try_into().unwrap(),
-            program_id,
-        ),
-        &[
-            authority_info.clone(),
-            settings_info.clone(),
-            system_program.clone(),
-        ],
-        &[seeds],
+    create_program_account(
+        authority_info,
+        settings_info,
+        system_program,
+        rent_program,
+        program_id,
+        MeepSettings::LEN,
+        seeds,
     )?;
 
     settings.serialize(&mut *settings_info.data.borrow_mut())?;
@@ -62,7 +49,7 @@ pub fn create_settings_account<'info>(
 pub fn process_initialize(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    settings_args: SettingsArgs,
+    args: InitializeMeepArgs,
 ) -> ProgramResult {
     let account_iter = &mut accounts.iter();
 
@@ -77,18 +64,45 @@ pub fn process_initialize(
     }
 
     if authority_info.key == secondary_creator_info.key {
-        return Err(MeepError::PrimareAndSecondaryAreSame.into());
+        return Err(MeepError::PrimaryAndSecondaryAreSame.into());
     }
 
-    if settings_args.primary_wallet_percentage > 100 {
-        return Err(MeepError::PercentageLimitExceeded.into());
+    if let Some(payment_mint) = args.payment_mint {
+        let payment_mint_info = next_account_info(account_iter)?;
+        assert_payment_mint(&payment_mint, payment_mint_info)?;
     }
 
+    let settings_args = args.settings;
+    settings_args.validate()?;
+
+    // The only search for the settings bump, later instructions use the stored one
+    let bump = Pda::settings_pubkey_with_bump(program_id, authority_info.key, args.collection_id).1;
+
     let settings = MeepSettings {
         authority: *authority_info.key,
+        collection_id: args.collection_id,
         secondary_creator: *secondary_creator_info.key,
         primary_wallet_percentage: settings_args.primary_wallet_percentage,
         minimum_price: settings_args.minimum_price,
+        reveal: None,
+        delegated_minters: Vec::new(),
+        mint_approval: None,
+        pda_authority: args.pda_authority,
+        royalty_vault: args.royalty_vault,
+        payment_mint: args.payment_mint,
+        treasury_fee_basis_points: settings_args.treasury_fee_basis_points,
+        treasury_mint_fee: settings_args.treasury_mint_fee,
+        governance: None,
+        proposal_count: 0,
+        original_authority: *authority_info.key,
+        signers: Vec::new(),
+        signer_threshold: 0,
+        settings_delay: settings_args.settings_delay,
+        pending_settings: None,
+        bump,
+        membership: args.membership,
+        staking: None,
+        bumps: Pda::settings_pda_bumps(program_id, settings_info.key),
     };
 
     create_settings_account(
@@ -98,5 +112,20 @@ pub fn process_initialize(
         rent_program,
         program_id,
         &settings,
-    )
+    )?;
+
+    MeepEvent::Initialized {
+        settings: *settings_info.key,
+        authority: settings.authority,
+        secondary_creator: settings.secondary_creator,
+        collection_id: settings.collection_id,
+        args: settings_args,
+        pda_authority: settings.pda_authority,
+        royalty_vault: settings.royalty_vault,
+        payment_mint: settings.payment_mint,
+        membership: settings.membership,
+    }
+    .emit();
+
+    Ok(())
 }
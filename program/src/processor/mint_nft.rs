@@ -1,17 +1,27 @@
 use crate::{
+    error::MeepError,
+    event::MeepEvent,
     instruction::MintNftArgs,
-    state::MeepSettings,
-    utils::{assert_authority, assert_secondary_creator, get_settings_checked, Pda},
+    state::{MeepSettings, MintRecord},
+    utils::{
+        assert_authority_pda, assert_authority_signers, assert_minter, assert_payment_account,
+        assert_royalty_vault, assert_secondary_creator, assert_treasury, create_program_account,
+        get_settings, set_membership_frozen, sign_as_creator, transfer_payment, treasury_cut, Pda,
+        AUTHORITY_SEED, MINT_RECORD_SEED, ROYALTY_VAULT_SEED, SECONDARY_APPROVAL_SEED,
+    },
 };
+use borsh::BorshSerialize;
 use metaplex_token_metadata::{
-    instruction::{create_master_edition, create_metadata_accounts, sign_metadata},
+    instruction::{create_master_edition, create_metadata_accounts, update_metadata_accounts},
     state::Creator,
 };
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::invoke,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
@@ -19,13 +29,15 @@ use solana_program::{
     sysvar::Sysvar,
 };
 use spl_token::{
-    instruction::{initialize_account, initialize_mint, mint_to},
+    instruction::{initialize_account2, initialize_mint, mint_to, set_authority, AuthorityType},
     state::{Account, Mint},
 };
 use std::convert::TryInto;
 
 fn prepare_mint_account<'info>(
-    authority_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+    mint_authority_info: &AccountInfo<'info>,
+    freeze_authority: Option<&Pubkey>,
     mint_info: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
     token_program: &AccountInfo<'info>,
@@ -38,14 +50,14 @@ fn prepare_mint_account<'info>(
     msg!("Create account for mint");
     invoke(
         &system_instruction::create_account(
-            authority_info.key,
+            payer_info.key,
             mint_info.key,
             lamports,
             space.try_into().unwrap(),
             &spl_token::ID,
         ),
         &[
-            authority_info.clone(),
+            payer_info.clone(),
             mint_info.clone(),
             system_program.clone(),
         ],
@@ -53,23 +65,33 @@ fn prepare_mint_account<'info>(
 
     msg!("Initialize mint");
     invoke(
-        &initialize_mint(&spl_token::ID, mint_info.key, authority_info.key, None, 0)?,
+        &initialize_mint(
+            &spl_token::ID,
+            mint_info.key,
+            mint_authority_info.key,
+            freeze_authority,
+            0,
+        )?,
         &[
             mint_info.clone(),
-            authority_info.clone(),
+            mint_authority_info.clone(),
             token_program.clone(),
             rent_program.clone(),
         ],
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn prepare_token_account<'info>(
-    authority_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+    mint_authority_info: &AccountInfo<'info>,
+    owner: &Pubkey,
     token_account_info: &AccountInfo<'info>,
     mint_info: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
     token_program: &AccountInfo<'info>,
     rent_program: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
 ) -> ProgramResult {
     let rent = Rent::from_account_info(rent_program)?;
     let space = Account::LEN;
@@ -78,14 +100,14 @@ fn prepare_token_account<'info>(
     msg!("Create token account");
     invoke(
         &system_instruction::create_account(
-            authority_info.key,
+            payer_info.key,
             token_account_info.key,
             lamports,
             space.try_into().unwrap(),
             &spl_token::ID,
         ),
         &[
-            authority_info.clone(),
+            payer_info.clone(),
             token_account_info.clone(),
             system_program.clone(),
         ],
@@ -93,14 +115,8 @@ fn prepare_token_account<'info>(
 
     msg!("Initialize token account");
     invoke(
-        &initialize_account(
-            &spl_token::ID,
-            token_account_info.key,
-            mint_info.key,
-            authority_info.key,
-        )?,
+        &initialize_account2(&spl_token::ID, token_account_info.key, mint_info.key, owner)?,
         &[
-            authority_info.clone(),
             token_account_info.clone(),
             mint_info.clone(),
             token_program.clone(),
@@ -109,28 +125,31 @@ fn prepare_token_account<'info>(
     )?;
 
     msg!("Mint one token");
-    invoke(
+    invoke_signed(
         &mint_to(
             &spl_token::ID,
             mint_info.key,
             token_account_info.key,
-            authority_info.key,
-            &[authority_info.key],
+            mint_authority_info.key,
+            &[mint_authority_info.key],
             1,
         )?,
         &[
-            authority_info.clone(),
+            mint_authority_info.clone(),
             token_account_info.clone(),
             mint_info.clone(),
             token_program.clone(),
         ],
+        &[authority_seeds],
     )
 }
 
 #[allow(clippy::too_many_arguments)]
 fn init_metadata<'info>(
-    authority_info: &AccountInfo<'info>,
+    minter_info: &AccountInfo<'info>,
+    update_authority_info: &AccountInfo<'info>,
     secondary_creator_info: &AccountInfo<'info>,
+    royalty_vault_info: &AccountInfo<'info>,
     mint_info: &AccountInfo<'info>,
     token_metadata_info: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
@@ -138,42 +157,87 @@ fn init_metadata<'info>(
     metaplex_program: &AccountInfo<'info>,
     settings: &MeepSettings,
     mint_args: MintNftArgs,
+    is_mutable: bool,
+    secondary_approval: Option<Pubkey>,
+    authority_seeds: &[&[u8]],
+    royalty_vault_seeds: &[&[u8]],
 ) -> ProgramResult {
-    let metadata_pubkey = Pda::metadata_pubkey(mint_info.key);
+    let update_authority = *update_authority_info.key;
+
+    // The vault collects all royalties and splits them by the current settings
+    let (primary_share, secondary_share) = if settings.royalty_vault {
+        (0, 0)
+    } else {
+        (
+            settings.primary_wallet_percentage,
+            100 - settings.primary_wallet_percentage,
+        )
+    };
 
     let primary_creator = Creator {
         address: settings.authority,
-        share: settings.primary_wallet_percentage,
-        verified: true,
+        share: primary_share,
+        verified: settings.authority == update_authority,
     };
 
     let secondary_creator = Creator {
         address: settings.secondary_creator,
-        share: 100 - settings.primary_wallet_percentage,
-        verified: false,
+        share: secondary_share,
+        verified: settings.secondary_creator == update_authority,
     };
 
-    let creators = Some(vec![primary_creator, secondary_creator]);
+    let mut creators = vec![primary_creator, secondary_creator];
+
+    if settings.royalty_vault {
+        creators.push(Creator {
+            address: *royalty_vault_info.key,
+            share: 100,
+            verified: false,
+        });
+    }
+
+    // Metaplex requires the update authority to be one of the creators, so a
+    // delegated minter or the authority PDA is listed with zero share
+    if creators
+        .iter()
+        .all(|creator| creator.address != update_authority)
+    {
+        creators.push(Creator {
+            address: update_authority,
+            share: 0,
+            verified: true,
+        });
+    }
 
+    if let Some(secondary_approval) = secondary_approval {
+        creators.push(Creator {
+            address: secondary_approval,
+            share: 0,
+            verified: false,
+        });
+    }
+
+    // Metaplex checks the caller-supplied metadata and edition addresses
     msg!("Create metadata account");
-    invoke(
+    invoke_signed(
         &create_metadata_accounts(
             metaplex_token_metadata::ID,
-            metadata_pubkey,
+            *token_metadata_info.key,
             *mint_info.key,
-            *authority_info.key,
-            *authority_info.key,
-            *authority_info.key,
+            update_authority,
+            *minter_info.key,
+            update_authority,
             mint_args.token_name,
             mint_args.token_symbol,
             mint_args.uri,
-            creators,
+            Some(creators),
             mint_args.seller_fee_basis_points,
             true,
-            false,
+            is_mutable,
         ),
         &[
-            authority_info.clone(),
+            minter_info.clone(),
+            update_authority_info.clone(),
             secondary_creator_info.clone(),
             token_metadata_info.clone(),
             mint_info.clone(),
@@ -181,65 +245,303 @@ fn init_metadata<'info>(
             rent_program.clone(),
             metaplex_program.clone(),
         ],
+        &[authority_seeds],
     )?;
 
-    invoke(
-        &sign_metadata(
-            metaplex_token_metadata::ID,
-            metadata_pubkey,
-            *secondary_creator_info.key,
-        ),
-        &[
-            token_metadata_info.clone(),
-            secondary_creator_info.clone(),
-            metaplex_program.clone(),
-        ],
+    if settings.royalty_vault {
+        sign_as_creator(
+            royalty_vault_info,
+            token_metadata_info,
+            metaplex_program,
+            &[royalty_vault_seeds],
+        )?;
+    }
+
+    if settings.authority == *minter_info.key && settings.authority != update_authority {
+        sign_as_creator(minter_info, token_metadata_info, metaplex_program, &[])?;
+    }
+
+    if !secondary_creator_info.is_signer {
+        // Verified later with `SignAsSecondary` under the standing mint approval
+        return Ok(());
+    }
+
+    if settings.secondary_creator == update_authority {
+        // Already verified at creation
+        return Ok(());
+    }
+
+    sign_as_creator(
+        secondary_creator_info,
+        token_metadata_info,
+        metaplex_program,
+        &[],
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn init_master_edition<'info>(
-    authority_info: &AccountInfo<'info>,
+    minter_info: &AccountInfo<'info>,
+    update_authority_info: &AccountInfo<'info>,
     mint_info: &AccountInfo<'info>,
     token_metadata_info: &AccountInfo<'info>,
     master_edition_info: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
     rent_program: &AccountInfo<'info>,
     metaplex_program: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
 ) -> ProgramResult {
-    let edition_pubkey = Pda::master_edition_pubkey(mint_info.key);
-
     msg!("Create master edition account");
-    invoke(
+    invoke_signed(
         &create_master_edition(
             metaplex_token_metadata::ID,
-            edition_pubkey,
+            *master_edition_info.key,
             *mint_info.key,
-            *authority_info.key,
-            *authority_info.key,
+            *update_authority_info.key,
+            *update_authority_info.key,
             *token_metadata_info.key,
-            *authority_info.key,
+            *minter_info.key,
             Some(0),
         ),
         &[
-            authority_info.clone(),
+            minter_info.clone(),
+            update_authority_info.clone(),
             mint_info.clone(),
             token_metadata_info.clone(),
             master_edition_info.clone(),
             system_program.clone(),
+            token_program.clone(),
             metaplex_program.clone(),
             rent_program.clone(),
         ],
+        &[authority_seeds],
+    )
+}
+
+fn remove_mint_authority<'info>(
+    mint_authority_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    msg!("Remove mint authority");
+    invoke_signed(
+        &set_authority(
+            &spl_token::ID,
+            mint_info.key,
+            None,
+            AuthorityType::MintTokens,
+            mint_authority_info.key,
+            &[],
+        )?,
+        &[
+            mint_info.clone(),
+            mint_authority_info.clone(),
+            token_program.clone(),
+        ],
+        &[authority_seeds],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn init_mint_record<'info>(
+    minter_info: &AccountInfo<'info>,
+    settings_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    mint_record_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    reveal_index: Option<u32>,
+    bump: u8,
+) -> ProgramResult {
+    // `MintNft` creates the mint, so its record can't exist at another bump
+    let mint_record_pubkey = Pda::mint_record_pubkey(program_id, mint_info.key, bump)
+        .map_err(|_| ProgramError::from(MeepError::WrongMintRecord))?;
+    if *mint_record_info.key != mint_record_pubkey {
+        return Err(MeepError::WrongMintRecord.into());
+    }
+
+    let seeds = &[MINT_RECORD_SEED.as_bytes(), mint_info.key.as_ref(), &[bump]];
+
+    msg!("Create mint record account");
+    create_program_account(
+        minter_info,
+        mint_record_info,
+        system_program,
+        rent_program,
+        program_id,
+        MintRecord::LEN,
+        seeds,
+    )?;
+
+    MintRecord {
+        settings: *settings_info.key,
+        minter: *minter_info.key,
+        reveal_index,
+        revealed: false,
+        bump,
+        minted_at: Clock::get()?.unix_timestamp,
+    }
+    .serialize(&mut *mint_record_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn pay_treasury_mint_fee<'info>(
+    minter_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    settings: &MeepSettings,
+) -> ProgramResult {
+    if settings.treasury_mint_fee == 0 {
+        return Ok(());
+    }
+
+    msg!("Pay treasury mint fee");
+    invoke(
+        &system_instruction::transfer(
+            minter_info.key,
+            treasury_info.key,
+            settings.treasury_mint_fee,
+        ),
+        &[
+            minter_info.clone(),
+            treasury_info.clone(),
+            system_program.clone(),
+        ],
+    )
+}
+
+/// Charges the buyer `minimum_price` for the primary sale, paid to the authority in the
+/// payment mint or in lamports, less the treasury cut. Returns the price and the cut
+fn pay_mint_price<'info>(
+    buyer_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    accounts_iter: &mut std::slice::Iter<AccountInfo<'info>>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    settings: &MeepSettings,
+) -> Result<(u64, u64), ProgramError> {
+    let price = settings.minimum_price;
+    let cut = treasury_cut(settings, price)?;
+    let recipient_info = next_account_info(accounts_iter)?;
+
+    if settings.payment_mint.is_some() {
+        let source_info = next_account_info(accounts_iter)?;
+        let treasury_token_info = next_account_info(accounts_iter)?;
+        let source = assert_payment_account(settings, source_info, buyer_info.key)?;
+        assert_payment_account(settings, recipient_info, &settings.authority)?;
+        assert_payment_account(settings, treasury_token_info, treasury_info.key)?;
+        if source.amount < price {
+            return Err(MeepError::InsufficientPayment.into());
+        }
+
+        msg!("Pay {} tokens for the mint, {} to the treasury", price, cut);
+        transfer_payment(
+            source_info,
+            recipient_info,
+            buyer_info,
+            token_program,
+            price - cut,
+            &[],
+        )?;
+        transfer_payment(
+            source_info,
+            treasury_token_info,
+            buyer_info,
+            token_program,
+            cut,
+            &[],
+        )?;
+    } else {
+        if *recipient_info.key != settings.authority {
+            return Err(MeepError::WrongPaymentAccountOwner.into());
+        }
+        if buyer_info.lamports() < price {
+            return Err(MeepError::InsufficientPayment.into());
+        }
+
+        msg!(
+            "Pay {} lamports for the mint, {} to the treasury",
+            price,
+            cut
+        );
+        for &(destination_info, amount) in &[(recipient_info, price - cut), (treasury_info, cut)] {
+            if amount == 0 {
+                continue;
+            }
+
+            invoke(
+                &system_instruction::transfer(buyer_info.key, destination_info.key, amount),
+                &[
+                    buyer_info.clone(),
+                    destination_info.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+    }
+
+    Ok((price, cut))
+}
+
+fn use_mint_approval(settings: &mut MeepSettings) -> ProgramResult {
+    let approval = settings
+        .mint_approval
+        .as_mut()
+        .ok_or(MeepError::MintApprovalMissing)?;
+
+    if let Some(expires_at) = approval.expires_at {
+        if Clock::get()?.unix_timestamp >= expires_at {
+            return Err(MeepError::MintApprovalExpired.into());
+        }
+    }
+
+    if let Some(remaining) = approval.remaining.as_mut() {
+        *remaining = remaining
+            .checked_sub(1)
+            .ok_or(MeepError::MintApprovalExhausted)?;
+    }
+
+    Ok(())
+}
+
+fn transfer_update_authority<'info>(
+    update_authority_info: &AccountInfo<'info>,
+    token_metadata_info: &AccountInfo<'info>,
+    metaplex_program: &AccountInfo<'info>,
+    settings: &MeepSettings,
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    msg!("Transfer update authority");
+    invoke_signed(
+        &update_metadata_accounts(
+            metaplex_token_metadata::ID,
+            *token_metadata_info.key,
+            *update_authority_info.key,
+            Some(settings.authority),
+            None,
+            None,
+        ),
+        &[
+            token_metadata_info.clone(),
+            update_authority_info.clone(),
+            metaplex_program.clone(),
+        ],
+        &[authority_seeds],
     )
 }
 
 pub fn process_mint(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    mint_args: MintNftArgs,
+    mut mint_args: MintNftArgs,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
-    let authority_info = next_account_info(accounts_iter)?;
+    let minter_info = next_account_info(accounts_iter)?;
     let secondary_creator_info = next_account_info(accounts_iter)?;
     let settings_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
@@ -250,14 +552,93 @@ pub fn process_mint(
     let token_program = next_account_info(accounts_iter)?;
     let rent_program = next_account_info(accounts_iter)?;
     let metaplex_program = next_account_info(accounts_iter)?;
+    let mint_record_info = next_account_info(accounts_iter)?;
+    let authority_pda_info = next_account_info(accounts_iter)?;
+    let royalty_vault_info = next_account_info(accounts_iter)?;
+    let treasury_info = next_account_info(accounts_iter)?;
+
+    mint_args.validate()?;
 
-    let settings = get_settings_checked(program_id, authority_info, settings_info)?;
+    let mut settings = get_settings(program_id, settings_info)?;
+    settings.activate_pending_settings(Clock::get()?.unix_timestamp);
 
-    assert_authority(&settings, authority_info)?;
+    let is_buyer = assert_minter(&settings, minter_info)?;
+    if *minter_info.key == settings.authority {
+        assert_authority_signers(&settings, minter_info, accounts_iter.as_slice())?;
+    }
     assert_secondary_creator(&settings, secondary_creator_info)?;
 
+    if !secondary_creator_info.is_signer {
+        use_mint_approval(&mut settings)?;
+    }
+
+    assert_treasury(program_id, &settings, settings_info, treasury_info)?;
+    pay_treasury_mint_fee(minter_info, treasury_info, system_program, &settings)?;
+    let (price, cut) = if is_buyer {
+        pay_mint_price(
+            minter_info,
+            treasury_info,
+            accounts_iter,
+            system_program,
+            token_program,
+            &settings,
+        )?
+    } else {
+        (0, 0)
+    };
+
+    let reveal_index = match settings.reveal.as_mut() {
+        Some(reveal) => {
+            let index = reveal.minted;
+            if index >= reveal.uri_count {
+                return Err(MeepError::RevealUrisExhausted.into());
+            }
+
+            reveal.minted = index.checked_add(1).ok_or(MeepError::NumericalOverflow)?;
+            mint_args.uri = reveal.placeholder_uri.clone();
+            Some(index)
+        }
+        None => None,
+    };
+
+    let authority_bump =
+        assert_authority_pda(program_id, &settings, settings_info, authority_pda_info)?;
+    let authority_seeds: &[&[u8]] = &[
+        AUTHORITY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[authority_bump],
+    ];
+
+    let royalty_vault_bump =
+        assert_royalty_vault(program_id, &settings, settings_info, royalty_vault_info)?;
+    let royalty_vault_seeds: &[&[u8]] = &[
+        ROYALTY_VAULT_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[royalty_vault_bump],
+    ];
+
+    // Mint and update authority of the NFT. A buyer is never listed as a creator, so the
+    // authority PDA mints for them
+    let update_authority_info = if settings.pda_authority || is_buyer {
+        authority_pda_info
+    } else {
+        minter_info
+    };
+
+    // Membership badges are held by the authority PDA until `RecoverMembership` hands
+    // them to a member, frozen so only the PDA moves them
+    let (freeze_authority, token_owner) = if settings.membership {
+        (Some(authority_pda_info.key), *authority_pda_info.key)
+    } else if is_buyer {
+        (None, *minter_info.key)
+    } else {
+        (None, settings.authority)
+    };
+
     prepare_mint_account(
-        authority_info,
+        minter_info,
+        update_authority_info,
+        freeze_authority,
         mint_info,
         system_program,
         token_program,
@@ -265,17 +646,53 @@ pub fn process_mint(
     )?;
 
     prepare_token_account(
-        authority_info,
+        minter_info,
+        update_authority_info,
+        &token_owner,
         token_account_info,
         mint_info,
         system_program,
         token_program,
         rent_program,
+        authority_seeds,
     )?;
 
+    if settings.membership {
+        msg!("Freeze membership token");
+        set_membership_frozen(
+            true,
+            token_account_info,
+            mint_info,
+            authority_pda_info,
+            token_program,
+            authority_seeds,
+        )?;
+    }
+
+    // Reveal has to update the URI later
+    let is_mutable = mint_args.is_mutable || reveal_index.is_some();
+
+    // Verified by `SignAsSecondary`, also when the secondary creator's delegate runs it
+    let secondary_approval = if secondary_creator_info.is_signer {
+        None
+    } else {
+        let secondary_approval = Pda::settings_pda_pubkey(
+            program_id,
+            SECONDARY_APPROVAL_SEED,
+            settings_info.key,
+            settings.bumps.secondary_approval,
+        )
+        .map_err(|_| ProgramError::from(MeepError::WrongSecondaryApproval))?;
+        Some(secondary_approval)
+    };
+    let uri = mint_args.uri.clone();
+    let mint_record_bump = mint_args.mint_record_bump;
+
     init_metadata(
-        authority_info,
+        minter_info,
+        update_authority_info,
         secondary_creator_info,
+        royalty_vault_info,
         mint_info,
         token_metadata_info,
         system_program,
@@ -283,15 +700,94 @@ pub fn process_mint(
         metaplex_program,
         &settings,
         mint_args,
+        is_mutable,
+        secondary_approval,
+        authority_seeds,
+        royalty_vault_seeds,
     )?;
 
-    init_master_edition(
-        authority_info,
+    // Metaplex hands the freeze authority to the master edition along with the mint
+    // authority, so badges get no edition and their supply is capped by dropping the
+    // mint authority instead
+    if settings.membership {
+        remove_mint_authority(
+            update_authority_info,
+            mint_info,
+            token_program,
+            authority_seeds,
+        )?;
+    } else {
+        init_master_edition(
+            minter_info,
+            update_authority_info,
+            mint_info,
+            token_metadata_info,
+            master_edition_info,
+            system_program,
+            token_program,
+            rent_program,
+            metaplex_program,
+            authority_seeds,
+        )?;
+    }
+
+    // A delegated minter or the authority PDA minting for a buyer hands the update
+    // authority over to the authority
+    if !settings.pda_authority && settings.authority != *update_authority_info.key {
+        transfer_update_authority(
+            update_authority_info,
+            token_metadata_info,
+            metaplex_program,
+            &settings,
+            authority_seeds,
+        )?;
+    }
+
+    init_mint_record(
+        minter_info,
+        settings_info,
         mint_info,
-        token_metadata_info,
-        master_edition_info,
+        mint_record_info,
         system_program,
         rent_program,
-        metaplex_program,
-    )
+        program_id,
+        reveal_index,
+        mint_record_bump,
+    )?;
+
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    let update_authority = if settings.pda_authority {
+        *authority_pda_info.key
+    } else {
+        settings.authority
+    };
+    MeepEvent::Minted {
+        settings: *settings_info.key,
+        minter: *minter_info.key,
+        mint: *mint_info.key,
+        token_account: *token_account_info.key,
+        metadata: *token_metadata_info.key,
+        owner: token_owner,
+        update_authority,
+        uri,
+        reveal_index,
+        treasury_fee: settings.treasury_mint_fee,
+    }
+    .emit();
+
+    if price > 0 {
+        MeepEvent::Sold {
+            settings: *settings_info.key,
+            mint: *mint_info.key,
+            seller: settings.authority,
+            buyer: *minter_info.key,
+            price,
+            payment_mint: settings.payment_mint,
+            treasury_fee: cut,
+        }
+        .emit();
+    }
+
+    Ok(())
 }
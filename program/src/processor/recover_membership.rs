@@ -0,0 +1,139 @@
+use crate::{
+    event::MeepEvent,
+    utils::{
+        assert_authority_pda, assert_authority_signers, assert_membership_nft, get_settings,
+        set_membership_frozen, AUTHORITY_SEED,
+    },
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+};
+use spl_token::instruction::{approve, close_account, transfer};
+
+pub fn process_recover_membership(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let member_info = next_account_info(account_iter)?;
+    let member_token_account_info = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+
+    let settings = get_settings(program_id, settings_info)?;
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+
+    let authority_bump =
+        assert_authority_pda(program_id, &settings, settings_info, authority_pda_info)?;
+    let authority_seeds: &[&[u8]] = &[
+        AUTHORITY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[authority_bump],
+    ];
+
+    let token_account = assert_membership_nft(
+        program_id,
+        &settings,
+        settings_info,
+        mint_info,
+        token_account_info,
+        mint_record_info,
+        authority_pda_info,
+    )?;
+
+    msg!("Thaw membership token");
+    set_membership_frozen(
+        false,
+        token_account_info,
+        mint_info,
+        authority_pda_info,
+        token_program,
+        authority_seeds,
+    )?;
+
+    msg!("Transfer membership token");
+    invoke_signed(
+        &transfer(
+            &spl_token::ID,
+            token_account_info.key,
+            member_token_account_info.key,
+            authority_pda_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_account_info.clone(),
+            member_token_account_info.clone(),
+            authority_pda_info.clone(),
+            token_program.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+
+    // The lost wallet's empty token account stays with it
+    if token_account.owner == *authority_pda_info.key {
+        msg!("Close token account");
+        invoke_signed(
+            &close_account(
+                &spl_token::ID,
+                token_account_info.key,
+                authority_info.key,
+                authority_pda_info.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                authority_info.clone(),
+                authority_pda_info.clone(),
+                token_program.clone(),
+            ],
+            &[authority_seeds],
+        )?;
+    }
+
+    // A frozen account can't change its delegate, so the PDA keeps moving the badge
+    msg!("Approve authority PDA as delegate");
+    invoke(
+        &approve(
+            &spl_token::ID,
+            member_token_account_info.key,
+            authority_pda_info.key,
+            member_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            member_token_account_info.clone(),
+            authority_pda_info.clone(),
+            member_info.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Freeze membership token");
+    set_membership_frozen(
+        true,
+        member_token_account_info,
+        mint_info,
+        authority_pda_info,
+        token_program,
+        authority_seeds,
+    )?;
+
+    MeepEvent::MembershipRecovered {
+        settings: *settings_info.key,
+        mint: *mint_info.key,
+        from: token_account.owner,
+        to: *member_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
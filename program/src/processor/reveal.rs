@@ -0,0 +1,75 @@
+use crate::{
+    error::MeepError,
+    instruction::RevealArgs,
+    utils::{
+        assert_authority_signers, assert_metadata, get_mint_record_checked, get_settings_checked,
+        reveal_leaf, trim_metadata_string, update_metadata_as_authority, verify_reveal_proof,
+    },
+};
+use borsh::BorshSerialize;
+use metaplex_token_metadata::state::Metadata;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+};
+
+pub fn process_reveal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: RevealArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_metadata_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let metaplex_program = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+
+    let settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    assert_metadata(mint_info, token_metadata_info)?;
+    args.validate()?;
+
+    let reveal = settings.reveal.ok_or(MeepError::RevealNotEnabled)?;
+    let mut mint_record =
+        get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+
+    let reveal_index = mint_record.reveal_index.ok_or(MeepError::NotRevealable)?;
+    if mint_record.revealed {
+        return Err(MeepError::AlreadyRevealed.into());
+    }
+
+    let leaf = reveal_leaf(reveal_index, &args.uri);
+    if !verify_reveal_proof(&reveal.uri_commitment, leaf, &args.proof) {
+        return Err(MeepError::InvalidRevealProof.into());
+    }
+
+    let mut data = Metadata::from_account_info(token_metadata_info)?.data;
+    data.name = trim_metadata_string(&data.name);
+    data.symbol = trim_metadata_string(&data.symbol);
+    data.uri = args.uri;
+
+    msg!("Reveal metadata URI");
+    update_metadata_as_authority(
+        program_id,
+        &settings,
+        settings_info,
+        authority_info,
+        authority_pda_info,
+        token_metadata_info,
+        metaplex_program,
+        None,
+        Some(data),
+    )?;
+
+    mint_record.revealed = true;
+    mint_record.serialize(&mut *mint_record_info.data.borrow_mut())?;
+
+    Ok(())
+}
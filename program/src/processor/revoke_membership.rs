@@ -0,0 +1,110 @@
+use crate::{
+    event::MeepEvent,
+    utils::{
+        assert_authority_pda, assert_authority_signers, assert_membership_nft,
+        close_program_account, get_settings, set_membership_frozen, AUTHORITY_SEED,
+    },
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::{burn, close_account};
+
+pub fn process_revoke_membership(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+
+    let settings = get_settings(program_id, settings_info)?;
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+
+    let authority_bump =
+        assert_authority_pda(program_id, &settings, settings_info, authority_pda_info)?;
+    let authority_seeds: &[&[u8]] = &[
+        AUTHORITY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[authority_bump],
+    ];
+
+    let token_account = assert_membership_nft(
+        program_id,
+        &settings,
+        settings_info,
+        mint_info,
+        token_account_info,
+        mint_record_info,
+        authority_pda_info,
+    )?;
+
+    msg!("Thaw membership token");
+    set_membership_frozen(
+        false,
+        token_account_info,
+        mint_info,
+        authority_pda_info,
+        token_program,
+        authority_seeds,
+    )?;
+
+    msg!("Burn membership token");
+    invoke_signed(
+        &burn(
+            &spl_token::ID,
+            token_account_info.key,
+            mint_info.key,
+            authority_pda_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_account_info.clone(),
+            mint_info.clone(),
+            authority_pda_info.clone(),
+            token_program.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+
+    // A member's token account can only be closed by the member
+    if token_account.owner == *authority_pda_info.key {
+        msg!("Close token account");
+        invoke_signed(
+            &close_account(
+                &spl_token::ID,
+                token_account_info.key,
+                authority_info.key,
+                authority_pda_info.key,
+                &[],
+            )?,
+            &[
+                token_account_info.clone(),
+                authority_info.clone(),
+                authority_pda_info.clone(),
+                token_program.clone(),
+            ],
+            &[authority_seeds],
+        )?;
+    }
+
+    msg!("Close mint record account");
+    close_program_account(mint_record_info, authority_info)?;
+
+    MeepEvent::Burned {
+        settings: *settings_info.key,
+        holder: token_account.owner,
+        mint: *mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::{
+    error::MeepError,
+    utils::{
+        assert_metadata, assert_secondary_approval, get_mint_record_checked, get_settings,
+        sign_as_creator, SECONDARY_APPROVAL_SEED,
+    },
+};
+use metaplex_token_metadata::state::Metadata;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_sign_as_secondary(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let signer_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let metaplex_program = next_account_info(account_iter)?;
+    let secondary_approval_info = next_account_info(account_iter)?;
+    let settings = get_settings(program_id, settings_info)?;
+
+    if !signer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The delegate can't sign for the secondary creator's wallet, it only verifies the
+    // approval PDA listed on NFTs minted under the mint approval
+    let is_secondary_creator = *signer_info.key == settings.secondary_creator;
+    let is_delegate = settings
+        .mint_approval
+        .as_ref()
+        .and_then(|approval| approval.delegate)
+        == Some(*signer_info.key);
+    if !is_secondary_creator && !is_delegate {
+        return Err(MeepError::WrongSecondaryCreator.into());
+    }
+
+    let bump = assert_secondary_approval(
+        program_id,
+        &settings,
+        settings_info,
+        secondary_approval_info,
+    )?;
+    let secondary_approval = *secondary_approval_info.key;
+    let secondary_approval_seeds: &[&[u8]] = &[
+        SECONDARY_APPROVAL_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[bump],
+    ];
+
+    let nft_infos = account_iter.as_slice();
+    if nft_infos.len() % 3 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    for nft_info in nft_infos.chunks(3) {
+        let (mint_info, token_metadata_info, mint_record_info) =
+            (&nft_info[0], &nft_info[1], &nft_info[2]);
+
+        // Anyone can list the approval PDA on their metadata, only NFTs minted under the
+        // settings are signed
+        get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+        assert_metadata(mint_info, token_metadata_info)?;
+
+        let metadata = Metadata::from_account_info(token_metadata_info)?;
+        let creators = metadata.data.creators.unwrap_or_default();
+        let is_unverified = |address: &Pubkey| {
+            creators
+                .iter()
+                .any(|creator| creator.address == *address && !creator.verified)
+        };
+
+        // Already verified metadata is skipped so a batch can be safely re-run
+        if is_secondary_creator && is_unverified(&settings.secondary_creator) {
+            msg!("Sign metadata as secondary creator");
+            sign_as_creator(signer_info, token_metadata_info, metaplex_program, &[])?;
+        }
+
+        if is_unverified(&secondary_approval) {
+            msg!("Sign metadata as secondary approval");
+            sign_as_creator(
+                secondary_approval_info,
+                token_metadata_info,
+                metaplex_program,
+                &[secondary_approval_seeds],
+            )?;
+        }
+    }
+
+    Ok(())
+}
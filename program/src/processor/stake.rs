@@ -0,0 +1,178 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    instruction::StakeArgs,
+    state::StakeRecord,
+    utils::{
+        assert_nft_holder, create_program_account, get_settings, Pda, STAKE_ACCOUNT_SEED,
+        STAKE_RECORD_SEED,
+    },
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::{
+    instruction::{initialize_account2, transfer},
+    state::Account,
+};
+
+pub fn process_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: StakeArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let staker_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let stake_record_info = next_account_info(account_iter)?;
+    let stake_account_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let metadata_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let rent_program = next_account_info(account_iter)?;
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    if settings.staking.is_none() {
+        return Err(MeepError::StakingNotConfigured.into());
+    }
+
+    // Badges are frozen, and moving them is up to the authority
+    if settings.membership {
+        return Err(MeepError::MembershipNftNotStakable.into());
+    }
+
+    assert_nft_holder(
+        program_id,
+        &settings,
+        settings_info,
+        staker_info,
+        token_account_info,
+        mint_info,
+        metadata_info,
+        mint_record_info,
+    )?;
+
+    let stake_record_bump = args.stake_record_bump;
+    let stake_record_pubkey =
+        Pda::stake_record_pubkey(program_id, mint_info.key, stake_record_bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongStakeRecord))?;
+    if *stake_record_info.key != stake_record_pubkey {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    let stake_account_bump = args.stake_account_bump;
+    let stake_account_pubkey =
+        Pda::stake_account_pubkey(program_id, mint_info.key, stake_account_bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongStakeRecord))?;
+    if *stake_account_info.key != stake_account_pubkey {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    msg!("Create stake record account");
+    create_program_account(
+        staker_info,
+        stake_record_info,
+        system_program,
+        rent_program,
+        program_id,
+        StakeRecord::LEN,
+        &[
+            STAKE_RECORD_SEED.as_bytes(),
+            mint_info.key.as_ref(),
+            &[stake_record_bump],
+        ],
+    )?;
+
+    msg!("Create stake token account");
+    create_program_account(
+        staker_info,
+        stake_account_info,
+        system_program,
+        rent_program,
+        &spl_token::ID,
+        Account::LEN,
+        &[
+            STAKE_ACCOUNT_SEED.as_bytes(),
+            mint_info.key.as_ref(),
+            &[stake_account_bump],
+        ],
+    )?;
+
+    invoke(
+        &initialize_account2(
+            &spl_token::ID,
+            stake_account_info.key,
+            mint_info.key,
+            stake_record_info.key,
+        )?,
+        &[
+            stake_account_info.clone(),
+            mint_info.clone(),
+            token_program.clone(),
+            rent_program.clone(),
+        ],
+    )?;
+
+    msg!("Transfer NFT to stake token account");
+    invoke(
+        &transfer(
+            &spl_token::ID,
+            token_account_info.key,
+            stake_account_info.key,
+            staker_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_account_info.clone(),
+            stake_account_info.clone(),
+            staker_info.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let staking = settings
+        .staking
+        .as_mut()
+        .ok_or(MeepError::StakingNotConfigured)?;
+    staking.staked_count = staking
+        .staked_count
+        .checked_add(1)
+        .ok_or(MeepError::NumericalOverflow)?;
+
+    StakeRecord {
+        settings: *settings_info.key,
+        mint: *mint_info.key,
+        staker: *staker_info.key,
+        staked_at: now,
+        claimed_at: now,
+        reward_checkpoint: staking.reward_per_nft_at(now)?,
+        bump: stake_record_bump,
+        stake_account_bump,
+    }
+    .serialize(&mut *stake_record_info.data.borrow_mut())?;
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    MeepEvent::Staked {
+        settings: *settings_info.key,
+        mint: *mint_info.key,
+        staker: *staker_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
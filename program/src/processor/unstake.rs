@@ -0,0 +1,151 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    instruction::UnstakeArgs,
+    utils::{
+        close_program_account, get_settings, get_stake_record_checked, pay_staking_rewards, Pda,
+        STAKE_RECORD_SEED,
+    },
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::{close_account, transfer};
+
+pub fn process_unstake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: UnstakeArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let staker_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let stake_record_info = next_account_info(account_iter)?;
+    let stake_account_info = next_account_info(account_iter)?;
+    let token_account_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let reward_token_account_info = next_account_info(account_iter)?;
+    let reward_mint_info = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+
+    if !staker_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut settings = get_settings(program_id, settings_info)?;
+    let mut stake_record = get_stake_record_checked(program_id, settings_info, stake_record_info)?;
+    if stake_record.staker != *staker_info.key {
+        return Err(MeepError::NotStaker.into());
+    }
+    if stake_record.mint != *mint_info.key {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    let stake_account_pubkey =
+        Pda::stake_account_pubkey(program_id, mint_info.key, stake_record.stake_account_bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongStakeRecord))?;
+    if *stake_account_info.key != stake_account_pubkey {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    // Rewards accrued up to now are paid before the record is closed, or dropped when
+    // forfeited so a failing payout can't keep the NFT staked
+    let now = Clock::get()?.unix_timestamp;
+    let staking = settings
+        .staking
+        .as_mut()
+        .ok_or(MeepError::StakingNotConfigured)?;
+    let amount = stake_record.settle(staking, now)?;
+    staking.staked_count = staking.staked_count.saturating_sub(1);
+    let reward_mint = staking.reward_mint;
+
+    if !args.forfeit_rewards {
+        pay_staking_rewards(
+            program_id,
+            &settings,
+            settings_info,
+            reward_token_account_info,
+            reward_mint_info,
+            authority_pda_info,
+            token_program,
+            account_iter.next(),
+            amount,
+        )?;
+
+        MeepEvent::RewardsClaimed {
+            settings: *settings_info.key,
+            mint: *mint_info.key,
+            staker: *staker_info.key,
+            reward_mint,
+            amount,
+            claimed_at: now,
+        }
+        .emit();
+    }
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    let stake_record_seeds: &[&[u8]] = &[
+        STAKE_RECORD_SEED.as_bytes(),
+        mint_info.key.as_ref(),
+        &[stake_record.bump],
+    ];
+
+    msg!("Return NFT to staker");
+    invoke_signed(
+        &transfer(
+            &spl_token::ID,
+            stake_account_info.key,
+            token_account_info.key,
+            stake_record_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            stake_account_info.clone(),
+            token_account_info.clone(),
+            stake_record_info.clone(),
+            token_program.clone(),
+        ],
+        &[stake_record_seeds],
+    )?;
+
+    msg!("Close stake token account");
+    invoke_signed(
+        &close_account(
+            &spl_token::ID,
+            stake_account_info.key,
+            staker_info.key,
+            stake_record_info.key,
+            &[],
+        )?,
+        &[
+            stake_account_info.clone(),
+            staker_info.clone(),
+            stake_record_info.clone(),
+            token_program.clone(),
+        ],
+        &[stake_record_seeds],
+    )?;
+
+    msg!("Close stake record account");
+    close_program_account(stake_record_info, staker_info)?;
+
+    MeepEvent::Unstaked {
+        settings: *settings_info.key,
+        mint: *mint_info.key,
+        staker: *staker_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{
+    error::MeepError,
+    instruction::UpdateNftMetadataArgs,
+    utils::{
+        assert_authority_signers, assert_metadata, get_mint_record_checked, get_settings_checked,
+        trim_metadata_string, update_metadata_as_authority, Pda,
+    },
+};
+use metaplex_token_metadata::state::Metadata;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_update_nft_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: UpdateNftMetadataArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mint_info = next_account_info(account_iter)?;
+    let token_metadata_info = next_account_info(account_iter)?;
+    let mint_record_info = next_account_info(account_iter)?;
+    let metaplex_program = next_account_info(account_iter)?;
+    let authority_pda_info = next_account_info(account_iter)?;
+
+    let settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    assert_metadata(mint_info, token_metadata_info)?;
+    get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+    args.validate()?;
+
+    let locked_update_authority =
+        Pda::locked_update_authority(program_id, settings.bumps.locked)
+            .map_err(|_| ProgramError::from(MeepError::WrongSettingsAccount))?;
+    let metadata = Metadata::from_account_info(token_metadata_info)?;
+    if !metadata.is_mutable || metadata.update_authority == locked_update_authority {
+        return Err(MeepError::MetadataImmutable.into());
+    }
+
+    let mut data = metadata.data;
+    data.name = args
+        .token_name
+        .unwrap_or_else(|| trim_metadata_string(&data.name));
+    data.symbol = trim_metadata_string(&data.symbol);
+    data.uri = args.uri.unwrap_or_else(|| trim_metadata_string(&data.uri));
+    if let Some(seller_fee_basis_points) = args.seller_fee_basis_points {
+        data.seller_fee_basis_points = seller_fee_basis_points;
+    }
+
+    let new_update_authority = if args.lock {
+        Some(locked_update_authority)
+    } else {
+        None
+    };
+
+    msg!("Update metadata account");
+    update_metadata_as_authority(
+        program_id,
+        &settings,
+        settings_info,
+        authority_info,
+        authority_pda_info,
+        token_metadata_info,
+        metaplex_program,
+        new_update_authority,
+        Some(data),
+    )
+}
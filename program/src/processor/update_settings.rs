@@ -1,13 +1,16 @@
 use crate::{
+    event::MeepEvent,
     instruction::SettingsArgs,
-    state::MeepSettings,
-    utils::{assert_authority, get_settings_checked},
+    utils::{assert_authority_signers, get_settings_checked},
 };
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 pub fn process_update_settings(
@@ -19,17 +22,25 @@ pub fn process_update_settings(
 
     let authority_info = next_account_info(account_iter)?;
     let settings_info = next_account_info(account_iter)?;
-    let old_settings = get_settings_checked(program_id, authority_info, settings_info)?;
+    let mut settings = get_settings_checked(program_id, authority_info, settings_info)?;
 
-    assert_authority(&old_settings, authority_info)?;
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    new_settings.validate()?;
 
-    MeepSettings {
-        authority: *authority_info.key,
-        secondary_creator: old_settings.secondary_creator,
-        primary_wallet_percentage: new_settings.primary_wallet_percentage,
-        minimum_price: new_settings.minimum_price,
+    let effective_at =
+        settings.schedule_update(new_settings.clone(), Clock::get()?.unix_timestamp)?;
+    if settings.pending_settings.is_some() {
+        msg!("Settings become effective at {}", effective_at);
     }
-    .serialize(&mut *settings_info.data.borrow_mut())?;
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    MeepEvent::SettingsUpdated {
+        settings: *settings_info.key,
+        authority: settings.authority,
+        args: new_settings,
+        effective_at,
+    }
+    .emit();
 
     Ok(())
 }
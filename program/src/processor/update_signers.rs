@@ -0,0 +1,31 @@
+use crate::{
+    instruction::UpdateSignersArgs,
+    utils::{assert_authority_signers, get_settings_checked},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+pub fn process_update_signers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: UpdateSignersArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings_checked(program_id, authority_info, settings_info)?;
+
+    assert_authority_signers(&settings, authority_info, account_iter.as_slice())?;
+    args.validate()?;
+
+    settings.signers = args.signers;
+    settings.signer_threshold = args.threshold;
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::{
+    error::MeepError,
+    event::MeepEvent,
+    utils::{assert_secondary_creator, get_settings},
+};
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+pub fn process_veto_pending_settings(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let secondary_creator_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let mut settings = get_settings(program_id, settings_info)?;
+
+    if !secondary_creator_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_secondary_creator(&settings, secondary_creator_info)?;
+
+    let pending = settings
+        .pending_settings
+        .take()
+        .ok_or(MeepError::NoPendingSettings)?;
+    if pending.effective_at <= Clock::get()?.unix_timestamp {
+        return Err(MeepError::PendingSettingsEffective.into());
+    }
+    settings.serialize(&mut *settings_info.data.borrow_mut())?;
+
+    MeepEvent::SettingsVetoed {
+        settings: *settings_info.key,
+        secondary_creator: *secondary_creator_info.key,
+        args: pending.settings,
+        effective_at: pending.effective_at,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::{
+    instruction::TreasuryTransferArgs,
+    utils::{
+        assert_governance, assert_treasury, assert_treasury_token_account, get_settings,
+        transfer_payment, TREASURY_SEED,
+    },
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    pubkey::Pubkey,
+    system_instruction,
+};
+
+pub fn process_withdraw(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: TreasuryTransferArgs,
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+
+    let governance_info = next_account_info(account_iter)?;
+    let settings_info = next_account_info(account_iter)?;
+    let treasury_info = next_account_info(account_iter)?;
+    let recipient_info = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+
+    let settings = get_settings(program_id, settings_info)?;
+    assert_governance(program_id, &settings, settings_info, governance_info)?;
+    let bump = assert_treasury(program_id, &settings, settings_info, treasury_info)?;
+
+    let seeds = &[
+        TREASURY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[bump],
+    ];
+
+    if let Some(treasury_token_info) = account_iter.next() {
+        let token_program = next_account_info(account_iter)?;
+
+        assert_treasury_token_account(treasury_info, treasury_token_info)?;
+
+        msg!("Withdraw {} tokens from the treasury", args.amount);
+        return transfer_payment(
+            treasury_token_info,
+            recipient_info,
+            treasury_info,
+            token_program,
+            args.amount,
+            &[seeds],
+        );
+    }
+
+    msg!("Withdraw {} lamports from the treasury", args.amount);
+    invoke_signed(
+        &system_instruction::transfer(treasury_info.key, recipient_info.key, args.amount),
+        &[
+            treasury_info.clone(),
+            recipient_info.clone(),
+            system_program.clone(),
+        ],
+        &[seeds],
+    )
+}
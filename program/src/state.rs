@@ -1,11 +1,425 @@
+use crate::{
+    error::MeepError,
+    instruction::{ProposalAction, SettingsArgs},
+};
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use metaplex_token_metadata::state::MAX_URI_LENGTH;
+use solana_program::{clock::UnixTimestamp, pubkey::Pubkey};
+use std::convert::TryFrom;
+
+pub const MAX_DELEGATED_MINTERS: usize = 10;
+pub const MAX_SIGNERS: usize = 5;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct RevealSettings {
+    pub placeholder_uri: String,
+    pub uri_commitment: [u8; 32],
+
+    /// Number of URIs in the commitment, the last reveal index is `uri_count - 1`
+    pub uri_count: u32,
+
+    pub minted: u32,
+}
+
+impl RevealSettings {
+    pub const LEN: usize = 4 + MAX_URI_LENGTH + 32 + 4 + 4;
+}
+
+/// Standing approval of the secondary creator for mints they don't co-sign
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct MintApproval {
+    pub remaining: Option<u32>,
+    pub expires_at: Option<UnixTimestamp>,
+
+    /// Key of a service allowed to run `SignAsSecondary` for the secondary creator
+    pub delegate: Option<Pubkey>,
+}
+
+impl MintApproval {
+    pub const LEN: usize = (1 + 4) + (1 + 8) + (1 + 32);
+}
+
+/// NFT-weighted governance, one vote per NFT minted under the settings
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct GovernanceConfig {
+    /// Minimum number of votes cast for a proposal to pass
+    pub quorum: u32,
+
+    /// Share of yes votes among the votes cast for a proposal to pass, [1; 100]
+    pub threshold_percentage: u8,
+
+    /// Seconds from proposal creation to the end of voting
+    pub voting_period: UnixTimestamp,
+
+    /// Seconds from the end of voting until an approved proposal can be executed
+    pub execution_delay: UnixTimestamp,
+}
+
+impl GovernanceConfig {
+    pub const LEN: usize = 4 + 1 + 8 + 8;
+}
+
+/// Rewards of NFTs staked under the settings
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct StakingConfig {
+    /// SPL token rewards are paid in
+    pub reward_mint: Pubkey,
+
+    /// Base units of `reward_mint` per staked NFT and second
+    pub reward_rate: u64,
+
+    /// Number of NFTs staked, the reward mint is fixed while there are any
+    pub staked_count: u64,
+
+    /// Rewards an NFT staked since staking was configured would have earned by
+    /// `updated_at`. Moved forward before the rate changes, so changes only apply from then on
+    pub reward_per_nft: u64,
+    pub updated_at: UnixTimestamp,
+}
+
+impl StakingConfig {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 8;
+
+    /// `reward_per_nft` at `now` under the current rate. A stake record earns the
+    /// difference to the value it was checkpointed at
+    pub fn reward_per_nft_at(&self, now: UnixTimestamp) -> Result<u64, MeepError> {
+        let seconds = u64::try_from(now.saturating_sub(self.updated_at)).unwrap_or(0);
+        seconds
+            .checked_mul(self.reward_rate)
+            .and_then(|accrued| accrued.checked_add(self.reward_per_nft))
+            .ok_or(MeepError::NumericalOverflow)
+    }
+
+    /// Settles `reward_per_nft` up to `now`, before the rate changes
+    pub fn checkpoint(&mut self, now: UnixTimestamp) -> Result<(), MeepError> {
+        self.reward_per_nft = self.reward_per_nft_at(now)?;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Bumps of the PDAs derived from the settings, found once by `InitializeMeep`
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy)]
+pub struct PdaBumps {
+    pub authority: u8,
+    pub royalty_vault: u8,
+    pub treasury: u8,
+    pub governance: u8,
+    pub secondary_approval: u8,
+
+    /// Bump of the locked update authority, PDA("locked_meep") is shared by all settings
+    pub locked: u8,
+}
+
+impl PdaBumps {
+    pub const LEN: usize = 6;
+}
+
+/// Settings queued by `UpdateSettings`
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct PendingSettings {
+    pub settings: SettingsArgs,
+
+    /// Clock time the settings become active at
+    pub effective_at: UnixTimestamp,
+}
+
+impl PendingSettings {
+    pub const LEN: usize = SettingsArgs::LEN + 8;
+}
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct MeepSettings {
     pub authority: Pubkey,
+    pub collection_id: u64,
     pub secondary_creator: Pubkey,
 
     pub primary_wallet_percentage: u8,
+
+    /// In base units of `payment_mint`, or in lamports when it's not set
     pub minimum_price: u64,
+
+    pub reveal: Option<RevealSettings>,
+
+    /// Wallets allowed to sign `MintNft` in place of the authority
+    pub delegated_minters: Vec<Pubkey>,
+
+    pub mint_approval: Option<MintApproval>,
+
+    /// NFTs are minted and updated by the settings' authority PDA instead of the
+    /// authority wallet. Chosen at initialization and never changed
+    pub pda_authority: bool,
+
+    /// NFTs list PDA("royalty_vault_meep", settings) as the only creator with a share,
+    /// royalties are split later by `DistributeRoyalties`
+    pub royalty_vault: bool,
+
+    /// SPL token prices are paid in, e.g. USDC. Chosen at initialization
+    pub payment_mint: Option<Pubkey>,
+
+    /// Cut of primary sales paid to the treasury
+    pub treasury_fee_basis_points: u16,
+
+    /// Lamports the minter pays to the treasury for every `MintNft`
+    pub treasury_mint_fee: u64,
+
+    pub governance: Option<GovernanceConfig>,
+
+    /// Number of proposals created, the next proposal's index
+    pub proposal_count: u64,
+
+    /// Authority the settings PDA is derived from, kept when the authority is rotated
+    pub original_authority: Pubkey,
+
+    /// Keys whose signatures count towards `signer_threshold` for authority actions.
+    /// Empty while the authority signs alone
+    pub signers: Vec<Pubkey>,
+
+    pub signer_threshold: u8,
+
+    /// Seconds between `UpdateSettings` and the new settings becoming active
+    pub settings_delay: UnixTimestamp,
+
+    pub pending_settings: Option<PendingSettings>,
+
+    /// Bump of the settings PDA, so its address is checked without searching for it
+    pub bump: u8,
+
+    /// NFTs are soulbound membership badges: their token accounts are frozen by the
+    /// authority PDA, which alone moves or burns them. Chosen at initialization
+    pub membership: bool,
+
+    pub staking: Option<StakingConfig>,
+
+    /// Bumps of the authority PDA, royalty vault, treasury, governance and secondary
+    /// approval PDAs, so their addresses are checked without searching for them
+    pub bumps: PdaBumps,
+}
+
+impl MeepSettings {
+    pub const LEN: usize = 32
+        + 8
+        + 32
+        + 1
+        + 8
+        + (1 + RevealSettings::LEN)
+        + (4 + 32 * MAX_DELEGATED_MINTERS)
+        + (1 + MintApproval::LEN)
+        + 1
+        + 1
+        + (1 + 32)
+        + 2
+        + 8
+        + (1 + GovernanceConfig::LEN)
+        + 8
+        + 32
+        + (4 + 32 * MAX_SIGNERS)
+        + 1
+        + 8
+        + (1 + PendingSettings::LEN)
+        + 1
+        + 1
+        + (1 + StakingConfig::LEN)
+        + PdaBumps::LEN;
+
+    pub fn update(&mut self, args: &SettingsArgs) {
+        self.primary_wallet_percentage = args.primary_wallet_percentage;
+        self.minimum_price = args.minimum_price;
+        self.treasury_fee_basis_points = args.treasury_fee_basis_points;
+        self.treasury_mint_fee = args.treasury_mint_fee;
+        self.settings_delay = args.settings_delay;
+    }
+
+    /// Makes the pending settings active if they are effective at `now`
+    pub fn activate_pending_settings(&mut self, now: UnixTimestamp) -> bool {
+        match self.pending_settings.take() {
+            Some(pending) if pending.effective_at <= now => {
+                self.update(&pending.settings);
+                true
+            }
+            pending => {
+                self.pending_settings = pending;
+                false
+            }
+        }
+    }
+
+    /// Applies `args` right away without a `settings_delay`, otherwise they replace the
+    /// pending settings until the returned effective time
+    pub fn schedule_update(
+        &mut self,
+        args: SettingsArgs,
+        now: UnixTimestamp,
+    ) -> Result<UnixTimestamp, MeepError> {
+        self.activate_pending_settings(now);
+
+        let effective_at = now
+            .checked_add(self.settings_delay)
+            .ok_or(MeepError::NumericalOverflow)?;
+
+        if self.settings_delay == 0 {
+            self.update(&args);
+            self.pending_settings = None;
+        } else {
+            self.pending_settings = Some(PendingSettings {
+                settings: args,
+                effective_at,
+            });
+        }
+
+        Ok(effective_at)
+    }
+
+    pub fn add_delegated_minter(&mut self, minter: &Pubkey) -> Result<(), MeepError> {
+        if *minter == self.authority || self.delegated_minters.contains(minter) {
+            return Err(MeepError::DelegatedMinterAlreadyAdded);
+        }
+
+        if self.delegated_minters.len() >= MAX_DELEGATED_MINTERS {
+            return Err(MeepError::DelegatedMintersLimitExceeded);
+        }
+
+        self.delegated_minters.push(*minter);
+        Ok(())
+    }
+
+    pub fn revoke_delegated_minter(&mut self, minter: &Pubkey) -> Result<(), MeepError> {
+        let position = self
+            .delegated_minters
+            .iter()
+            .position(|delegated_minter| delegated_minter == minter)
+            .ok_or(MeepError::DelegatedMinterNotFound)?;
+
+        self.delegated_minters.remove(position);
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct MintRecord {
+    pub settings: Pubkey,
+
+    /// Authority, delegated minter or buyer that signed `MintNft`
+    pub minter: Pubkey,
+
+    /// Position in the reveal commitment, set only for NFTs minted in reveal mode
+    pub reveal_index: Option<u32>,
+    pub revealed: bool,
+
+    /// Bump of the mint record PDA
+    pub bump: u8,
+
+    /// Clock time of the mint, NFTs minted after a proposal was created don't vote on it
+    pub minted_at: UnixTimestamp,
+}
+
+impl MintRecord {
+    pub const LEN: usize = 32 + 32 + (1 + 4) + 1 + 1 + 8;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ProposalState {
+    Voting,
+    Approved,
+    Rejected,
+    Executed,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Proposal {
+    pub settings: Pubkey,
+    pub proposer: Pubkey,
+    pub index: u64,
+
+    /// Off-chain proposal text
+    pub description_uri: String,
+
+    pub voting_ends_at: UnixTimestamp,
+    pub yes_votes: u32,
+    pub no_votes: u32,
+    pub state: ProposalState,
+
+    /// Settings governance at creation, later `ConfigureGovernance` calls don't change
+    /// how the proposal is counted and executed
+    pub governance: GovernanceConfig,
+
+    /// Applied by `ExecuteProposal` once the proposal is approved
+    pub action: ProposalAction,
+
+    /// Clock time of the creation, the voting snapshot of the collection
+    pub created_at: UnixTimestamp,
+
+    /// Bump of the proposal PDA
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32
+        + 32
+        + 8
+        + (4 + MAX_URI_LENGTH)
+        + 8
+        + 4
+        + 4
+        + 1
+        + GovernanceConfig::LEN
+        + ProposalAction::MAX_LEN
+        + 8
+        + 1;
+}
+
+/// Exists once the NFT `mint` has voted on `proposal`
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub mint: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32 + 32 + 32 + 1;
+}
+
+/// Exists while the NFT `mint` is staked, its token is held by
+/// PDA("stake_account_meep", mint), a token account owned by the stake record
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct StakeRecord {
+    pub settings: Pubkey,
+    pub mint: Pubkey,
+
+    /// Owner of the token account the NFT was staked from, gets it back with `Unstake`
+    pub staker: Pubkey,
+
+    pub staked_at: UnixTimestamp,
+
+    /// Clock time rewards were last paid at
+    pub claimed_at: UnixTimestamp,
+
+    /// `StakingConfig::reward_per_nft` at the last payment, rewards are paid up to it
+    pub reward_checkpoint: u64,
+
+    /// Bumps of the stake record and stake token account PDAs
+    pub bump: u8,
+    pub stake_account_bump: u8,
+}
+
+impl StakeRecord {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 1 + 1;
+
+    /// Rewards accrued since the last payment, moves the checkpoint to `now`
+    pub fn settle(
+        &mut self,
+        staking: &StakingConfig,
+        now: UnixTimestamp,
+    ) -> Result<u64, MeepError> {
+        let reward_per_nft = staking.reward_per_nft_at(now)?;
+        let amount = reward_per_nft
+            .checked_sub(self.reward_checkpoint)
+            .ok_or(MeepError::NumericalOverflow)?;
+
+        self.reward_checkpoint = reward_per_nft;
+        self.claimed_at = now;
+        Ok(amount)
+    }
 }
@@ -1,18 +1,243 @@
-use crate::{error::MeepError, state::MeepSettings};
-use borsh::BorshDeserialize;
-use metaplex_token_metadata::state::{EDITION, PREFIX};
+use crate::{
+    error::MeepError,
+    instruction::MAX_BASIS_POINTS,
+    state::{MeepSettings, MintRecord, PdaBumps, Proposal, StakeRecord},
+};
+use metaplex_token_metadata::{
+    instruction::{sign_metadata, update_metadata_accounts},
+    state::{Data, Key, Metadata, EDITION, PREFIX},
+};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo,
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::{Pubkey, PubkeyError},
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
+};
+use spl_token::{
+    instruction::{freeze_account, mint_to, thaw_account, transfer},
+    state::{Account, Mint},
 };
+use std::convert::TryInto;
 
 pub const SETTINGS_SEED: &str = "settings_meep";
+pub const MINT_RECORD_SEED: &str = "mint_record_meep";
+pub const LOCKED_SEED: &str = "locked_meep";
+pub const AUTHORITY_SEED: &str = "authority_meep";
+pub const ROYALTY_VAULT_SEED: &str = "royalty_vault_meep";
+pub const TREASURY_SEED: &str = "treasury_meep";
+pub const GOVERNANCE_SEED: &str = "governance_meep";
+pub const PROPOSAL_SEED: &str = "proposal_meep";
+pub const VOTE_RECORD_SEED: &str = "vote_meep";
+pub const STAKE_RECORD_SEED: &str = "stake_meep";
+pub const STAKE_ACCOUNT_SEED: &str = "stake_account_meep";
+pub const SECONDARY_APPROVAL_SEED: &str = "secondary_approval_meep";
+
+/// Offset of `Metadata::mint`, after the key and the update authority
+const METADATA_MINT_OFFSET: usize = 1 + 32;
 
 pub struct Pda;
 
 impl Pda {
-    pub fn settings_pubkey_with_bump(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[SETTINGS_SEED.as_bytes(), authority.as_ref()], program_id)
+    pub fn settings_pubkey_with_bump(
+        program_id: &Pubkey,
+        authority: &Pubkey,
+        collection_id: u64,
+    ) -> (Pubkey, u8) {
+        let seeds = &[
+            SETTINGS_SEED.as_bytes(),
+            authority.as_ref(),
+            &collection_id.to_le_bytes(),
+        ];
+
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Settings address from the bump stored in the settings
+    pub fn settings_pubkey(
+        program_id: &Pubkey,
+        authority: &Pubkey,
+        collection_id: u64,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        let seeds = &[
+            SETTINGS_SEED.as_bytes(),
+            authority.as_ref(),
+            &collection_id.to_le_bytes(),
+            &[bump],
+        ];
+
+        Pubkey::create_program_address(seeds, program_id)
+    }
+
+    pub fn mint_record_pubkey_with_bump(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[MINT_RECORD_SEED.as_bytes(), mint.as_ref()], program_id)
+    }
+
+    /// Mint record address from the bump stored in the mint record
+    pub fn mint_record_pubkey(
+        program_id: &Pubkey,
+        mint: &Pubkey,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[MINT_RECORD_SEED.as_bytes(), mint.as_ref(), &[bump]],
+            program_id,
+        )
+    }
+
+    /// Address of a PDA derived from the settings, from the bump stored in `MeepSettings::bumps`
+    pub fn settings_pda_pubkey(
+        program_id: &Pubkey,
+        seed: &str,
+        settings: &Pubkey,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&[seed.as_bytes(), settings.as_ref(), &[bump]], program_id)
+    }
+
+    /// Mint and update authority of NFTs minted under settings with `pda_authority`
+    pub fn authority_pubkey_with_bump(program_id: &Pubkey, settings: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[AUTHORITY_SEED.as_bytes(), settings.as_ref()], program_id)
+    }
+
+    /// System account collecting royalties of NFTs minted under settings with `royalty_vault`
+    pub fn royalty_vault_pubkey_with_bump(program_id: &Pubkey, settings: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[ROYALTY_VAULT_SEED.as_bytes(), settings.as_ref()],
+            program_id,
+        )
+    }
+
+    /// System account holding the DAO's lamports and owning its token accounts
+    pub fn treasury_pubkey_with_bump(program_id: &Pubkey, settings: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[TREASURY_SEED.as_bytes(), settings.as_ref()], program_id)
+    }
+
+    /// Signs governance actions such as treasury withdrawals
+    pub fn governance_pubkey_with_bump(program_id: &Pubkey, settings: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[GOVERNANCE_SEED.as_bytes(), settings.as_ref()], program_id)
+    }
+
+    pub fn proposal_pubkey_with_bump(
+        program_id: &Pubkey,
+        settings: &Pubkey,
+        index: u64,
+    ) -> (Pubkey, u8) {
+        let seeds = &[
+            PROPOSAL_SEED.as_bytes(),
+            settings.as_ref(),
+            &index.to_le_bytes(),
+        ];
+
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Proposal address from the bump stored in the proposal
+    pub fn proposal_pubkey(
+        program_id: &Pubkey,
+        settings: &Pubkey,
+        index: u64,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        let seeds = &[
+            PROPOSAL_SEED.as_bytes(),
+            settings.as_ref(),
+            &index.to_le_bytes(),
+            &[bump],
+        ];
+
+        Pubkey::create_program_address(seeds, program_id)
+    }
+
+    pub fn vote_record_pubkey_with_bump(
+        program_id: &Pubkey,
+        proposal: &Pubkey,
+        mint: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds = &[
+            VOTE_RECORD_SEED.as_bytes(),
+            proposal.as_ref(),
+            mint.as_ref(),
+        ];
+
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn stake_record_pubkey_with_bump(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[STAKE_RECORD_SEED.as_bytes(), mint.as_ref()], program_id)
+    }
+
+    /// Stake record address from the bump stored in the stake record
+    pub fn stake_record_pubkey(
+        program_id: &Pubkey,
+        mint: &Pubkey,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[STAKE_RECORD_SEED.as_bytes(), mint.as_ref(), &[bump]],
+            program_id,
+        )
+    }
+
+    /// Token account holding a staked NFT, owned by its stake record
+    pub fn stake_account_pubkey_with_bump(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[STAKE_ACCOUNT_SEED.as_bytes(), mint.as_ref()], program_id)
+    }
+
+    /// Stake token account address from the bump stored in the stake record
+    pub fn stake_account_pubkey(
+        program_id: &Pubkey,
+        mint: &Pubkey,
+        bump: u8,
+    ) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[STAKE_ACCOUNT_SEED.as_bytes(), mint.as_ref(), &[bump]],
+            program_id,
+        )
+    }
+
+    /// Zero share creator of NFTs minted under a mint approval, verified by `SignAsSecondary`
+    /// for the secondary creator or their delegate
+    pub fn secondary_approval_pubkey_with_bump(
+        program_id: &Pubkey,
+        settings: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[SECONDARY_APPROVAL_SEED.as_bytes(), settings.as_ref()],
+            program_id,
+        )
+    }
+
+    /// Searches the bumps of the settings' PDAs, done once when the settings are created
+    pub fn settings_pda_bumps(program_id: &Pubkey, settings: &Pubkey) -> PdaBumps {
+        PdaBumps {
+            authority: Self::authority_pubkey_with_bump(program_id, settings).1,
+            royalty_vault: Self::royalty_vault_pubkey_with_bump(program_id, settings).1,
+            treasury: Self::treasury_pubkey_with_bump(program_id, settings).1,
+            governance: Self::governance_pubkey_with_bump(program_id, settings).1,
+            secondary_approval: Self::secondary_approval_pubkey_with_bump(program_id, settings).1,
+            locked: Self::locked_update_authority_with_bump(program_id).1,
+        }
+    }
+
+    /// Update authority of locked metadata. The program never signs for it, while
+    /// Metaplex's `update_metadata_accounts` can't make metadata immutable
+    pub fn locked_update_authority_with_bump(program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[LOCKED_SEED.as_bytes()], program_id)
+    }
+
+    /// Locked update authority from the bump stored in `MeepSettings::bumps`
+    pub fn locked_update_authority(program_id: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(&[LOCKED_SEED.as_bytes(), &[bump]], program_id)
     }
 
     pub fn metadata_pubkey(mint: &Pubkey) -> Pubkey {
@@ -37,22 +262,232 @@ impl Pda {
     }
 }
 
+/// Creates a PDA owned by `program_id`. Anyone can send lamports to a PDA before it is
+/// created, which makes `create_account` fail, so a funded address is topped up to the
+/// rent-exempt balance, allocated and assigned instead
+pub fn create_program_account<'info>(
+    payer_info: &AccountInfo<'info>,
+    account_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    space: usize,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    let rent = Rent::from_account_info(rent_program)?;
+    let lamports = rent.minimum_balance(space);
+
+    if account_info.lamports() > 0 {
+        let top_up = lamports.saturating_sub(account_info.lamports());
+        if top_up > 0 {
+            invoke(
+                &system_instruction::transfer(payer_info.key, account_info.key, top_up),
+                &[
+                    payer_info.clone(),
+                    account_info.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(account_info.key, space.try_into().unwrap()),
+            &[account_info.clone(), system_program.clone()],
+            &[seeds],
+        )?;
+
+        return invoke_signed(
+            &system_instruction::assign(account_info.key, program_id),
+            &[account_info.clone(), system_program.clone()],
+            &[seeds],
+        );
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer_info.key,
+            account_info.key,
+            lamports,
+            space.try_into().unwrap(),
+            program_id,
+        ),
+        &[
+            payer_info.clone(),
+            account_info.clone(),
+            system_program.clone(),
+        ],
+        &[seeds],
+    )
+}
+
+/// Moves all lamports of a program account to `destination_info` and clears its data,
+/// the runtime removes the account at the end of the transaction
+pub fn close_program_account(
+    account_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    let lamports = destination_info
+        .lamports()
+        .checked_add(account_info.lamports())
+        .ok_or(MeepError::NumericalOverflow)?;
+
+    **destination_info.lamports.borrow_mut() = lamports;
+    **account_info.lamports.borrow_mut() = 0;
+    account_info.data.borrow_mut().fill(0);
+
+    Ok(())
+}
+
+/// Freezes or thaws a token account of a membership mint, signed by the authority PDA
+/// as the mint's freeze authority
+pub fn set_membership_frozen<'info>(
+    frozen: bool,
+    token_account_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    authority_pda_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> ProgramResult {
+    let instruction = if frozen {
+        freeze_account(
+            &spl_token::ID,
+            token_account_info.key,
+            mint_info.key,
+            authority_pda_info.key,
+            &[],
+        )?
+    } else {
+        thaw_account(
+            &spl_token::ID,
+            token_account_info.key,
+            mint_info.key,
+            authority_pda_info.key,
+            &[],
+        )?
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            token_account_info.clone(),
+            mint_info.clone(),
+            authority_pda_info.clone(),
+            token_program.clone(),
+        ],
+        &[authority_seeds],
+    )
+}
+
+/// Verifies a creator entry of the metadata, signed by the creator or with PDA seeds
+pub fn sign_as_creator<'info>(
+    creator_info: &AccountInfo<'info>,
+    token_metadata_info: &AccountInfo<'info>,
+    metaplex_program: &AccountInfo<'info>,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &sign_metadata(
+            metaplex_token_metadata::ID,
+            *token_metadata_info.key,
+            *creator_info.key,
+        ),
+        &[
+            token_metadata_info.clone(),
+            creator_info.clone(),
+            metaplex_program.clone(),
+        ],
+        signers_seeds,
+    )
+}
+
+/// Updates NFT metadata as its update authority: the authority PDA when settings
+/// use `pda_authority`, the authority wallet otherwise
+#[allow(clippy::too_many_arguments)]
+pub fn update_metadata_as_authority<'info>(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo<'info>,
+    authority_info: &AccountInfo<'info>,
+    authority_pda_info: &AccountInfo<'info>,
+    token_metadata_info: &AccountInfo<'info>,
+    metaplex_program: &AccountInfo<'info>,
+    new_update_authority: Option<Pubkey>,
+    data: Option<Data>,
+) -> ProgramResult {
+    let bump = assert_authority_pda(program_id, settings, settings_info, authority_pda_info)?;
+    let seeds = &[
+        AUTHORITY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[bump],
+    ];
+
+    let update_authority_info = if settings.pda_authority {
+        authority_pda_info
+    } else {
+        authority_info
+    };
+
+    invoke_signed(
+        &update_metadata_accounts(
+            metaplex_token_metadata::ID,
+            *token_metadata_info.key,
+            *update_authority_info.key,
+            new_update_authority,
+            data,
+            None,
+        ),
+        &[
+            token_metadata_info.clone(),
+            update_authority_info.clone(),
+            metaplex_program.clone(),
+        ],
+        &[seeds],
+    )
+}
+
 pub fn get_settings_checked<'info>(
     program_id: &Pubkey,
     authority_info: &AccountInfo<'info>,
     settings_info: &AccountInfo<'info>,
 ) -> Result<MeepSettings, ProgramError> {
-    assert_settings(program_id, authority_info, settings_info)?;
-    let settings = MeepSettings::try_from_slice(&settings_info.data.borrow());
-    settings.map_err(|_| MeepError::WrongSettingsAccount.into())
+    let settings = get_settings(program_id, settings_info)?;
+    if settings.authority != *authority_info.key {
+        return Err(MeepError::WrongSettingsAccount.into());
+    }
+
+    Ok(settings)
+}
+
+/// Verifies the settings account against the original authority and collection stored
+/// in it, for instructions that may be signed by someone other than the authority
+pub fn get_settings(
+    program_id: &Pubkey,
+    settings_info: &AccountInfo,
+) -> Result<MeepSettings, ProgramError> {
+    if settings_info.owner != program_id {
+        return Err(MeepError::WrongSettingsAccount.into());
+    }
+
+    let settings: MeepSettings = try_from_slice_unchecked(&settings_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongSettingsAccount))?;
+
+    assert_settings(program_id, &settings, settings_info)?;
+
+    Ok(settings)
 }
 
 pub fn assert_settings(
     program_id: &Pubkey,
-    authority_info: &AccountInfo,
+    settings: &MeepSettings,
     settings_info: &AccountInfo,
 ) -> ProgramResult {
-    let settings_pubkey = Pda::settings_pubkey_with_bump(program_id, authority_info.key).0;
+    let settings_pubkey = Pda::settings_pubkey(
+        program_id,
+        &settings.original_authority,
+        settings.collection_id,
+        settings.bump,
+    )
+    .map_err(|_| ProgramError::from(MeepError::WrongSettingsAccount))?;
     if *settings_info.key != settings_pubkey {
         return Err(MeepError::WrongSettingsAccount.into());
     }
@@ -60,6 +495,496 @@ pub fn assert_settings(
     Ok(())
 }
 
+pub fn get_mint_record_checked<'info>(
+    program_id: &Pubkey,
+    settings_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    mint_record_info: &AccountInfo<'info>,
+) -> Result<MintRecord, ProgramError> {
+    if mint_record_info.owner != program_id {
+        return Err(MeepError::WrongMintRecord.into());
+    }
+
+    let mint_record: MintRecord = try_from_slice_unchecked(&mint_record_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongMintRecord))?;
+    if mint_record.settings != *settings_info.key {
+        return Err(MeepError::WrongMintRecord.into());
+    }
+
+    let mint_record_pubkey = Pda::mint_record_pubkey(program_id, mint_info.key, mint_record.bump)
+        .map_err(|_| ProgramError::from(MeepError::WrongMintRecord))?;
+    if *mint_record_info.key != mint_record_pubkey {
+        return Err(MeepError::WrongMintRecord.into());
+    }
+
+    Ok(mint_record)
+}
+
+pub fn get_proposal_checked(
+    program_id: &Pubkey,
+    settings_info: &AccountInfo,
+    proposal_info: &AccountInfo,
+) -> Result<Proposal, ProgramError> {
+    if proposal_info.owner != program_id {
+        return Err(MeepError::WrongProposal.into());
+    }
+
+    let proposal: Proposal = try_from_slice_unchecked(&proposal_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongProposal))?;
+    if proposal.settings != *settings_info.key {
+        return Err(MeepError::WrongProposal.into());
+    }
+
+    let proposal_pubkey =
+        Pda::proposal_pubkey(program_id, settings_info.key, proposal.index, proposal.bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongProposal))?;
+    if *proposal_info.key != proposal_pubkey {
+        return Err(MeepError::WrongProposal.into());
+    }
+
+    Ok(proposal)
+}
+
+pub fn get_stake_record_checked(
+    program_id: &Pubkey,
+    settings_info: &AccountInfo,
+    stake_record_info: &AccountInfo,
+) -> Result<StakeRecord, ProgramError> {
+    if stake_record_info.owner != program_id {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    let stake_record: StakeRecord = try_from_slice_unchecked(&stake_record_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongStakeRecord))?;
+    if stake_record.settings != *settings_info.key {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    let stake_record_pubkey =
+        Pda::stake_record_pubkey(program_id, &stake_record.mint, stake_record.bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongStakeRecord))?;
+    if *stake_record_info.key != stake_record_pubkey {
+        return Err(MeepError::WrongStakeRecord.into());
+    }
+
+    Ok(stake_record)
+}
+
+/// Proves that `holder_info` signed and holds an NFT minted under the settings: the
+/// token account holds the mint, Meep has a mint record for it, and its metadata
+/// has a verified creator written by `MintNft`. Returns the mint record
+#[allow(clippy::too_many_arguments)]
+pub fn assert_nft_holder<'info>(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo<'info>,
+    holder_info: &AccountInfo<'info>,
+    token_account_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    metadata_info: &AccountInfo<'info>,
+    mint_record_info: &AccountInfo<'info>,
+) -> Result<MintRecord, ProgramError> {
+    if !holder_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *token_account_info.owner != spl_token::ID {
+        return Err(MeepError::NotNftHolder.into());
+    }
+
+    let token_account = Account::unpack(&token_account_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::NotNftHolder))?;
+    if token_account.mint != *mint_info.key
+        || token_account.owner != *holder_info.key
+        || token_account.amount != 1
+    {
+        return Err(MeepError::NotNftHolder.into());
+    }
+
+    let mint_record =
+        get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+
+    assert_metadata(mint_info, metadata_info)?;
+    let metadata = Metadata::from_account_info(metadata_info)?;
+
+    let settings_pda = |seed, bump| {
+        Pda::settings_pda_pubkey(program_id, seed, settings_info.key, bump)
+            .map_err(|_| ProgramError::from(MeepError::WrongSettingsAccount))
+    };
+    let meep_creators = [
+        settings.authority,
+        mint_record.minter,
+        settings_pda(AUTHORITY_SEED, settings.bumps.authority)?,
+        settings_pda(ROYALTY_VAULT_SEED, settings.bumps.royalty_vault)?,
+    ];
+    let verified = metadata
+        .data
+        .creators
+        .unwrap_or_default()
+        .iter()
+        .any(|creator| creator.verified && meep_creators.contains(&creator.address));
+    if !verified {
+        return Err(MeepError::UnverifiedNft.into());
+    }
+
+    Ok(mint_record)
+}
+
+/// Checks that the NFT is a membership badge of the settings: its mint record belongs to
+/// them, the authority PDA is the mint's freeze authority and the token account holds
+/// the badge under the PDA as owner or delegate. Returns the token account
+pub fn assert_membership_nft<'info>(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    token_account_info: &AccountInfo<'info>,
+    mint_record_info: &AccountInfo<'info>,
+    authority_pda_info: &AccountInfo<'info>,
+) -> Result<Account, ProgramError> {
+    if !settings.membership {
+        return Err(MeepError::NotMembershipNft.into());
+    }
+
+    get_mint_record_checked(program_id, settings_info, mint_info, mint_record_info)?;
+
+    if *mint_info.owner != spl_token::ID || *token_account_info.owner != spl_token::ID {
+        return Err(MeepError::NotMembershipNft.into());
+    }
+
+    let mint = Mint::unpack(&mint_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::NotMembershipNft))?;
+    if mint.freeze_authority != COption::Some(*authority_pda_info.key) {
+        return Err(MeepError::NotMembershipNft.into());
+    }
+
+    let token_account = Account::unpack(&token_account_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::NotMembershipNft))?;
+    let held_by_pda = token_account.owner == *authority_pda_info.key
+        || token_account.delegate == COption::Some(*authority_pda_info.key);
+    if token_account.mint != *mint_info.key || token_account.amount != 1 || !held_by_pda {
+        return Err(MeepError::NotMembershipNft.into());
+    }
+
+    Ok(token_account)
+}
+
+/// Metaplex creates metadata of a mint only at its PDA, so a Metaplex-owned metadata
+/// account of the mint is the one without deriving the address
+pub fn assert_metadata(mint_info: &AccountInfo, metadata_info: &AccountInfo) -> ProgramResult {
+    if *metadata_info.owner != metaplex_token_metadata::ID {
+        return Err(MeepError::WrongMetadataAccount.into());
+    }
+
+    let data = metadata_info.data.borrow();
+    if data.len() < METADATA_MINT_OFFSET + 32
+        || data[0] != Key::MetadataV1 as u8
+        || data[METADATA_MINT_OFFSET..METADATA_MINT_OFFSET + 32] != mint_info.key.to_bytes()
+    {
+        return Err(MeepError::WrongMetadataAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Checks a PDA of the settings against its stored bump, returns the bump to sign with
+fn assert_settings_pda(
+    program_id: &Pubkey,
+    seed: &str,
+    settings_info: &AccountInfo,
+    bump: u8,
+    pda_info: &AccountInfo,
+    error: MeepError,
+) -> Result<u8, ProgramError> {
+    let pda = Pda::settings_pda_pubkey(program_id, seed, settings_info.key, bump)
+        .map_err(|_| ProgramError::from(error.clone()))?;
+    if *pda_info.key != pda {
+        return Err(error.into());
+    }
+
+    Ok(bump)
+}
+
+/// Returns the bump to sign with when the authority PDA is the update authority
+pub fn assert_authority_pda(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    authority_pda_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_settings_pda(
+        program_id,
+        AUTHORITY_SEED,
+        settings_info,
+        settings.bumps.authority,
+        authority_pda_info,
+        MeepError::WrongAuthorityPda,
+    )
+}
+
+pub fn assert_royalty_vault(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    royalty_vault_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_settings_pda(
+        program_id,
+        ROYALTY_VAULT_SEED,
+        settings_info,
+        settings.bumps.royalty_vault,
+        royalty_vault_info,
+        MeepError::WrongRoyaltyVault,
+    )
+}
+
+pub fn assert_treasury(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    treasury_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_settings_pda(
+        program_id,
+        TREASURY_SEED,
+        settings_info,
+        settings.bumps.treasury,
+        treasury_info,
+        MeepError::WrongTreasury,
+    )
+}
+
+/// Returns the bump the governance PDA signs with, its signature is checked separately
+/// where the program invokes itself
+pub fn assert_governance_pda(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    governance_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_settings_pda(
+        program_id,
+        GOVERNANCE_SEED,
+        settings_info,
+        settings.bumps.governance,
+        governance_info,
+        MeepError::WrongGovernance,
+    )
+}
+
+pub fn assert_governance(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    governance_info: &AccountInfo,
+) -> ProgramResult {
+    if !governance_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_governance_pda(program_id, settings, settings_info, governance_info)?;
+
+    Ok(())
+}
+
+/// Returns the bump the secondary approval PDA signs with
+pub fn assert_secondary_approval(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo,
+    secondary_approval_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_settings_pda(
+        program_id,
+        SECONDARY_APPROVAL_SEED,
+        settings_info,
+        settings.bumps.secondary_approval,
+        secondary_approval_info,
+        MeepError::WrongSecondaryApproval,
+    )
+}
+
+/// Treasury token accounts are any token accounts owned by the treasury PDA
+pub fn assert_treasury_token_account(
+    treasury_info: &AccountInfo,
+    token_account_info: &AccountInfo,
+) -> Result<Account, ProgramError> {
+    if *token_account_info.owner != spl_token::ID {
+        return Err(MeepError::WrongPaymentAccount.into());
+    }
+
+    let token_account = Account::unpack(&token_account_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongPaymentAccount))?;
+    if token_account.owner != *treasury_info.key {
+        return Err(MeepError::WrongPaymentAccountOwner.into());
+    }
+
+    Ok(token_account)
+}
+
+/// Treasury part of a primary sale `price`
+pub fn treasury_cut(settings: &MeepSettings, price: u64) -> Result<u64, ProgramError> {
+    let cut = (price as u128)
+        .checked_mul(settings.treasury_fee_basis_points as u128)
+        .ok_or(MeepError::NumericalOverflow)?
+        / MAX_BASIS_POINTS as u128;
+
+    Ok(cut as u64)
+}
+
+pub fn assert_payment_mint(
+    payment_mint: &Pubkey,
+    payment_mint_info: &AccountInfo,
+) -> ProgramResult {
+    if payment_mint_info.key != payment_mint || *payment_mint_info.owner != spl_token::ID {
+        return Err(MeepError::WrongPaymentMint.into());
+    }
+
+    Mint::unpack(&payment_mint_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongPaymentMint))?;
+
+    Ok(())
+}
+
+/// Checks a token account that pays or receives a price in the settings' payment mint
+pub fn assert_payment_account(
+    settings: &MeepSettings,
+    token_account_info: &AccountInfo,
+    owner: &Pubkey,
+) -> Result<Account, ProgramError> {
+    let payment_mint = settings.payment_mint.ok_or(MeepError::PaymentMintNotSet)?;
+
+    if *token_account_info.owner != spl_token::ID {
+        return Err(MeepError::WrongPaymentAccount.into());
+    }
+
+    let token_account = Account::unpack(&token_account_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongPaymentAccount))?;
+
+    if token_account.mint != payment_mint {
+        return Err(MeepError::WrongPaymentMint.into());
+    }
+
+    if token_account.owner != *owner {
+        return Err(MeepError::WrongPaymentAccountOwner.into());
+    }
+
+    Ok(token_account)
+}
+
+/// Pays staking rewards: minted by the authority PDA when it is the mint authority of the
+/// reward mint, otherwise transferred from the reward vault, a reward mint token account
+/// owned by the PDA
+#[allow(clippy::too_many_arguments)]
+pub fn pay_staking_rewards<'info>(
+    program_id: &Pubkey,
+    settings: &MeepSettings,
+    settings_info: &AccountInfo<'info>,
+    reward_token_account_info: &AccountInfo<'info>,
+    reward_mint_info: &AccountInfo<'info>,
+    authority_pda_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    reward_vault_info: Option<&AccountInfo<'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let staking = settings
+        .staking
+        .as_ref()
+        .ok_or(MeepError::StakingNotConfigured)?;
+    if *reward_mint_info.key != staking.reward_mint {
+        return Err(MeepError::WrongRewardMint.into());
+    }
+
+    let authority_bump =
+        assert_authority_pda(program_id, settings, settings_info, authority_pda_info)?;
+    let authority_seeds: &[&[u8]] = &[
+        AUTHORITY_SEED.as_bytes(),
+        settings_info.key.as_ref(),
+        &[authority_bump],
+    ];
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let reward_mint = Mint::unpack(&reward_mint_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongRewardMint))?;
+
+    if reward_mint.mint_authority == COption::Some(*authority_pda_info.key) {
+        msg!("Mint rewards");
+        return invoke_signed(
+            &mint_to(
+                &spl_token::ID,
+                reward_mint_info.key,
+                reward_token_account_info.key,
+                authority_pda_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                reward_mint_info.clone(),
+                reward_token_account_info.clone(),
+                authority_pda_info.clone(),
+                token_program.clone(),
+            ],
+            &[authority_seeds],
+        );
+    }
+
+    let reward_vault_info = reward_vault_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if *reward_vault_info.owner != spl_token::ID {
+        return Err(MeepError::WrongRewardVault.into());
+    }
+
+    let vault = Account::unpack(&reward_vault_info.data.borrow())
+        .map_err(|_| ProgramError::from(MeepError::WrongRewardVault))?;
+    if vault.mint != staking.reward_mint || vault.owner != *authority_pda_info.key {
+        return Err(MeepError::WrongRewardVault.into());
+    }
+
+    msg!("Transfer rewards");
+    transfer_payment(
+        reward_vault_info,
+        reward_token_account_info,
+        authority_pda_info,
+        token_program,
+        amount,
+        &[authority_seeds],
+    )
+}
+
+/// Transfers payment mint tokens between accounts checked by `assert_payment_account`
+pub fn transfer_payment<'info>(
+    source_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    owner_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    invoke_signed(
+        &transfer(
+            &spl_token::ID,
+            source_info.key,
+            destination_info.key,
+            owner_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_info.clone(),
+            destination_info.clone(),
+            owner_info.clone(),
+            token_program.clone(),
+        ],
+        signers_seeds,
+    )
+}
+
 pub fn assert_authority(settings: &MeepSettings, authority_info: &AccountInfo) -> ProgramResult {
     if !authority_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
@@ -72,6 +997,69 @@ pub fn assert_authority(settings: &MeepSettings, authority_info: &AccountInfo) -
     Ok(())
 }
 
+/// Checks the authority's signature or, once a signer set is configured, counts the
+/// distinct signers of the set among the authority and `signer_infos`
+pub fn assert_authority_signers(
+    settings: &MeepSettings,
+    authority_info: &AccountInfo,
+    signer_infos: &[AccountInfo],
+) -> ProgramResult {
+    if settings.signers.is_empty() {
+        return assert_authority(settings, authority_info);
+    }
+
+    if *authority_info.key != settings.authority {
+        return Err(MeepError::WrongAuthority.into());
+    }
+
+    let mut signed: Vec<&Pubkey> = Vec::new();
+    for info in std::iter::once(authority_info).chain(signer_infos.iter()) {
+        if info.is_signer && settings.signers.contains(info.key) && !signed.contains(&info.key) {
+            signed.push(info.key);
+        }
+    }
+
+    if signed.len() < settings.signer_threshold as usize {
+        return Err(MeepError::NotEnoughSigners.into());
+    }
+
+    Ok(())
+}
+
+/// Returns whether the minter buys the NFT: once `minimum_price` is set anyone but the
+/// authority and the delegated minters can mint by paying it, except membership badges
+pub fn assert_minter(
+    settings: &MeepSettings,
+    minter_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if !minter_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *minter_info.key == settings.authority
+        || settings.delegated_minters.contains(minter_info.key)
+    {
+        return Ok(false);
+    }
+
+    if settings.minimum_price == 0 || settings.membership {
+        return Err(MeepError::WrongMinter.into());
+    }
+
+    Ok(true)
+}
+
+pub fn assert_primary_creator(
+    settings: &MeepSettings,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    if *authority_info.key != settings.authority {
+        return Err(MeepError::WrongPrimaryCreator.into());
+    }
+
+    Ok(())
+}
+
 pub fn assert_secondary_creator(
     settings: &MeepSettings,
     secondary_creator_info: &AccountInfo,
@@ -82,3 +1070,80 @@ pub fn assert_secondary_creator(
 
     Ok(())
 }
+
+/// Metadata account data is padded with zeros up to the Metaplex limits
+pub fn trim_metadata_string(value: &str) -> String {
+    value.trim_end_matches(char::from(0)).to_string()
+}
+
+/// Leaf of the reveal commitment: hash of the NFT's reveal index and its final URI
+pub fn reveal_leaf(index: u32, uri: &str) -> [u8; 32] {
+    hashv(&[index.to_le_bytes().as_ref(), uri.as_bytes()]).to_bytes()
+}
+
+fn reveal_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[left.as_ref(), right.as_ref()]).to_bytes()
+    } else {
+        hashv(&[right.as_ref(), left.as_ref()]).to_bytes()
+    }
+}
+
+fn reveal_next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => reveal_node(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn reveal_leaves(uris: &[String]) -> Vec<[u8; 32]> {
+    uris.iter()
+        .enumerate()
+        .map(|(index, uri)| reveal_leaf(index as u32, uri))
+        .collect()
+}
+
+/// Merkle root over the final URI list, stored in settings when reveal mode is enabled
+pub fn reveal_commitment(uris: &[String]) -> [u8; 32] {
+    let mut level = reveal_leaves(uris);
+    if level.is_empty() {
+        return [0; 32];
+    }
+
+    while level.len() > 1 {
+        level = reveal_next_level(&level);
+    }
+
+    level[0]
+}
+
+/// Merkle proof for the URI at `index`, passed to the `Reveal` instruction
+pub fn reveal_proof(uris: &[String], index: usize) -> Vec<[u8; 32]> {
+    let mut level = reveal_leaves(uris);
+    let mut index = index;
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+
+        level = reveal_next_level(&level);
+        index /= 2;
+    }
+
+    proof
+}
+
+pub fn verify_reveal_proof(commitment: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let root = proof
+        .iter()
+        .fold(leaf, |node, sibling| reveal_node(&node, sibling));
+
+    root == *commitment
+}
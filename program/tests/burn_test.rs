@@ -0,0 +1,100 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::utils::Pda;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn burn_nft() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    for collection_id in 0..2 {
+        client
+            .initialize_meep(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                &get_random_settings(),
+            )
+            .unwrap();
+    }
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            0,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // only the holder burns
+    let other = Keypair::new();
+    client.airdrop(&other, lamports);
+    assert!(client
+        .burn_nft(
+            &authority.pubkey(),
+            0,
+            &other,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+
+    // the mint record ties the NFT to its own settings
+    assert!(client
+        .burn_nft(
+            &authority.pubkey(),
+            1,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+
+    let mint_record = Pda::mint_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    let metadata = Pda::metadata_pubkey(&mint.pubkey());
+    let master_edition = Pda::master_edition_pubkey(&mint.pubkey());
+    let reclaimed = client.get_balance(&token_account.pubkey())
+        + client.get_balance(&mint_record)
+        + client.get_balance(&metadata)
+        + client.get_balance(&master_edition);
+    let balance = client.get_balance(&authority.pubkey());
+
+    client
+        .burn_nft(
+            &authority.pubkey(),
+            0,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+
+    assert_eq!(client.get_balance(&authority.pubkey()), balance + reclaimed);
+    assert!(!client.account_exists(&token_account.pubkey()));
+    assert!(!client.account_exists(&mint_record));
+    assert!(!client.account_exists(&metadata));
+    assert!(!client.account_exists(&master_edition));
+    assert_eq!(client.get_mint(&mint.pubkey()).supply, 0);
+
+    assert!(client
+        .burn_nft(
+            &authority.pubkey(),
+            0,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+}
@@ -21,6 +21,8 @@ pub fn get_random_mint_args() -> MintNftArgs {
         token_name: token_name.clone(),
         token_symbol: token_symbol.clone(),
         uri: uri.clone(),
+        is_mutable: false,
+        mint_record_bump: 0,
     }
 }
 
@@ -29,5 +31,8 @@ pub fn get_random_settings() -> SettingsArgs {
     SettingsArgs {
         primary_wallet_percentage: rng.gen_range(0..=100),
         minimum_price: rng.gen_range(0..=1_000_000_000),
+        treasury_fee_basis_points: rng.gen_range(0..=10000),
+        treasury_mint_fee: 0,
+        settings_delay: 0,
     }
 }
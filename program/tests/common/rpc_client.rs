@@ -1,13 +1,19 @@
 use super::delay;
-use borsh::BorshDeserialize;
 use meep::{
-    instruction::{MeepInstructions, MintNftArgs, SettingsArgs},
-    state::MeepSettings,
+    instruction::{
+        CreateProposalArgs, GovernanceArgs, InitializeMeepArgs, MeepInstructions, MintApprovalArgs,
+        MintNftArgs, ProposalAction, RevealArgs, RevealModeArgs, SettingsArgs, StakingArgs,
+        UpdateNftMetadataArgs, UpdateSignersArgs,
+    },
+    state::{MeepSettings, MintRecord, Proposal, StakeRecord},
     utils::Pda,
 };
 use metaplex_token_metadata::state::{MasterEditionV2, Metadata};
 use solana_client::{client_error::ClientError, rpc_client::RpcClient};
-use solana_program::{borsh::try_from_slice_unchecked, pubkey::Pubkey, system_program};
+use solana_program::{
+    borsh::try_from_slice_unchecked, instruction::AccountMeta, program_pack::Pack, pubkey::Pubkey,
+    system_instruction, system_program,
+};
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     signature::{Keypair, Signature},
@@ -15,6 +21,11 @@ use solana_sdk::{
     system_transaction,
     transaction::Transaction,
 };
+use solana_transaction_status::UiTransactionEncoding;
+use spl_token::{
+    instruction::{initialize_account2, initialize_mint, mint_to, transfer as token_transfer},
+    state::{Account, Mint},
+};
 use std::time::Duration;
 
 pub struct MeepRpcClient {
@@ -85,14 +96,33 @@ impl MeepRpcClient {
     pub fn initialize_meep(
         &self,
         authority: &Keypair,
+        collection_id: u64,
         secondary_creator: &Keypair,
         initialize_args: &SettingsArgs,
+    ) -> Result<Signature, ClientError> {
+        let args = InitializeMeepArgs {
+            collection_id,
+            settings: initialize_args.clone(),
+            pda_authority: false,
+            royalty_vault: false,
+            payment_mint: None,
+            membership: false,
+        };
+
+        self.initialize_meep_with_args(authority, secondary_creator, &args)
+    }
+
+    pub fn initialize_meep_with_args(
+        &self,
+        authority: &Keypair,
+        secondary_creator: &Keypair,
+        args: &InitializeMeepArgs,
     ) -> Result<Signature, ClientError> {
         let ix = MeepInstructions::initialize_meep(
             &self.program_id,
             &authority.pubkey(),
             &secondary_creator.pubkey(),
-            initialize_args,
+            args,
         );
 
         let blockhash = self.client.get_recent_blockhash().unwrap().0;
@@ -109,9 +139,27 @@ impl MeepRpcClient {
     pub fn update_settings(
         &self,
         authority: &Keypair,
+        collection_id: u64,
+        settings: &SettingsArgs,
+    ) -> Result<Signature, ClientError> {
+        self.update_settings_as(&authority.pubkey(), collection_id, authority, settings)
+    }
+
+    /// `authority` is the current authority of settings initialized by `original_authority`
+    pub fn update_settings_as(
+        &self,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Keypair,
         settings: &SettingsArgs,
     ) -> Result<Signature, ClientError> {
-        let ix = MeepInstructions::update_settings(&self.program_id, &authority.pubkey(), settings);
+        let ix = MeepInstructions::update_settings(
+            &self.program_id,
+            original_authority,
+            collection_id,
+            &authority.pubkey(),
+            settings,
+        );
 
         let blockhash = self.client.get_recent_blockhash().unwrap().0;
         let tx = Transaction::new_signed_with_payer(
@@ -124,9 +172,182 @@ impl MeepRpcClient {
         self.client.send_and_confirm_transaction_with_spinner(&tx)
     }
 
+    pub fn multisig_update_settings(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        signers: &[&Keypair],
+        settings: &SettingsArgs,
+    ) -> Result<Signature, ClientError> {
+        let mut ix = MeepInstructions::update_settings(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            settings,
+        );
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|signer| signer.pubkey()).collect();
+        MeepInstructions::append_signers(&mut ix, &signer_pubkeys);
+
+        let mut tx_signers = vec![authority, &self.fee_payer];
+        tx_signers.extend_from_slice(signers);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &tx_signers,
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn apply_pending_settings(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+    ) -> Result<Signature, ClientError> {
+        let ix =
+            MeepInstructions::apply_pending_settings(&self.program_id, authority, collection_id);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn veto_pending_settings(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Keypair,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::veto_pending_settings(
+            &self.program_id,
+            authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[secondary_creator, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn update_signers(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        signers: &[&Keypair],
+        args: &UpdateSignersArgs,
+    ) -> Result<Signature, ClientError> {
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let ix = MeepInstructions::update_signers(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            &signer_pubkeys,
+            args,
+        );
+
+        let mut tx_signers = vec![authority, &self.fee_payer];
+        tx_signers.extend_from_slice(signers);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &tx_signers,
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn multisig_mint_nft(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        signers: &[&Keypair],
+        secondary_creator: &Keypair,
+        mint: &Keypair,
+        token_account: &Keypair,
+        mint_args: &MintNftArgs,
+    ) -> Result<Signature, ClientError> {
+        let mut ix = MeepInstructions::mint_nft(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            &secondary_creator.pubkey(),
+            true,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            mint_args,
+        );
+        let signer_pubkeys: Vec<Pubkey> = signers.iter().map(|signer| signer.pubkey()).collect();
+        MeepInstructions::append_signers(&mut ix, &signer_pubkeys);
+
+        let mut tx_signers = vec![
+            authority,
+            &self.fee_payer,
+            secondary_creator,
+            mint,
+            token_account,
+        ];
+        tx_signers.extend_from_slice(signers);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &tx_signers,
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
     pub fn mint_nft(
         &self,
         authority: &Keypair,
+        collection_id: u64,
+        secondary_creator: &Keypair,
+        mint: &Keypair,
+        token_account: &Keypair,
+        mint_args: &MintNftArgs,
+    ) -> Result<Signature, ClientError> {
+        self.delegated_mint_nft(
+            &authority.pubkey(),
+            collection_id,
+            authority,
+            secondary_creator,
+            mint,
+            token_account,
+            mint_args,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn delegated_mint_nft(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        minter: &Keypair,
         secondary_creator: &Keypair,
         mint: &Keypair,
         token_account: &Keypair,
@@ -134,8 +355,11 @@ impl MeepRpcClient {
     ) -> Result<Signature, ClientError> {
         let ix = MeepInstructions::mint_nft(
             &self.program_id,
-            &authority.pubkey(),
+            authority,
+            collection_id,
+            &minter.pubkey(),
             &secondary_creator.pubkey(),
+            true,
             &mint.pubkey(),
             &token_account.pubkey(),
             mint_args,
@@ -146,7 +370,7 @@ impl MeepRpcClient {
             &[ix],
             Some(&self.fee_payer.pubkey()),
             &vec![
-                authority,
+                minter,
                 &self.fee_payer,
                 secondary_creator,
                 mint,
@@ -158,21 +382,998 @@ impl MeepRpcClient {
         self.client.send_and_confirm_transaction_with_spinner(&tx)
     }
 
-    pub fn get_settings(&self, authority: &Pubkey) -> MeepSettings {
-        let settings_pubkey = Pda::settings_pubkey_with_bump(&self.program_id, authority).0;
-        let settings_data = self.client.get_account_data(&settings_pubkey).unwrap();
-        MeepSettings::try_from_slice(&settings_data).unwrap()
+    /// `buyer` mints in the public mint, `recipient` and `token_accounts` are the payment
+    /// accounts of `append_mint_payment`
+    #[allow(clippy::too_many_arguments)]
+    pub fn paid_mint_nft(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        buyer: &Keypair,
+        secondary_creator: &Keypair,
+        mint: &Keypair,
+        token_account: &Keypair,
+        mint_args: &MintNftArgs,
+        recipient: &Pubkey,
+        token_accounts: Option<(&Pubkey, &Pubkey)>,
+    ) -> Result<Signature, ClientError> {
+        let mut ix = MeepInstructions::mint_nft(
+            &self.program_id,
+            authority,
+            collection_id,
+            &buyer.pubkey(),
+            &secondary_creator.pubkey(),
+            true,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            mint_args,
+        );
+        MeepInstructions::append_mint_payment(&mut ix, recipient, token_accounts);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &vec![
+                buyer,
+                &self.fee_payer,
+                secondary_creator,
+                mint,
+                token_account,
+            ],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
     }
 
-    pub fn get_metadata(&self, mint: &Pubkey) -> Metadata {
-        let metadata_pubkey = Pda::metadata_pubkey(mint);
-        let metadata_data = self.client.get_account_data(&metadata_pubkey).unwrap();
-        let mut metadata: Metadata = try_from_slice_unchecked(&metadata_data).unwrap();
-        let zero = char::from(0);
-        metadata.data.name = metadata.data.name.trim_end_matches(zero).to_string();
-        metadata.data.symbol = metadata.data.symbol.trim_end_matches(zero).to_string();
-        metadata.data.uri = metadata.data.uri.trim_end_matches(zero).to_string();
-        metadata
+    pub fn approved_mint_nft(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        secondary_creator: &Pubkey,
+        mint: &Keypair,
+        token_account: &Keypair,
+        mint_args: &MintNftArgs,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::mint_nft(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            secondary_creator,
+            false,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            mint_args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &vec![authority, &self.fee_payer, mint, token_account],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn add_delegated_minter(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        minter: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        self.add_delegated_minter_as(&authority.pubkey(), collection_id, authority, minter)
+    }
+
+    /// `authority` is the current authority of settings initialized by `original_authority`
+    pub fn add_delegated_minter_as(
+        &self,
+        original_authority: &Pubkey,
+        collection_id: u64,
+        authority: &Keypair,
+        minter: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::add_delegated_minter(
+            &self.program_id,
+            original_authority,
+            collection_id,
+            &authority.pubkey(),
+            minter,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn revoke_delegated_minter(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        minter: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::revoke_delegated_minter(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            minter,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn grant_mint_approval(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Keypair,
+        args: &MintApprovalArgs,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::grant_mint_approval(
+            &self.program_id,
+            authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+            args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[secondary_creator, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn revoke_mint_approval(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Keypair,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::revoke_mint_approval(
+            &self.program_id,
+            authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[secondary_creator, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn sign_as_secondary(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        signer: &Keypair,
+        mints: &[Pubkey],
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::sign_as_secondary(
+            &self.program_id,
+            authority,
+            collection_id,
+            &signer.pubkey(),
+            mints,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[signer, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn enable_reveal_mode(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        args: &RevealModeArgs,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::enable_reveal_mode(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn reveal(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        mint: &Pubkey,
+        args: &RevealArgs,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::reveal(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            mint,
+            args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn update_nft_metadata(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        mint: &Pubkey,
+        args: &UpdateNftMetadataArgs,
+    ) -> Result<Signature, ClientError> {
+        self.update_nft_metadata_account(
+            authority,
+            collection_id,
+            mint,
+            &Pda::metadata_pubkey(mint),
+            args,
+        )
+    }
+
+    /// Passes `metadata` as the mint's metadata account
+    pub fn update_nft_metadata_account(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        mint: &Pubkey,
+        metadata: &Pubkey,
+        args: &UpdateNftMetadataArgs,
+    ) -> Result<Signature, ClientError> {
+        let mut ix = MeepInstructions::update_nft_metadata(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            mint,
+            args,
+        );
+        ix.accounts[3] = AccountMeta::new(*metadata, false);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn create_mint(&self, mint_authority: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let lamports = self
+            .client
+            .get_minimum_balance_for_rent_exemption(Mint::LEN)
+            .unwrap();
+
+        let instructions = [
+            system_instruction::create_account(
+                &self.fee_payer.pubkey(),
+                &mint.pubkey(),
+                lamports,
+                Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            initialize_mint(
+                &spl_token::ID,
+                &mint.pubkey(),
+                mint_authority,
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer, &mint],
+            blockhash,
+        );
+
+        self.client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+        mint.pubkey()
+    }
+
+    pub fn create_token_account(&self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let token_account = Keypair::new();
+        let lamports = self
+            .client
+            .get_minimum_balance_for_rent_exemption(Account::LEN)
+            .unwrap();
+
+        let instructions = [
+            system_instruction::create_account(
+                &self.fee_payer.pubkey(),
+                &token_account.pubkey(),
+                lamports,
+                Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            initialize_account2(&spl_token::ID, &token_account.pubkey(), mint, owner).unwrap(),
+        ];
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer, &token_account],
+            blockhash,
+        );
+
+        self.client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+        token_account.pubkey()
+    }
+
+    pub fn mint_tokens(
+        &self,
+        mint_authority: &Keypair,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        amount: u64,
+    ) {
+        let ix = mint_to(
+            &spl_token::ID,
+            mint,
+            token_account,
+            &mint_authority.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer, mint_authority],
+            blockhash,
+        );
+
+        self.client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+    }
+
+    pub fn transfer_tokens(
+        &self,
+        owner: &Keypair,
+        source: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> Result<Signature, ClientError> {
+        let ix = token_transfer(
+            &spl_token::ID,
+            source,
+            destination,
+            &owner.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer, owner],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn get_token_balance(&self, token_account: &Pubkey) -> u64 {
+        self.get_token_account(token_account).amount
+    }
+
+    pub fn get_token_account(&self, token_account: &Pubkey) -> Account {
+        let data = self.client.get_account_data(token_account).unwrap();
+        Account::unpack(&data).unwrap()
+    }
+
+    pub fn deposit(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        depositor: &Keypair,
+        token_accounts: Option<(&Pubkey, &Pubkey)>,
+        amount: u64,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::deposit(
+            &self.program_id,
+            authority,
+            collection_id,
+            &depositor.pubkey(),
+            token_accounts,
+            amount,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[depositor, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    /// `signer` stands in for the governance PDA, which only the program can sign for
+    pub fn withdraw(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        signer: &Keypair,
+        recipient: &Pubkey,
+        amount: u64,
+    ) -> Result<Signature, ClientError> {
+        let mut ix = MeepInstructions::withdraw(
+            &self.program_id,
+            authority,
+            collection_id,
+            recipient,
+            None,
+            amount,
+        );
+        ix.accounts[0] = AccountMeta::new_readonly(signer.pubkey(), true);
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[signer, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn transfer(&self, from: &Keypair, to: &Pubkey, lamports: u64) {
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = system_transaction::transfer(from, to, lamports, blockhash);
+
+        self.client
+            .send_and_confirm_transaction_with_spinner(&tx)
+            .unwrap();
+    }
+
+    pub fn distribute_royalties(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        secondary_creator: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::distribute_royalties(
+            &self.program_id,
+            authority,
+            collection_id,
+            authority,
+            secondary_creator,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn configure_governance(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        args: &GovernanceArgs,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::configure_governance(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    /// Returns the new proposal's pubkey
+    pub fn create_proposal(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        proposer: &Keypair,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        args: &CreateProposalArgs,
+    ) -> Result<Pubkey, ClientError> {
+        self.create_proposal_with_signature(
+            authority,
+            collection_id,
+            proposer,
+            token_account,
+            mint,
+            args,
+        )
+        .map(|(proposal, _)| proposal)
+    }
+
+    pub fn create_proposal_with_signature(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        proposer: &Keypair,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        args: &CreateProposalArgs,
+    ) -> Result<(Pubkey, Signature), ClientError> {
+        let settings = Pda::settings_pubkey_with_bump(&self.program_id, authority, collection_id).0;
+        let index = self.get_settings(authority, collection_id).proposal_count;
+        let ix = MeepInstructions::create_proposal(
+            &self.program_id,
+            authority,
+            collection_id,
+            index,
+            &proposer.pubkey(),
+            token_account,
+            mint,
+            args,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[proposer, &self.fee_payer],
+            blockhash,
+        );
+
+        let signature = self.client.send_and_confirm_transaction_with_spinner(&tx)?;
+        Ok((
+            Pda::proposal_pubkey_with_bump(&self.program_id, &settings, index).0,
+            signature,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_vote(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+        voter: &Keypair,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        approve: bool,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::cast_vote(
+            &self.program_id,
+            authority,
+            collection_id,
+            proposal,
+            &voter.pubkey(),
+            token_account,
+            mint,
+            approve,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[voter, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn finalize_proposal(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::finalize_proposal(
+            &self.program_id,
+            authority,
+            collection_id,
+            proposal,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn execute_proposal(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        proposal: &Pubkey,
+        action: &ProposalAction,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::execute_proposal(
+            &self.program_id,
+            authority,
+            collection_id,
+            proposal,
+            action,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[&self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn burn_nft(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        holder: &Keypair,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::burn_nft(
+            &self.program_id,
+            authority,
+            collection_id,
+            &holder.pubkey(),
+            mint,
+            token_account,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[holder, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn revoke_membership(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::revoke_membership(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            mint,
+            token_account,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn recover_membership(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        member: &Keypair,
+        member_token_account: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::recover_membership(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            mint,
+            token_account,
+            &member.pubkey(),
+            member_token_account,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, member, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn configure_staking(
+        &self,
+        authority: &Keypair,
+        collection_id: u64,
+        reward_mint: &Pubkey,
+        reward_rate: u64,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::configure_staking(
+            &self.program_id,
+            &authority.pubkey(),
+            collection_id,
+            &authority.pubkey(),
+            reward_mint,
+            &StakingArgs { reward_rate },
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[authority, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn stake(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        staker: &Keypair,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::stake(
+            &self.program_id,
+            authority,
+            collection_id,
+            &staker.pubkey(),
+            mint,
+            token_account,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[staker, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn unstake(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        staker: &Keypair,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        reward_token_account: &Pubkey,
+        reward_mint: &Pubkey,
+        reward_vault: Option<&Pubkey>,
+        forfeit_rewards: bool,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::unstake(
+            &self.program_id,
+            authority,
+            collection_id,
+            &staker.pubkey(),
+            mint,
+            token_account,
+            reward_token_account,
+            reward_mint,
+            reward_vault,
+            forfeit_rewards,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[staker, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_rewards(
+        &self,
+        authority: &Pubkey,
+        collection_id: u64,
+        staker: &Keypair,
+        mint: &Pubkey,
+        reward_token_account: &Pubkey,
+        reward_mint: &Pubkey,
+        reward_vault: Option<&Pubkey>,
+    ) -> Result<Signature, ClientError> {
+        let ix = MeepInstructions::claim_rewards(
+            &self.program_id,
+            authority,
+            collection_id,
+            &staker.pubkey(),
+            mint,
+            reward_token_account,
+            reward_mint,
+            reward_vault,
+        );
+
+        let blockhash = self.client.get_recent_blockhash().unwrap().0;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &[staker, &self.fee_payer],
+            blockhash,
+        );
+
+        self.client.send_and_confirm_transaction_with_spinner(&tx)
+    }
+
+    pub fn account_exists(&self, account: &Pubkey) -> bool {
+        self.client.get_account(account).is_ok()
+    }
+
+    /// Compute units consumed by the top-level Meep instruction of a confirmed transaction,
+    /// including its CPIs
+    pub fn get_compute_units(&self, signature: &Signature) -> u64 {
+        let transaction = self
+            .client
+            .get_transaction(signature, UiTransactionEncoding::Json)
+            .unwrap();
+        let logs = transaction
+            .transaction
+            .meta
+            .and_then(|meta| meta.log_messages)
+            .unwrap_or_default();
+
+        // inner invocations are logged before the ones that invoked them
+        let prefix = format!("Program {} consumed ", self.program_id);
+        logs.iter()
+            .rev()
+            .find_map(|log| {
+                log.strip_prefix(&prefix)?
+                    .split_whitespace()
+                    .next()?
+                    .parse()
+                    .ok()
+            })
+            .unwrap()
+    }
+
+    pub fn get_account_size(&self, account: &Pubkey) -> usize {
+        self.client.get_account_data(account).unwrap().len()
+    }
+
+    pub fn get_proposal(&self, proposal: &Pubkey) -> Proposal {
+        let proposal_data = self.client.get_account_data(proposal).unwrap();
+        try_from_slice_unchecked(&proposal_data).unwrap()
+    }
+
+    pub fn get_stake_record(&self, mint: &Pubkey) -> StakeRecord {
+        let stake_record_pubkey = Pda::stake_record_pubkey_with_bump(&self.program_id, mint).0;
+        let stake_record_data = self.client.get_account_data(&stake_record_pubkey).unwrap();
+        try_from_slice_unchecked(&stake_record_data).unwrap()
+    }
+
+    pub fn get_settings(&self, authority: &Pubkey, collection_id: u64) -> MeepSettings {
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(&self.program_id, authority, collection_id).0;
+        let settings_data = self.client.get_account_data(&settings_pubkey).unwrap();
+        try_from_slice_unchecked(&settings_data).unwrap()
+    }
+
+    pub fn get_mint_record(&self, mint: &Pubkey) -> MintRecord {
+        let mint_record_pubkey = Pda::mint_record_pubkey_with_bump(&self.program_id, mint).0;
+        let mint_record_data = self.client.get_account_data(&mint_record_pubkey).unwrap();
+        try_from_slice_unchecked(&mint_record_data).unwrap()
+    }
+
+    pub fn get_metadata(&self, mint: &Pubkey) -> Metadata {
+        let metadata_pubkey = Pda::metadata_pubkey(mint);
+        let metadata_data = self.client.get_account_data(&metadata_pubkey).unwrap();
+        let mut metadata: Metadata = try_from_slice_unchecked(&metadata_data).unwrap();
+        let zero = char::from(0);
+        metadata.data.name = metadata.data.name.trim_end_matches(zero).to_string();
+        metadata.data.symbol = metadata.data.symbol.trim_end_matches(zero).to_string();
+        metadata.data.uri = metadata.data.uri.trim_end_matches(zero).to_string();
+        metadata
+    }
+
+    pub fn get_mint(&self, mint: &Pubkey) -> Mint {
+        let mint_data = self.client.get_account_data(mint).unwrap();
+        Mint::unpack(&mint_data).unwrap()
     }
 
     pub fn get_master_edition(&self, mint: &Pubkey) -> MasterEditionV2 {
@@ -0,0 +1,445 @@
+use common::rpc_client::MeepRpcClient;
+use meep::{
+    instruction::{
+        CreateProposalArgs, GovernanceArgs, InitializeMeepArgs, MintApprovalArgs, MintNftArgs,
+        ProposalAction, RevealArgs, RevealModeArgs, SettingsArgs, UpdateNftMetadataArgs,
+        UpdateSignersArgs,
+    },
+    utils::{reveal_commitment, reveal_proof, Pda},
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{collections::BTreeMap, env, fs, path::Path, thread::sleep, time::Duration};
+
+#[allow(dead_code)]
+mod common;
+
+const BASELINE_PATH: &str = "tests/compute_units_baseline.txt";
+
+/// Compute units vary with the bump seeds of the random accounts
+const UNITS_TOLERANCE_PERCENTAGE: u64 = 5;
+
+#[derive(Default)]
+struct Measurements {
+    units: BTreeMap<String, u64>,
+    sizes: BTreeMap<String, u64>,
+}
+
+fn get_settings_args(settings_delay: i64) -> SettingsArgs {
+    SettingsArgs {
+        primary_wallet_percentage: 60,
+        minimum_price: 0,
+        treasury_fee_basis_points: 0,
+        treasury_mint_fee: 1_000,
+        settings_delay,
+    }
+}
+
+// Fixed arguments, string lengths change the cost
+fn get_mint_args() -> MintNftArgs {
+    MintNftArgs {
+        seller_fee_basis_points: 500,
+        token_name: "Meep".to_string(),
+        token_symbol: "MEEP".to_string(),
+        uri: "https://test.com/0".to_string(),
+        is_mutable: true,
+        mint_record_bump: 0,
+    }
+}
+
+fn read_baseline(path: &Path) -> Measurements {
+    let mut baseline = Measurements::default();
+    let content = fs::read_to_string(path).unwrap_or_default();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let value = fields[2].parse().unwrap();
+        match fields[0] {
+            "units" => baseline.units.insert(fields[1].to_string(), value),
+            "size" => baseline.sizes.insert(fields[1].to_string(), value),
+            kind => panic!("Unknown baseline entry {}", kind),
+        };
+    }
+
+    baseline
+}
+
+fn write_baseline(path: &Path, measurements: &Measurements) {
+    let mut content = String::from(
+        "# Compute units of every instruction and sizes of the program accounts, checked by\n\
+         # compute_units_test. Rerun it with MEEP_UPDATE_BASELINE=1 to record intended changes\n",
+    );
+    for (name, size) in measurements.sizes.iter() {
+        content.push_str(&format!("size {} {}\n", name, size));
+    }
+    for (name, units) in measurements.units.iter() {
+        content.push_str(&format!("units {} {}\n", name, units));
+    }
+
+    fs::write(path, content).unwrap();
+}
+
+/// Runs every instruction once and records what it costs
+fn measure(client: &MeepRpcClient) -> Measurements {
+    let mut measurements = Measurements::default();
+    let mut units = |name: &str, signature| {
+        measurements
+            .units
+            .insert(name.to_string(), client.get_compute_units(&signature));
+    };
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_settings_args(0),
+        pda_authority: false,
+        royalty_vault: true,
+        payment_mint: None,
+        membership: false,
+    };
+    let signature = client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+    units("InitializeMeep", signature);
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let royalty_vault = Pda::royalty_vault_pubkey_with_bump(&meep::ID, &settings).0;
+
+    let signature = client
+        .update_settings(&authority, collection_id, &get_settings_args(0))
+        .unwrap();
+    units("UpdateSettings", signature);
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let signature = client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_mint_args(),
+        )
+        .unwrap();
+    units("MintNft", signature);
+
+    let minter = Keypair::new();
+    let signature = client
+        .add_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .unwrap();
+    units("AddDelegatedMinter", signature);
+    let signature = client
+        .revoke_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .unwrap();
+    units("RevokeDelegatedMinter", signature);
+
+    let approval = MintApprovalArgs {
+        max_mints: Some(1),
+        expires_at: None,
+        delegate: None,
+    };
+    let signature = client
+        .grant_mint_approval(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &approval,
+        )
+        .unwrap();
+    units("GrantMintApproval", signature);
+
+    let approved_mint = Keypair::new();
+    let signature = client
+        .approved_mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+            &approved_mint,
+            &Keypair::new(),
+            &get_mint_args(),
+        )
+        .unwrap();
+    units("MintNft.Approved", signature);
+
+    let signature = client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &[approved_mint.pubkey()],
+        )
+        .unwrap();
+    units("SignAsSecondary", signature);
+
+    let signature = client
+        .revoke_mint_approval(&authority.pubkey(), collection_id, &secondary_creator)
+        .unwrap();
+    units("RevokeMintApproval", signature);
+
+    let update_args = UpdateNftMetadataArgs {
+        token_name: None,
+        uri: Some("https://test.com/1".to_string()),
+        seller_fee_basis_points: None,
+        lock: false,
+    };
+    let signature = client
+        .update_nft_metadata(&authority, collection_id, &mint.pubkey(), &update_args)
+        .unwrap();
+    units("UpdateNftMetadata", signature);
+
+    let uris: Vec<String> = (0..4)
+        .map(|i| format!("https://test.com/revealed/{}", i))
+        .collect();
+    let reveal_mode_args = RevealModeArgs {
+        placeholder_uri: "https://test.com/placeholder".to_string(),
+        uri_commitment: reveal_commitment(&uris),
+        uri_count: uris.len() as u32,
+    };
+    let signature = client
+        .enable_reveal_mode(&authority, collection_id, &reveal_mode_args)
+        .unwrap();
+    units("EnableRevealMode", signature);
+
+    let hidden_mint = Keypair::new();
+    let signature = client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &hidden_mint,
+            &Keypair::new(),
+            &get_mint_args(),
+        )
+        .unwrap();
+    units("MintNft.RevealMode", signature);
+
+    let reveal_index = client
+        .get_mint_record(&hidden_mint.pubkey())
+        .reveal_index
+        .unwrap() as usize;
+    let reveal_args = RevealArgs {
+        uri: uris[reveal_index].clone(),
+        proof: reveal_proof(&uris, reveal_index),
+    };
+    let signature = client
+        .reveal(
+            &authority,
+            collection_id,
+            &hidden_mint.pubkey(),
+            &reveal_args,
+        )
+        .unwrap();
+    units("Reveal", signature);
+
+    client.transfer(&authority, &royalty_vault, 1_000_000);
+    let signature = client
+        .distribute_royalties(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator.pubkey(),
+        )
+        .unwrap();
+    units("DistributeRoyalties", signature);
+
+    let signature = client
+        .deposit(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            None,
+            10_000_000,
+        )
+        .unwrap();
+    units("Deposit", signature);
+
+    let governance = GovernanceArgs {
+        quorum: 1,
+        threshold_percentage: 50,
+        voting_period: 2,
+        execution_delay: 0,
+    };
+    let signature = client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+    units("ConfigureGovernance", signature);
+
+    let action = ProposalAction::TreasuryTransfer {
+        recipient: Keypair::new().pubkey(),
+        amount: 4_000_000,
+        treasury_token_account: None,
+    };
+    let proposal_args = CreateProposalArgs {
+        description_uri: "https://test.com/proposal".to_string(),
+        action: action.clone(),
+    };
+    let (proposal, signature) = client
+        .create_proposal_with_signature(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &proposal_args,
+        )
+        .unwrap();
+    units("CreateProposal", signature);
+
+    let signature = client
+        .cast_vote(
+            &authority.pubkey(),
+            collection_id,
+            &proposal,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            true,
+        )
+        .unwrap();
+    units("CastVote", signature);
+
+    sleep(Duration::from_secs(governance.voting_period as u64 + 2));
+    let signature = client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .unwrap();
+    units("FinalizeProposal", signature);
+
+    // includes the Withdraw CPI, which only the governance PDA can sign
+    let signature = client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .unwrap();
+    units("ExecuteProposal.TreasuryTransfer", signature);
+
+    // the first update applies at once and sets the delay for the next ones
+    client
+        .update_settings(&authority, collection_id, &get_settings_args(1))
+        .unwrap();
+    let signature = client
+        .update_settings(&authority, collection_id, &get_settings_args(1))
+        .unwrap();
+    units("UpdateSettings.Queued", signature);
+    let signature = client
+        .veto_pending_settings(&authority.pubkey(), collection_id, &secondary_creator)
+        .unwrap();
+    units("VetoPendingSettings", signature);
+
+    client
+        .update_settings(&authority, collection_id, &get_settings_args(1))
+        .unwrap();
+    sleep(Duration::from_secs(2));
+    let signature = client
+        .apply_pending_settings(&authority.pubkey(), collection_id)
+        .unwrap();
+    units("ApplyPendingSettings", signature);
+
+    let burned_mint = Keypair::new();
+    let burned_token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &burned_mint,
+            &burned_token_account,
+            &get_mint_args(),
+        )
+        .unwrap();
+    let signature = client
+        .burn_nft(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &burned_mint.pubkey(),
+            &burned_token_account.pubkey(),
+        )
+        .unwrap();
+    units("BurnNft", signature);
+
+    let signer = Keypair::new();
+    let signers_args = UpdateSignersArgs {
+        signers: vec![signer.pubkey()],
+        threshold: 1,
+    };
+    let signature = client
+        .update_signers(&authority, collection_id, &[&signer], &signers_args)
+        .unwrap();
+    units("UpdateSigners", signature);
+
+    let vote_record = Pda::vote_record_pubkey_with_bump(&meep::ID, &proposal, &mint.pubkey()).0;
+    let mint_record = Pda::mint_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    for (name, account) in vec![
+        ("MeepSettings", settings),
+        ("MintRecord", mint_record),
+        ("Proposal", proposal),
+        ("VoteRecord", vote_record),
+    ] {
+        measurements
+            .sizes
+            .insert(name.to_string(), client.get_account_size(&account) as u64);
+    }
+
+    measurements
+}
+
+#[test]
+fn compute_units() {
+    let client = MeepRpcClient::new();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(BASELINE_PATH);
+
+    let measurements = measure(&client);
+    let baseline = read_baseline(&path);
+
+    if env::var("MEEP_UPDATE_BASELINE").is_ok() {
+        write_baseline(&path, &measurements);
+        return;
+    }
+
+    let mut regressions = Vec::new();
+    for (name, units) in measurements.units.iter() {
+        match baseline.units.get(name) {
+            Some(baseline_units) => {
+                println!(
+                    "{:<36} {:>8} units, baseline {}",
+                    name, units, baseline_units
+                );
+                if *units > baseline_units * (100 + UNITS_TOLERANCE_PERCENTAGE) / 100 {
+                    regressions.push(format!(
+                        "{} consumes {} units, baseline {}",
+                        name, units, baseline_units
+                    ));
+                }
+            }
+            None => regressions.push(format!("{} has no units baseline", name)),
+        }
+    }
+
+    for (name, size) in measurements.sizes.iter() {
+        match baseline.sizes.get(name) {
+            Some(baseline_size) => {
+                println!("{:<36} {:>8} bytes, baseline {}", name, size, baseline_size);
+                if size > baseline_size {
+                    regressions.push(format!(
+                        "{} takes {} bytes, baseline {}",
+                        name, size, baseline_size
+                    ));
+                }
+            }
+            None => regressions.push(format!("{} has no size baseline", name)),
+        }
+    }
+
+    assert!(
+        regressions.is_empty(),
+        "Regressions beyond the baseline:\n{}",
+        regressions.join("\n")
+    );
+}
@@ -0,0 +1,114 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::utils::Pda;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn delegated_minter() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let minter = Keypair::new();
+    let initialize_args = get_random_settings();
+
+    client.airdrop(&authority, lamports);
+    client.airdrop(&minter, lamports);
+
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &initialize_args,
+        )
+        .unwrap();
+
+    let mint_nft = |mint: &Keypair, token: &Keypair| {
+        client.delegated_mint_nft(
+            &authority.pubkey(),
+            collection_id,
+            &minter,
+            &secondary_creator,
+            mint,
+            token,
+            &get_random_mint_args(),
+        )
+    };
+
+    // not a delegated minter yet, and a buyer has to pay
+    assert!(mint_nft(&Keypair::new(), &Keypair::new()).is_err());
+
+    // only the authority manages delegated minters
+    assert!(client
+        .add_delegated_minter(&minter, collection_id, &minter.pubkey())
+        .is_err());
+
+    client
+        .add_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .unwrap();
+
+    // double addition
+    assert!(client
+        .add_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .is_err());
+
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(settings.delegated_minters, vec![minter.pubkey()]);
+
+    let mint = Keypair::new();
+    let token = Keypair::new();
+    let treasury = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &treasury).0;
+    let balance = client.get_balance(&authority.pubkey());
+    let treasury_balance = client.get_balance(&treasury);
+    mint_nft(&mint, &token).unwrap();
+
+    // staff mints are free and the token goes to the authority
+    assert_eq!(client.get_balance(&authority.pubkey()), balance);
+    assert_eq!(client.get_balance(&treasury), treasury_balance);
+    let token_account = client.get_token_account(&token.pubkey());
+    assert_eq!(token_account.owner, authority.pubkey());
+    assert_eq!(token_account.amount, 1);
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.update_authority, authority.pubkey());
+
+    let creators = metadata.data.creators.unwrap();
+    assert_eq!(creators.len(), 3);
+    assert_eq!(creators[0].address, authority.pubkey());
+    assert_eq!(creators[0].verified, false);
+    assert_eq!(creators[0].share, initialize_args.primary_wallet_percentage);
+    assert_eq!(creators[1].address, secondary_creator.pubkey());
+    assert_eq!(creators[1].verified, true);
+    assert_eq!(creators[2].address, minter.pubkey());
+    assert_eq!(creators[2].verified, true);
+    assert_eq!(creators[2].share, 0);
+
+    let mint_record = client.get_mint_record(&mint.pubkey());
+    assert_eq!(mint_record.minter, minter.pubkey());
+
+    // delegated minters can't change settings
+    assert!(client
+        .update_settings(&minter, collection_id, &get_random_settings())
+        .is_err());
+
+    client
+        .revoke_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .unwrap();
+
+    assert!(client
+        .get_settings(&authority.pubkey(), collection_id)
+        .delegated_minters
+        .is_empty());
+    assert!(mint_nft(&Keypair::new(), &Keypair::new()).is_err());
+
+    // double revocation
+    assert!(client
+        .revoke_delegated_minter(&authority, collection_id, &minter.pubkey())
+        .is_err());
+}
@@ -0,0 +1,93 @@
+use meep::error::MeepError;
+use num_traits::FromPrimitive;
+use solana_program::program_error::ProgramError;
+
+// Clients match on these codes, a failing assertion means a code was changed by mistake
+fn get_error_codes() -> Vec<(MeepError, u32)> {
+    vec![
+        (MeepError::PrimaryAndSecondaryAreSame, 0),
+        (MeepError::PercentageLimitExceeded, 1),
+        (MeepError::WrongSettingsAccount, 2),
+        (MeepError::WrongAuthority, 3),
+        (MeepError::WrongPrimaryCreator, 4),
+        (MeepError::WrongSecondaryCreator, 5),
+        (MeepError::RevealAlreadyEnabled, 6),
+        (MeepError::RevealNotEnabled, 7),
+        (MeepError::NotRevealable, 8),
+        (MeepError::AlreadyRevealed, 9),
+        (MeepError::InvalidRevealProof, 10),
+        (MeepError::WrongMetadataAccount, 11),
+        (MeepError::WrongMintRecord, 12),
+        (MeepError::NumericalOverflow, 13),
+        (MeepError::WrongMinter, 14),
+        (MeepError::DelegatedMinterAlreadyAdded, 15),
+        (MeepError::DelegatedMinterNotFound, 16),
+        (MeepError::DelegatedMintersLimitExceeded, 17),
+        (MeepError::MintApprovalMissing, 18),
+        (MeepError::MintApprovalExpired, 19),
+        (MeepError::MintApprovalExhausted, 20),
+        (MeepError::NameTooLong, 21),
+        (MeepError::SymbolTooLong, 22),
+        (MeepError::UriTooLong, 23),
+        (MeepError::InvalidSellerFee, 24),
+        (MeepError::MetadataImmutable, 25),
+        (MeepError::WrongAuthorityPda, 26),
+        (MeepError::WrongRoyaltyVault, 27),
+        (MeepError::PaymentMintNotSet, 28),
+        (MeepError::WrongPaymentMint, 29),
+        (MeepError::WrongPaymentAccount, 30),
+        (MeepError::WrongPaymentAccountOwner, 31),
+        (MeepError::InsufficientPayment, 32),
+        (MeepError::InvalidTreasuryFee, 33),
+        (MeepError::WrongTreasury, 34),
+        (MeepError::WrongGovernance, 35),
+        (MeepError::InvalidGovernanceConfig, 36),
+        (MeepError::GovernanceNotConfigured, 37),
+        (MeepError::NotNftHolder, 38),
+        (MeepError::UnverifiedNft, 39),
+        (MeepError::WrongProposal, 40),
+        (MeepError::WrongVoteRecord, 41),
+        (MeepError::AlreadyVoted, 42),
+        (MeepError::VotingClosed, 43),
+        (MeepError::VotingNotEnded, 44),
+        (MeepError::ProposalNotApproved, 45),
+        (MeepError::ExecutionDelayNotPassed, 46),
+        (MeepError::WrongRecipient, 47),
+        (MeepError::InvalidSignerSet, 48),
+        (MeepError::NotEnoughSigners, 49),
+        (MeepError::InvalidSettingsDelay, 50),
+        (MeepError::NoPendingSettings, 51),
+        (MeepError::PendingSettingsNotEffective, 52),
+        (MeepError::PendingSettingsEffective, 53),
+        (MeepError::NotMembershipNft, 54),
+        (MeepError::MembershipNftNotBurnable, 55),
+        (MeepError::StakingNotConfigured, 56),
+        (MeepError::WrongStakeRecord, 57),
+        (MeepError::NotStaker, 58),
+        (MeepError::WrongRewardMint, 59),
+        (MeepError::WrongRewardVault, 60),
+        (MeepError::MembershipNftNotStakable, 61),
+        (MeepError::InvalidRevealUriCount, 62),
+        (MeepError::RevealUrisExhausted, 63),
+        (MeepError::WrongSecondaryApproval, 64),
+        (MeepError::RewardMintInUse, 65),
+        (MeepError::MintedAfterProposal, 66),
+        (MeepError::WrongTreasuryTokenAccount, 67),
+    ]
+}
+
+#[test]
+fn error_codes() {
+    for (error, code) in get_error_codes() {
+        assert_eq!(error.clone() as u32, code, "{:?}", error);
+        assert_eq!(MeepError::from_u32(code), Some(error.clone()));
+        assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+    }
+
+    let next_code = get_error_codes().len() as u32;
+    assert_eq!(
+        MeepError::from_u32(next_code),
+        None,
+        "new errors need a frozen code here"
+    );
+}
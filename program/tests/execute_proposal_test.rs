@@ -0,0 +1,452 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{
+    instruction::{CreateProposalArgs, GovernanceArgs, ProposalAction},
+    state::ProposalState,
+    utils::Pda,
+};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::{thread::sleep, time::Duration};
+
+#[allow(dead_code)]
+mod common;
+
+fn pass_proposal(
+    client: &MeepRpcClient,
+    authority: &Keypair,
+    collection_id: u64,
+    nfts: &[(Keypair, Keypair)],
+    governance: &GovernanceArgs,
+    action: ProposalAction,
+) -> Pubkey {
+    let args = CreateProposalArgs {
+        description_uri: "https://test.com/proposal".to_string(),
+        action: action.clone(),
+    };
+    let (mint, token_account) = &nfts[0];
+
+    let proposal = client
+        .create_proposal(
+            &authority.pubkey(),
+            collection_id,
+            authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &args,
+        )
+        .unwrap();
+
+    for (mint, token_account) in nfts.iter() {
+        client
+            .cast_vote(
+                &authority.pubkey(),
+                collection_id,
+                &proposal,
+                authority,
+                &token_account.pubkey(),
+                &mint.pubkey(),
+                true,
+            )
+            .unwrap();
+    }
+
+    // only approved proposals are executable
+    assert!(client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .is_err());
+
+    sleep(Duration::from_secs(governance.voting_period as u64 + 2));
+    client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .unwrap();
+
+    // the execution delay starts when voting ends
+    assert!(client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .is_err());
+
+    sleep(Duration::from_secs(governance.execution_delay as u64));
+    client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .unwrap();
+    assert_eq!(
+        client.get_proposal(&proposal).state,
+        ProposalState::Executed
+    );
+
+    // and are executed once
+    assert!(client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .is_err());
+
+    proposal
+}
+
+#[test]
+fn execute_proposal() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings).0;
+    let governance_pda = Pda::governance_pubkey_with_bump(&meep::ID, &settings).0;
+
+    let nfts: Vec<(Keypair, Keypair)> = (0..2).map(|_| (Keypair::new(), Keypair::new())).collect();
+    for (mint, token_account) in nfts.iter() {
+        client
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                mint,
+                token_account,
+                &get_random_mint_args(),
+            )
+            .unwrap();
+    }
+
+    let governance = GovernanceArgs {
+        quorum: 2,
+        threshold_percentage: 50,
+        voting_period: 5,
+        execution_delay: 5,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    // spend treasury lamports
+    client
+        .deposit(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            None,
+            10_000_000,
+        )
+        .unwrap();
+    let recipient = Keypair::new().pubkey();
+    pass_proposal(
+        &client,
+        &authority,
+        collection_id,
+        &nfts,
+        &governance,
+        ProposalAction::TreasuryTransfer {
+            recipient,
+            amount: 4_000_000,
+            treasury_token_account: None,
+        },
+    );
+    assert_eq!(client.get_balance(&recipient), 4_000_000);
+    assert_eq!(client.get_balance(&treasury), 6_000_000);
+
+    // change the settings
+    let mut new_settings = get_random_settings();
+    new_settings.treasury_mint_fee = 42;
+    pass_proposal(
+        &client,
+        &authority,
+        collection_id,
+        &nfts,
+        &governance,
+        ProposalAction::UpdateSettings(new_settings.clone()),
+    );
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(
+        state.primary_wallet_percentage,
+        new_settings.primary_wallet_percentage
+    );
+    assert_eq!(state.treasury_mint_fee, 42);
+
+    // hand the authority over to the governance PDA, the settings address stays the same
+    pass_proposal(
+        &client,
+        &authority,
+        collection_id,
+        &nfts,
+        &governance,
+        ProposalAction::RotateAuthority(governance_pda),
+    );
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.authority, governance_pda);
+    assert_eq!(state.original_authority, authority.pubkey());
+
+    assert!(client
+        .update_settings(&authority, collection_id, &get_random_settings())
+        .is_err());
+}
+
+#[test]
+fn act_as_rotated_authority() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let nfts: Vec<(Keypair, Keypair)> = (0..2).map(|_| (Keypair::new(), Keypair::new())).collect();
+    for (mint, token_account) in nfts.iter() {
+        client
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                mint,
+                token_account,
+                &get_random_mint_args(),
+            )
+            .unwrap();
+    }
+
+    let governance = GovernanceArgs {
+        quorum: 2,
+        threshold_percentage: 50,
+        voting_period: 5,
+        execution_delay: 0,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    let new_authority = Keypair::new();
+    pass_proposal(
+        &client,
+        &authority,
+        collection_id,
+        &nfts,
+        &governance,
+        ProposalAction::RotateAuthority(new_authority.pubkey()),
+    );
+
+    // the settings are still derived from the original authority, the new one signs
+    let minter = Keypair::new().pubkey();
+    assert!(client
+        .add_delegated_minter(&authority, collection_id, &minter)
+        .is_err());
+    client
+        .add_delegated_minter_as(&authority.pubkey(), collection_id, &new_authority, &minter)
+        .unwrap();
+
+    let mut new_settings = get_random_settings();
+    new_settings.treasury_mint_fee = 42;
+    client
+        .update_settings_as(
+            &authority.pubkey(),
+            collection_id,
+            &new_authority,
+            &new_settings,
+        )
+        .unwrap();
+
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.authority, new_authority.pubkey());
+    assert_eq!(state.delegated_minters, vec![minter]);
+    assert_eq!(state.treasury_mint_fee, 42);
+}
+
+#[test]
+fn execute_token_transfer() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings).0;
+
+    let (mint, token_account) = (Keypair::new(), Keypair::new());
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    let governance = GovernanceArgs {
+        quorum: 1,
+        threshold_percentage: 50,
+        voting_period: 5,
+        execution_delay: 0,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    // the treasury holds the token in two accounts, the proposal spends from one
+    let token_mint = client.create_mint(&authority.pubkey(), 0);
+    let treasury_token_account = client.create_token_account(&token_mint, &treasury);
+    let other_treasury_token_account = client.create_token_account(&token_mint, &treasury);
+    for account in [treasury_token_account, other_treasury_token_account].iter() {
+        client.mint_tokens(&authority, &token_mint, account, 1_000);
+    }
+    let recipient = client.create_token_account(&token_mint, &Keypair::new().pubkey());
+
+    let action = ProposalAction::TreasuryTransfer {
+        recipient,
+        amount: 400,
+        treasury_token_account: Some(treasury_token_account),
+    };
+    let args = CreateProposalArgs {
+        description_uri: "https://test.com/proposal".to_string(),
+        action: action.clone(),
+    };
+    let proposal = client
+        .create_proposal(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &args,
+        )
+        .unwrap();
+    client
+        .cast_vote(
+            &authority.pubkey(),
+            collection_id,
+            &proposal,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            true,
+        )
+        .unwrap();
+
+    sleep(Duration::from_secs(governance.voting_period as u64 + 2));
+    client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .unwrap();
+
+    // the executor can't swap in another treasury token account
+    let swapped = ProposalAction::TreasuryTransfer {
+        recipient,
+        amount: 400,
+        treasury_token_account: Some(other_treasury_token_account),
+    };
+    assert!(client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &swapped)
+        .is_err());
+
+    client
+        .execute_proposal(&authority.pubkey(), collection_id, &proposal, &action)
+        .unwrap();
+    assert_eq!(client.get_token_balance(&recipient), 400);
+    assert_eq!(client.get_token_balance(&treasury_token_account), 600);
+    assert_eq!(
+        client.get_token_balance(&other_treasury_token_account),
+        1_000
+    );
+}
+
+#[test]
+fn execute_delayed_settings_update() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    let mut args = get_random_settings();
+    args.settings_delay = 3_600;
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(&authority, collection_id, &secondary_creator, &args)
+        .unwrap();
+
+    let nfts: Vec<(Keypair, Keypair)> = (0..2).map(|_| (Keypair::new(), Keypair::new())).collect();
+    for (mint, token_account) in nfts.iter() {
+        client
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                mint,
+                token_account,
+                &get_random_mint_args(),
+            )
+            .unwrap();
+    }
+
+    let governance = GovernanceArgs {
+        quorum: 2,
+        threshold_percentage: 50,
+        voting_period: 5,
+        execution_delay: 2,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    let mut queued = get_random_settings();
+    queued.settings_delay = args.settings_delay;
+    client
+        .update_settings(&authority, collection_id, &queued)
+        .unwrap();
+
+    // the governance update replaces the queued one and waits for the settings delay
+    let mut voted = get_random_settings();
+    voted.settings_delay = args.settings_delay;
+    voted.treasury_mint_fee = 42;
+    pass_proposal(
+        &client,
+        &authority,
+        collection_id,
+        &nfts,
+        &governance,
+        ProposalAction::UpdateSettings(voted.clone()),
+    );
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.treasury_mint_fee, args.treasury_mint_fee);
+    assert_eq!(state.pending_settings.unwrap().settings, voted);
+
+    // and the secondary creator can veto it
+    client
+        .veto_pending_settings(&authority.pubkey(), collection_id, &secondary_creator)
+        .unwrap();
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert!(state.pending_settings.is_none());
+    assert_eq!(state.minimum_price, args.minimum_price);
+}
@@ -0,0 +1,271 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{
+    instruction::{CreateProposalArgs, GovernanceArgs, ProposalAction},
+    state::ProposalState,
+    utils::Pda,
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{thread::sleep, time::Duration};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn governance() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    // NFTs are minted to the authority's token accounts
+    let nfts: Vec<(Keypair, Keypair)> = (0..3).map(|_| (Keypair::new(), Keypair::new())).collect();
+    for (mint, token_account) in nfts.iter() {
+        client
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                mint,
+                token_account,
+                &get_random_mint_args(),
+            )
+            .unwrap();
+    }
+
+    let args = CreateProposalArgs {
+        description_uri: "https://test.com/proposal".to_string(),
+        action: ProposalAction::None,
+    };
+    let (mint, token_account) = &nfts[0];
+
+    assert!(client
+        .create_proposal(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &args,
+        )
+        .is_err());
+
+    let governance = GovernanceArgs {
+        quorum: 2,
+        threshold_percentage: 60,
+        voting_period: 10,
+        execution_delay: 0,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    // only holders can propose
+    let stranger = Keypair::new();
+    client.airdrop(&stranger, lamports);
+    assert!(client
+        .create_proposal(
+            &authority.pubkey(),
+            collection_id,
+            &stranger,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &args,
+        )
+        .is_err());
+
+    let proposal = client
+        .create_proposal(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &args,
+        )
+        .unwrap();
+    assert_eq!(
+        client
+            .get_settings(&authority.pubkey(), collection_id)
+            .proposal_count,
+        1
+    );
+
+    for (index, (mint, token_account)) in nfts.iter().enumerate() {
+        client
+            .cast_vote(
+                &authority.pubkey(),
+                collection_id,
+                &proposal,
+                &authority,
+                &token_account.pubkey(),
+                &mint.pubkey(),
+                index < 2,
+            )
+            .unwrap();
+    }
+
+    // one vote per NFT
+    assert!(client
+        .cast_vote(
+            &authority.pubkey(),
+            collection_id,
+            &proposal,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            true,
+        )
+        .is_err());
+
+    // NFTs minted once the proposal exists don't swing it
+    sleep(Duration::from_secs(2));
+    let (late_mint, late_token_account) = (Keypair::new(), Keypair::new());
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &late_mint,
+            &late_token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    assert!(client
+        .cast_vote(
+            &authority.pubkey(),
+            collection_id,
+            &proposal,
+            &authority,
+            &late_token_account.pubkey(),
+            &late_mint.pubkey(),
+            true,
+        )
+        .is_err());
+
+    let state = client.get_proposal(&proposal);
+    assert_eq!(state.yes_votes, 2);
+    assert_eq!(state.no_votes, 1);
+    assert_eq!(state.state, ProposalState::Voting);
+    assert_eq!(state.governance.quorum, governance.quorum);
+
+    // the proposal is counted by the governance it was created under
+    let stricter = GovernanceArgs {
+        quorum: 5,
+        threshold_percentage: 100,
+        ..governance
+    };
+    client
+        .configure_governance(&authority, collection_id, &stricter)
+        .unwrap();
+
+    assert!(client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .is_err());
+
+    sleep(Duration::from_secs(governance.voting_period as u64 + 2));
+
+    client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .unwrap();
+    assert_eq!(
+        client.get_proposal(&proposal).state,
+        ProposalState::Approved
+    );
+
+    assert!(client
+        .finalize_proposal(&authority.pubkey(), collection_id, &proposal)
+        .is_err());
+}
+
+#[test]
+fn prefunded_governance_accounts() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    let governance = GovernanceArgs {
+        quorum: 1,
+        threshold_percentage: 50,
+        voting_period: 10,
+        execution_delay: 0,
+    };
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+
+    // lamports sent to the next proposal and its vote record don't block them
+    let proposal = Pda::proposal_pubkey_with_bump(&meep::ID, &settings, 0).0;
+    let vote_record = Pda::vote_record_pubkey_with_bump(&meep::ID, &proposal, &mint.pubkey()).0;
+    client.transfer(&authority, &proposal, 1);
+    client.transfer(&authority, &vote_record, 10_000_000);
+
+    let args = CreateProposalArgs {
+        description_uri: "https://test.com/proposal".to_string(),
+        action: ProposalAction::None,
+    };
+    assert_eq!(
+        client
+            .create_proposal(
+                &authority.pubkey(),
+                collection_id,
+                &authority,
+                &token_account.pubkey(),
+                &mint.pubkey(),
+                &args,
+            )
+            .unwrap(),
+        proposal
+    );
+
+    client
+        .cast_vote(
+            &authority.pubkey(),
+            collection_id,
+            &proposal,
+            &authority,
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            true,
+        )
+        .unwrap();
+    assert_eq!(client.get_proposal(&proposal).yes_votes, 1);
+}
@@ -1,5 +1,6 @@
 use crate::common::get_random_settings;
 use common::rpc_client::MeepRpcClient;
+use meep::utils::{Pda, TREASURY_SEED};
 use solana_sdk::{signature::Keypair, signer::Signer};
 
 #[allow(dead_code)]
@@ -11,31 +12,67 @@ fn initialization() {
 
     for _ in 0..10 {
         let authority = Keypair::new();
-        let secondary_creator = Keypair::new();
-        let args = get_random_settings();
 
         let lamports = 1_000_000_000;
         client.airdrop(&authority, lamports);
 
-        client
-            .initialize_meep(&authority, &secondary_creator, &args)
-            .unwrap();
-
-        let another_secondary_creator = Keypair::new();
-        let another_args = get_random_settings();
-
-        // double initialization
-        assert!(client
-            .initialize_meep(&authority, &another_secondary_creator, &another_args)
-            .is_err());
-
-        let settings = client.get_settings(&authority.pubkey());
-        assert_eq!(settings.authority, authority.pubkey());
-        assert_eq!(settings.secondary_creator, secondary_creator.pubkey());
-        assert_eq!(
-            settings.primary_wallet_percentage,
-            args.primary_wallet_percentage
-        );
-        assert_eq!(settings.minimum_price, args.minimum_price);
+        // independent configurations of one authority
+        for collection_id in 0..3 {
+            let secondary_creator = Keypair::new();
+            let args = get_random_settings();
+
+            client
+                .initialize_meep(&authority, collection_id, &secondary_creator, &args)
+                .unwrap();
+
+            let another_secondary_creator = Keypair::new();
+            let another_args = get_random_settings();
+
+            // double initialization
+            assert!(client
+                .initialize_meep(
+                    &authority,
+                    collection_id,
+                    &another_secondary_creator,
+                    &another_args
+                )
+                .is_err());
+
+            let settings = client.get_settings(&authority.pubkey(), collection_id);
+            assert_eq!(settings.authority, authority.pubkey());
+            assert_eq!(settings.collection_id, collection_id);
+            assert_eq!(settings.secondary_creator, secondary_creator.pubkey());
+            assert_eq!(
+                settings.primary_wallet_percentage,
+                args.primary_wallet_percentage
+            );
+            assert_eq!(settings.minimum_price, args.minimum_price);
+
+            // the stored bump derives the settings address
+            let (settings_pubkey, bump) =
+                Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id);
+            assert_eq!(settings.bump, bump);
+            assert_eq!(
+                Pda::settings_pubkey(&meep::ID, &authority.pubkey(), collection_id, bump),
+                Ok(settings_pubkey)
+            );
+
+            // so do the bumps of the settings' PDAs
+            let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings_pubkey);
+            assert_eq!(settings.bumps.treasury, treasury.1);
+            assert_eq!(
+                Pda::settings_pda_pubkey(
+                    &meep::ID,
+                    TREASURY_SEED,
+                    &settings_pubkey,
+                    settings.bumps.treasury
+                ),
+                Ok(treasury.0)
+            );
+            assert_eq!(
+                settings.bumps.authority,
+                Pda::authority_pubkey_with_bump(&meep::ID, &settings_pubkey).1
+            );
+        }
     }
 }
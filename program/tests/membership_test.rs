@@ -0,0 +1,233 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::InitializeMeepArgs, utils::Pda};
+use solana_program::program_option::COption;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_token::state::AccountState;
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn membership_badges() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_random_settings(),
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: true,
+    };
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+    assert!(
+        client
+            .get_settings(&authority.pubkey(), collection_id)
+            .membership
+    );
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    let badge_mint = client.get_mint(&mint.pubkey());
+    assert_eq!(badge_mint.freeze_authority, COption::Some(authority_pda));
+    assert_eq!(badge_mint.mint_authority, COption::None);
+    assert_eq!(badge_mint.supply, 1);
+    assert!(!client.account_exists(&Pda::master_edition_pubkey(&mint.pubkey())));
+    let badge = client.get_token_account(&token_account.pubkey());
+    assert_eq!(badge.owner, authority_pda);
+    assert_eq!(badge.state, AccountState::Frozen);
+
+    // holders can't burn a badge themselves
+    assert!(client
+        .burn_nft(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+
+    // hand the badge out
+    let member = Keypair::new();
+    let member_token_account = client.create_token_account(&mint.pubkey(), &member.pubkey());
+    client
+        .recover_membership(
+            &authority,
+            collection_id,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &member,
+            &member_token_account,
+        )
+        .unwrap();
+
+    assert!(!client.account_exists(&token_account.pubkey()));
+    let badge = client.get_token_account(&member_token_account);
+    assert_eq!(badge.owner, member.pubkey());
+    assert_eq!(badge.amount, 1);
+    assert_eq!(badge.delegate, COption::Some(authority_pda));
+    assert_eq!(badge.state, AccountState::Frozen);
+
+    // soulbound
+    client.airdrop(&member, lamports);
+    let other_token_account = client.create_token_account(&mint.pubkey(), &member.pubkey());
+    assert!(client
+        .transfer_tokens(&member, &member_token_account, &other_token_account, 1)
+        .is_err());
+    assert!(client
+        .burn_nft(
+            &authority.pubkey(),
+            collection_id,
+            &member,
+            &mint.pubkey(),
+            &member_token_account
+        )
+        .is_err());
+
+    // only the authority moves badges
+    let new_wallet = Keypair::new();
+    let new_token_account = client.create_token_account(&mint.pubkey(), &new_wallet.pubkey());
+    assert!(client
+        .recover_membership(
+            &member,
+            collection_id,
+            &mint.pubkey(),
+            &member_token_account,
+            &new_wallet,
+            &new_token_account,
+        )
+        .is_err());
+
+    // the member lost their wallet
+    client
+        .recover_membership(
+            &authority,
+            collection_id,
+            &mint.pubkey(),
+            &member_token_account,
+            &new_wallet,
+            &new_token_account,
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&member_token_account), 0);
+    let badge = client.get_token_account(&new_token_account);
+    assert_eq!(badge.amount, 1);
+    assert_eq!(badge.state, AccountState::Frozen);
+
+    assert!(client
+        .revoke_membership(&member, collection_id, &mint.pubkey(), &new_token_account)
+        .is_err());
+
+    let mint_record = Pda::mint_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    let reclaimed = client.get_balance(&mint_record);
+    let balance = client.get_balance(&authority.pubkey());
+
+    client
+        .revoke_membership(
+            &authority,
+            collection_id,
+            &mint.pubkey(),
+            &new_token_account,
+        )
+        .unwrap();
+
+    assert_eq!(client.get_balance(&authority.pubkey()), balance + reclaimed);
+    assert!(!client.account_exists(&mint_record));
+    assert_eq!(client.get_mint(&mint.pubkey()).supply, 0);
+    assert_eq!(client.get_token_balance(&new_token_account), 0);
+}
+
+#[test]
+fn revoke_unassigned_badge() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    for &membership in &[false, true] {
+        let args = InitializeMeepArgs {
+            collection_id: membership as u64,
+            settings: get_random_settings(),
+            pda_authority: false,
+            royalty_vault: false,
+            payment_mint: None,
+            membership,
+        };
+        client
+            .initialize_meep_with_args(&authority, &secondary_creator, &args)
+            .unwrap();
+    }
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            1,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // regular NFTs aren't badges
+    let plain_mint = Keypair::new();
+    let plain_token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            0,
+            &secondary_creator,
+            &plain_mint,
+            &plain_token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    assert!(client
+        .revoke_membership(
+            &authority,
+            0,
+            &plain_mint.pubkey(),
+            &plain_token_account.pubkey()
+        )
+        .is_err());
+
+    // a badge still held by the PDA is revoked with its token account
+    let mint_record = Pda::mint_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    let reclaimed = client.get_balance(&token_account.pubkey()) + client.get_balance(&mint_record);
+    let balance = client.get_balance(&authority.pubkey());
+
+    client
+        .revoke_membership(&authority, 1, &mint.pubkey(), &token_account.pubkey())
+        .unwrap();
+
+    assert_eq!(client.get_balance(&authority.pubkey()), balance + reclaimed);
+    assert!(!client.account_exists(&token_account.pubkey()));
+    assert!(!client.account_exists(&mint_record));
+}
@@ -0,0 +1,290 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::MintApprovalArgs, utils::Pda};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[allow(dead_code)]
+mod common;
+
+fn is_secondary_verified(client: &MeepRpcClient, mint: &Pubkey) -> bool {
+    let creators = client.get_metadata(mint).data.creators.unwrap();
+    creators[1].verified
+}
+
+fn is_approval_verified(client: &MeepRpcClient, settings: &Pubkey, mint: &Pubkey) -> bool {
+    let secondary_approval = Pda::secondary_approval_pubkey_with_bump(&meep::ID, settings).0;
+    let creators = client.get_metadata(mint).data.creators.unwrap();
+    creators
+        .iter()
+        .any(|creator| creator.address == secondary_approval && creator.verified)
+}
+
+#[test]
+fn mint_approval() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let initialize_args = get_random_settings();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &initialize_args,
+        )
+        .unwrap();
+
+    let mint_nft = |mint: &Keypair| {
+        client.approved_mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+            mint,
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+    };
+
+    // no approval yet
+    assert!(mint_nft(&Keypair::new()).is_err());
+
+    // only the secondary creator grants approvals
+    let approval = MintApprovalArgs {
+        max_mints: Some(2),
+        expires_at: None,
+        delegate: None,
+    };
+    assert!(client
+        .grant_mint_approval(&authority.pubkey(), collection_id, &authority, &approval)
+        .is_err());
+
+    client
+        .grant_mint_approval(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &approval,
+        )
+        .unwrap();
+
+    let mints = vec![Keypair::new(), Keypair::new()];
+    for mint in mints.iter() {
+        mint_nft(mint).unwrap();
+        assert!(!is_secondary_verified(&client, &mint.pubkey()));
+    }
+
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(settings.mint_approval.unwrap().remaining, Some(0));
+
+    // count limit is reached
+    assert!(mint_nft(&Keypair::new()).is_err());
+
+    let mint_pubkeys: Vec<Pubkey> = mints.iter().map(|mint| mint.pubkey()).collect();
+    client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &mint_pubkeys,
+        )
+        .unwrap();
+
+    for mint in mint_pubkeys.iter() {
+        assert!(is_secondary_verified(&client, mint));
+    }
+
+    // already verified metadata is skipped
+    client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &mint_pubkeys,
+        )
+        .unwrap();
+
+    let expired = MintApprovalArgs {
+        max_mints: None,
+        expires_at: Some(1),
+        delegate: None,
+    };
+    client
+        .grant_mint_approval(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &expired,
+        )
+        .unwrap();
+    assert!(mint_nft(&Keypair::new()).is_err());
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let unlimited = MintApprovalArgs {
+        max_mints: None,
+        expires_at: Some(now + 3600),
+        delegate: None,
+    };
+    client
+        .grant_mint_approval(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &unlimited,
+        )
+        .unwrap();
+    mint_nft(&Keypair::new()).unwrap();
+
+    client
+        .revoke_mint_approval(&authority.pubkey(), collection_id, &secondary_creator)
+        .unwrap();
+    assert!(mint_nft(&Keypair::new()).is_err());
+}
+
+#[test]
+fn mint_approval_delegate() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let delegate = Keypair::new();
+    let initialize_args = get_random_settings();
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &initialize_args,
+        )
+        .unwrap();
+
+    let approval = MintApprovalArgs {
+        max_mints: None,
+        expires_at: None,
+        delegate: Some(delegate.pubkey()),
+    };
+    client
+        .grant_mint_approval(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &approval,
+        )
+        .unwrap();
+
+    let mint = Keypair::new();
+    client
+        .approved_mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator.pubkey(),
+            &mint,
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    assert!(!is_approval_verified(&client, &settings, &mint.pubkey()));
+
+    // only the secondary creator or its delegate signs
+    assert!(client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &Keypair::new(),
+            &[mint.pubkey()],
+        )
+        .is_err());
+
+    // the delegate verifies the approval, the secondary creator stays unverified
+    client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &delegate,
+            &[mint.pubkey()],
+        )
+        .unwrap();
+    assert!(is_approval_verified(&client, &settings, &mint.pubkey()));
+    assert!(!is_secondary_verified(&client, &mint.pubkey()));
+
+    client
+        .sign_as_secondary(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator,
+            &[mint.pubkey()],
+        )
+        .unwrap();
+    assert!(is_secondary_verified(&client, &mint.pubkey()));
+}
+
+#[test]
+fn sign_as_secondary_other_collection() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    let initialize_args = get_random_settings();
+    let approval = MintApprovalArgs {
+        max_mints: None,
+        expires_at: None,
+        delegate: None,
+    };
+
+    client.airdrop(&authority, lamports);
+    for collection_id in 0..2 {
+        client
+            .initialize_meep(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                &initialize_args,
+            )
+            .unwrap();
+        client
+            .grant_mint_approval(
+                &authority.pubkey(),
+                collection_id,
+                &secondary_creator,
+                &approval,
+            )
+            .unwrap();
+    }
+
+    let mint = Keypair::new();
+    client
+        .approved_mint_nft(
+            &authority,
+            1,
+            &secondary_creator.pubkey(),
+            &mint,
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // the mint record belongs to the other settings
+    assert!(client
+        .sign_as_secondary(&authority.pubkey(), 0, &secondary_creator, &[mint.pubkey()])
+        .is_err());
+    assert!(!is_secondary_verified(&client, &mint.pubkey()));
+
+    client
+        .sign_as_secondary(&authority.pubkey(), 1, &secondary_creator, &[mint.pubkey()])
+        .unwrap();
+    assert!(is_secondary_verified(&client, &mint.pubkey()));
+}
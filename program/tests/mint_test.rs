@@ -1,6 +1,8 @@
 use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::utils::Pda;
 use solana_sdk::{signature::Keypair, signer::Signer};
 
+#[allow(dead_code)]
 mod common;
 
 #[test]
@@ -10,6 +12,7 @@ fn mint_token() {
 
     for i in 1..=10 {
         let authority = Keypair::new();
+        let collection_id = 0;
         let secondary_creator = Keypair::new();
         let mint = Keypair::new();
         let token = Keypair::new();
@@ -23,13 +26,25 @@ fn mint_token() {
         let authority_initial_balance = client.get_balance(&authority.pubkey());
 
         client
-            .initialize_meep(&authority, &secondary_creator, &initialize_args)
+            .initialize_meep(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                &initialize_args,
+            )
             .unwrap();
 
         let authority_balance = client.get_balance(&authority.pubkey());
 
         client
-            .mint_nft(&authority, &secondary_creator, &mint, &token, &mint_args)
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                &mint,
+                &token,
+                &mint_args,
+            )
             .unwrap();
 
         println!(
@@ -69,6 +84,15 @@ fn mint_token() {
             100 - initialize_args.primary_wallet_percentage
         );
 
+        let mint_record = client.get_mint_record(&mint.pubkey());
+        assert_eq!(mint_record.minter, authority.pubkey());
+        assert_eq!(mint_record.reveal_index, None);
+        assert_eq!(mint_record.revealed, false);
+        assert_eq!(
+            mint_record.bump,
+            Pda::mint_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).1
+        );
+
         let edition = client.get_master_edition(&mint.pubkey());
         assert_eq!(edition.supply, 0);
         assert_eq!(edition.max_supply, Some(0));
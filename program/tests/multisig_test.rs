@@ -0,0 +1,133 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::instruction::{GovernanceArgs, UpdateSignersArgs};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn multisig() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let founders: Vec<Keypair> = (0..2).map(|_| Keypair::new()).collect();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let args = UpdateSignersArgs {
+        signers: vec![
+            authority.pubkey(),
+            founders[0].pubkey(),
+            founders[1].pubkey(),
+        ],
+        threshold: 2,
+    };
+
+    // threshold can't exceed the signer set
+    assert!(client
+        .update_signers(
+            &authority,
+            collection_id,
+            &[],
+            &UpdateSignersArgs {
+                signers: vec![founders[0].pubkey()],
+                threshold: 2,
+            },
+        )
+        .is_err());
+
+    client
+        .update_signers(&authority, collection_id, &[], &args)
+        .unwrap();
+
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(settings.signers, args.signers);
+    assert_eq!(settings.signer_threshold, 2);
+
+    // the authority alone is below the threshold
+    let new_settings = get_random_settings();
+    assert!(client
+        .update_settings(&authority, collection_id, &new_settings)
+        .is_err());
+
+    // a stranger's signature doesn't count
+    let stranger = Keypair::new();
+    assert!(client
+        .multisig_update_settings(&authority, collection_id, &[&stranger], &new_settings)
+        .is_err());
+
+    client
+        .multisig_update_settings(&authority, collection_id, &[&founders[1]], &new_settings)
+        .unwrap();
+    assert_eq!(
+        client
+            .get_settings(&authority.pubkey(), collection_id)
+            .primary_wallet_percentage,
+        new_settings.primary_wallet_percentage
+    );
+
+    // minting by the authority takes the threshold as well
+    assert!(client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &Keypair::new(),
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .is_err());
+
+    client
+        .multisig_mint_nft(
+            &authority,
+            collection_id,
+            &[&founders[0]],
+            &secondary_creator,
+            &Keypair::new(),
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // so does every other authority instruction
+    let governance = GovernanceArgs {
+        quorum: 1,
+        threshold_percentage: 50,
+        voting_period: 10,
+        execution_delay: 0,
+    };
+    assert!(client
+        .configure_governance(&authority, collection_id, &governance)
+        .is_err());
+
+    // back to the single authority
+    let single = UpdateSignersArgs {
+        signers: Vec::new(),
+        threshold: 0,
+    };
+    assert!(client
+        .update_signers(&authority, collection_id, &[], &single)
+        .is_err());
+    client
+        .update_signers(&authority, collection_id, &[&founders[0]], &single)
+        .unwrap();
+
+    client
+        .update_settings(&authority, collection_id, &get_random_settings())
+        .unwrap();
+    client
+        .configure_governance(&authority, collection_id, &governance)
+        .unwrap();
+}
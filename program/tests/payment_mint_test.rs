@@ -0,0 +1,208 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::InitializeMeepArgs, utils::Pda};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn payment_mint() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    let usdc = client.create_mint(&Keypair::new().pubkey(), 6);
+
+    let mut args = InitializeMeepArgs {
+        collection_id: 0,
+        settings: get_random_settings(),
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: Some(Keypair::new().pubkey()),
+        membership: false,
+    };
+
+    // payment mint must be an initialized SPL token mint
+    assert!(client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .is_err());
+
+    args.payment_mint = Some(usdc);
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+
+    let settings = client.get_settings(&authority.pubkey(), 0);
+    assert_eq!(settings.payment_mint, Some(usdc));
+    assert_eq!(settings.minimum_price, args.settings.minimum_price);
+
+    // lamport pricing stays the default
+    client
+        .initialize_meep(&authority, 1, &secondary_creator, &get_random_settings())
+        .unwrap();
+    assert_eq!(
+        client.get_settings(&authority.pubkey(), 1).payment_mint,
+        None
+    );
+}
+
+#[test]
+fn paid_mint() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    let buyer = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client.airdrop(&buyer, lamports);
+
+    let usdc_authority = Keypair::new();
+    let usdc = client.create_mint(&usdc_authority.pubkey(), 6);
+
+    let mut settings = get_random_settings();
+    settings.minimum_price = 1_000_000;
+    settings.treasury_fee_basis_points = 1_000;
+    let args = InitializeMeepArgs {
+        collection_id: 0,
+        settings,
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: Some(usdc),
+        membership: false,
+    };
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+
+    let buyer_usdc = client.create_token_account(&usdc, &buyer.pubkey());
+    let authority_usdc = client.create_token_account(&usdc, &authority.pubkey());
+    let treasury = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), 0).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &treasury).0;
+    let treasury_usdc = client.create_token_account(&usdc, &treasury);
+    client.mint_tokens(&usdc_authority, &usdc, &buyer_usdc, 1_500_000);
+
+    let mint_nft = |token_account: &Keypair,
+                    recipient: &Pubkey,
+                    token_accounts: Option<(&Pubkey, &Pubkey)>| {
+        client.paid_mint_nft(
+            &authority.pubkey(),
+            0,
+            &buyer,
+            &secondary_creator,
+            &Keypair::new(),
+            token_account,
+            &get_random_mint_args(),
+            recipient,
+            token_accounts,
+        )
+    };
+
+    // lamports aren't accepted with a payment mint
+    assert!(mint_nft(&Keypair::new(), &authority.pubkey(), None).is_err());
+
+    // the price goes to the authority's and the treasury's token accounts
+    assert!(mint_nft(
+        &Keypair::new(),
+        &buyer_usdc,
+        Some((&buyer_usdc, &treasury_usdc))
+    )
+    .is_err());
+    assert!(mint_nft(
+        &Keypair::new(),
+        &authority_usdc,
+        Some((&buyer_usdc, &authority_usdc))
+    )
+    .is_err());
+
+    let token_account = Keypair::new();
+    mint_nft(
+        &token_account,
+        &authority_usdc,
+        Some((&buyer_usdc, &treasury_usdc)),
+    )
+    .unwrap();
+    assert_eq!(client.get_token_balance(&authority_usdc), 900_000);
+    assert_eq!(client.get_token_balance(&treasury_usdc), 100_000);
+    assert_eq!(client.get_token_balance(&buyer_usdc), 500_000);
+
+    // the buyer holds the NFT, the authority keeps the update authority
+    let token = client.get_token_account(&token_account.pubkey());
+    assert_eq!(token.owner, buyer.pubkey());
+    assert_eq!(token.amount, 1);
+    let metadata = client.get_metadata(&token.mint);
+    assert_eq!(metadata.update_authority, authority.pubkey());
+    assert!(metadata
+        .data
+        .creators
+        .unwrap()
+        .iter()
+        .all(|creator| creator.address != buyer.pubkey()));
+
+    // below the minimum price
+    assert!(mint_nft(
+        &Keypair::new(),
+        &authority_usdc,
+        Some((&buyer_usdc, &treasury_usdc))
+    )
+    .is_err());
+    assert_eq!(client.get_token_balance(&buyer_usdc), 500_000);
+}
+
+#[test]
+fn public_mint() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let secondary_creator = Keypair::new();
+    let buyer = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client.airdrop(&buyer, lamports);
+
+    let mut settings = get_random_settings();
+    settings.minimum_price = 0;
+    settings.treasury_fee_basis_points = 2_000;
+    client
+        .initialize_meep(&authority, 0, &secondary_creator, &settings)
+        .unwrap();
+
+    let mint_nft = |token_account: &Keypair| {
+        client.paid_mint_nft(
+            &authority.pubkey(),
+            0,
+            &buyer,
+            &secondary_creator,
+            &Keypair::new(),
+            token_account,
+            &get_random_mint_args(),
+            &authority.pubkey(),
+            None,
+        )
+    };
+
+    // no public mint without a price
+    assert!(mint_nft(&Keypair::new()).is_err());
+
+    settings.minimum_price = 10_000_000;
+    client.update_settings(&authority, 0, &settings).unwrap();
+
+    let treasury = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), 0).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &treasury).0;
+    let balance = client.get_balance(&authority.pubkey());
+    let treasury_balance = client.get_balance(&treasury);
+
+    let token_account = Keypair::new();
+    mint_nft(&token_account).unwrap();
+    assert_eq!(client.get_balance(&authority.pubkey()), balance + 8_000_000);
+    assert_eq!(client.get_balance(&treasury), treasury_balance + 2_000_000);
+
+    let token = client.get_token_account(&token_account.pubkey());
+    assert_eq!(token.owner, buyer.pubkey());
+    assert_eq!(token.amount, 1);
+    assert_eq!(client.get_mint_record(&token.mint).minter, buyer.pubkey());
+}
@@ -0,0 +1,92 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{
+    instruction::{InitializeMeepArgs, UpdateNftMetadataArgs},
+    utils::Pda,
+};
+use solana_program::program_option::COption;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn pda_authority_mint() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_random_settings(),
+        pda_authority: true,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: false,
+    };
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    assert!(settings.pda_authority);
+
+    let settings_pubkey =
+        Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings_pubkey).0;
+
+    let mint = Keypair::new();
+    let mut mint_args = get_random_mint_args();
+    mint_args.is_mutable = true;
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &Keypair::new(),
+            &mint_args,
+        )
+        .unwrap();
+
+    let token_mint = client.get_mint(&mint.pubkey());
+    assert_eq!(token_mint.mint_authority, COption::Some(authority_pda));
+    assert_eq!(token_mint.supply, 1);
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.update_authority, authority_pda);
+
+    let creators = metadata.data.creators.unwrap();
+    assert_eq!(creators.len(), 3);
+    assert_eq!(creators[0].address, authority.pubkey());
+    assert!(creators[0].verified);
+    assert_eq!(creators[1].address, secondary_creator.pubkey());
+    assert!(creators[1].verified);
+    assert_eq!(creators[2].address, authority_pda);
+    assert_eq!(creators[2].share, 0);
+    assert!(creators[2].verified);
+
+    let args = UpdateNftMetadataArgs {
+        token_name: None,
+        uri: Some("https://test.com/updated".to_string()),
+        seller_fee_basis_points: None,
+        lock: false,
+    };
+
+    // the authority still has to sign, the PDA signs the Metaplex update
+    assert!(client
+        .update_nft_metadata(&secondary_creator, collection_id, &mint.pubkey(), &args)
+        .is_err());
+
+    client
+        .update_nft_metadata(&authority, collection_id, &mint.pubkey(), &args)
+        .unwrap();
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.update_authority, authority_pda);
+    assert_eq!(metadata.data.uri, "https://test.com/updated");
+}
@@ -0,0 +1,142 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{
+    instruction::{RevealArgs, RevealModeArgs},
+    utils::{reveal_commitment, reveal_proof, Pda},
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn reveal() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let initialize_args = get_random_settings();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &initialize_args,
+        )
+        .unwrap();
+
+    let uris: Vec<String> = (0..5)
+        .map(|i| format!("https://test.com/revealed/{}", i))
+        .collect();
+    let reveal_mode_args = RevealModeArgs {
+        placeholder_uri: "https://test.com/placeholder".to_string(),
+        uri_commitment: reveal_commitment(&uris),
+        uri_count: uris.len() as u32,
+    };
+
+    // the commitment has to cover at least one URI
+    let empty_args = RevealModeArgs {
+        uri_count: 0,
+        ..reveal_mode_args.clone()
+    };
+    assert!(client
+        .enable_reveal_mode(&authority, collection_id, &empty_args)
+        .is_err());
+
+    client
+        .enable_reveal_mode(&authority, collection_id, &reveal_mode_args)
+        .unwrap();
+
+    // the commitment can't be replaced
+    assert!(client
+        .enable_reveal_mode(&authority, collection_id, &reveal_mode_args)
+        .is_err());
+
+    let mut mints = Vec::new();
+    for index in 0..uris.len() {
+        let mint = Keypair::new();
+        let token = Keypair::new();
+        let mint_args = get_random_mint_args();
+
+        client
+            .mint_nft(
+                &authority,
+                collection_id,
+                &secondary_creator,
+                &mint,
+                &token,
+                &mint_args,
+            )
+            .unwrap();
+
+        let metadata = client.get_metadata(&mint.pubkey());
+        assert_eq!(metadata.data.uri, reveal_mode_args.placeholder_uri);
+        assert_eq!(metadata.data.name, mint_args.token_name);
+        assert_eq!(metadata.is_mutable, true);
+
+        let mint_record = client.get_mint_record(&mint.pubkey());
+        let settings_pubkey =
+            Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+        assert_eq!(mint_record.settings, settings_pubkey);
+        assert_eq!(mint_record.reveal_index, Some(index as u32));
+        assert_eq!(mint_record.revealed, false);
+
+        mints.push(mint.pubkey());
+    }
+
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    let reveal = settings.reveal.unwrap();
+    assert_eq!(reveal.minted, uris.len() as u32);
+    assert_eq!(reveal.uri_count, uris.len() as u32);
+
+    // every committed URI has an NFT, another one could never be revealed
+    assert!(client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &Keypair::new(),
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .is_err());
+    let settings = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(settings.reveal.unwrap().minted, uris.len() as u32);
+
+    for (index, mint) in mints.iter().enumerate() {
+        // URI of another NFT doesn't match the commitment
+        let wrong_args = RevealArgs {
+            uri: uris[(index + 1) % uris.len()].clone(),
+            proof: reveal_proof(&uris, index),
+        };
+        assert!(client
+            .reveal(&authority, collection_id, mint, &wrong_args)
+            .is_err());
+
+        let args = RevealArgs {
+            uri: uris[index].clone(),
+            proof: reveal_proof(&uris, index),
+        };
+
+        // only the authority can reveal
+        assert!(client
+            .reveal(&secondary_creator, collection_id, mint, &args)
+            .is_err());
+
+        client
+            .reveal(&authority, collection_id, mint, &args)
+            .unwrap();
+
+        let metadata = client.get_metadata(mint);
+        assert_eq!(metadata.data.uri, uris[index]);
+        assert_eq!(client.get_mint_record(mint).revealed, true);
+
+        // double reveal
+        assert!(client
+            .reveal(&authority, collection_id, mint, &args)
+            .is_err());
+    }
+}
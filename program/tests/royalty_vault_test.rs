@@ -0,0 +1,91 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::InitializeMeepArgs, utils::Pda};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn royalty_vault() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_random_settings(),
+        pda_authority: false,
+        royalty_vault: true,
+        payment_mint: None,
+        membership: false,
+    };
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+
+    let settings_pubkey =
+        Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let royalty_vault = Pda::royalty_vault_pubkey_with_bump(&meep::ID, &settings_pubkey).0;
+
+    let mint = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    let creators = client.get_metadata(&mint.pubkey()).data.creators.unwrap();
+    assert_eq!(creators.len(), 3);
+    assert_eq!(creators[0].address, authority.pubkey());
+    assert_eq!(creators[0].share, 0);
+    assert!(creators[0].verified);
+    assert_eq!(creators[1].address, secondary_creator.pubkey());
+    assert_eq!(creators[1].share, 0);
+    assert!(creators[1].verified);
+    assert_eq!(creators[2].address, royalty_vault);
+    assert_eq!(creators[2].share, 100);
+    assert!(creators[2].verified);
+
+    // a marketplace pays royalties to the vault
+    let royalties = 1_000_000;
+    let marketplace = Keypair::new();
+    client.airdrop(&marketplace, lamports);
+    client.transfer(&marketplace, &royalty_vault, royalties);
+
+    let authority_balance = client.get_balance(&authority.pubkey());
+    let secondary_balance = client.get_balance(&secondary_creator.pubkey());
+
+    // creators must match the settings
+    assert!(client
+        .distribute_royalties(&authority.pubkey(), collection_id, &marketplace.pubkey())
+        .is_err());
+
+    client
+        .distribute_royalties(
+            &authority.pubkey(),
+            collection_id,
+            &secondary_creator.pubkey(),
+        )
+        .unwrap();
+
+    let primary_part = royalties * args.settings.primary_wallet_percentage as u64 / 100;
+    assert_eq!(
+        client.get_balance(&authority.pubkey()),
+        authority_balance + primary_part
+    );
+    assert_eq!(
+        client.get_balance(&secondary_creator.pubkey()),
+        secondary_balance + royalties - primary_part
+    );
+    assert_eq!(client.get_balance(&royalty_vault), 0);
+}
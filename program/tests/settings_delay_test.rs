@@ -0,0 +1,103 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::utils::Pda;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{thread::sleep, time::Duration};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn settings_delay() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    let mut args = get_random_settings();
+    args.settings_delay = 5;
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(&authority, collection_id, &secondary_creator, &args)
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings).0;
+
+    // updates are queued
+    let mut new_settings = get_random_settings();
+    new_settings.settings_delay = args.settings_delay;
+    new_settings.treasury_mint_fee = 1_000_000;
+    client
+        .update_settings(&authority, collection_id, &new_settings)
+        .unwrap();
+
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.minimum_price, args.minimum_price);
+    assert_eq!(state.pending_settings.unwrap().settings, new_settings);
+
+    assert!(client
+        .apply_pending_settings(&authority.pubkey(), collection_id)
+        .is_err());
+
+    // only the secondary creator can veto
+    assert!(client
+        .veto_pending_settings(&authority.pubkey(), collection_id, &authority)
+        .is_err());
+    client
+        .veto_pending_settings(&authority.pubkey(), collection_id, &secondary_creator)
+        .unwrap();
+    assert!(client
+        .get_settings(&authority.pubkey(), collection_id)
+        .pending_settings
+        .is_none());
+
+    // mints use the pending settings once they are effective
+    client
+        .update_settings(&authority, collection_id, &new_settings)
+        .unwrap();
+    sleep(Duration::from_secs(args.settings_delay as u64 + 2));
+
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &Keypair::new(),
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    assert_eq!(
+        client.get_balance(&treasury),
+        new_settings.treasury_mint_fee
+    );
+
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.minimum_price, new_settings.minimum_price);
+    assert!(state.pending_settings.is_none());
+
+    // or once applied
+    let mut next_settings = get_random_settings();
+    next_settings.settings_delay = 1;
+    client
+        .update_settings(&authority, collection_id, &next_settings)
+        .unwrap();
+    sleep(Duration::from_secs(args.settings_delay as u64 + 2));
+
+    assert!(client
+        .veto_pending_settings(&authority.pubkey(), collection_id, &secondary_creator)
+        .is_err());
+    client
+        .apply_pending_settings(&authority.pubkey(), collection_id)
+        .unwrap();
+
+    let state = client.get_settings(&authority.pubkey(), collection_id);
+    assert_eq!(state.minimum_price, next_settings.minimum_price);
+    assert_eq!(state.settings_delay, 1);
+    assert!(client
+        .apply_pending_settings(&authority.pubkey(), collection_id)
+        .is_err());
+}
@@ -0,0 +1,591 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::InitializeMeepArgs, utils::Pda};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{thread::sleep, time::Duration};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn stake_and_claim_minted_rewards() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+    let reward_rate = 1_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // nothing to earn before the authority configures staking
+    assert!(client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+
+    // the authority PDA mints the rewards
+    let reward_mint = client.create_mint(&authority_pda, 6);
+    let other = Keypair::new();
+    client.airdrop(&other, lamports);
+    assert!(client
+        .configure_staking(&other, collection_id, &reward_mint, reward_rate)
+        .is_err());
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, reward_rate)
+        .unwrap();
+
+    let staking = client
+        .get_settings(&authority.pubkey(), collection_id)
+        .staking
+        .unwrap();
+    assert_eq!(staking.reward_mint, reward_mint);
+    assert_eq!(staking.reward_rate, reward_rate);
+
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+
+    let stake_account = Pda::stake_account_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    assert_eq!(client.get_token_balance(&token_account.pubkey()), 0);
+    assert_eq!(client.get_token_balance(&stake_account), 1);
+
+    let stake_record = client.get_stake_record(&mint.pubkey());
+    assert_eq!(stake_record.settings, settings);
+    assert_eq!(stake_record.staker, authority.pubkey());
+    assert_eq!(stake_record.claimed_at, stake_record.staked_at);
+
+    sleep(Duration::from_secs(3));
+
+    // rewards go to the staker only
+    let reward_token_account = client.create_token_account(&reward_mint, &authority.pubkey());
+    let other_reward_account = client.create_token_account(&reward_mint, &other.pubkey());
+    assert!(client
+        .claim_rewards(
+            &authority.pubkey(),
+            collection_id,
+            &other,
+            &mint.pubkey(),
+            &other_reward_account,
+            &reward_mint,
+            None
+        )
+        .is_err());
+
+    client
+        .claim_rewards(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            None,
+        )
+        .unwrap();
+
+    let claimed = client.get_stake_record(&mint.pubkey());
+    let seconds = (claimed.claimed_at - claimed.staked_at) as u64;
+    assert!(seconds > 0);
+    assert_eq!(
+        client.get_token_balance(&reward_token_account),
+        seconds * reward_rate
+    );
+
+    // only the staker unstakes
+    assert!(client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &other,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &other_reward_account,
+            &reward_mint,
+            None,
+            false,
+        )
+        .is_err());
+
+    // unstaking pays what accrued since the claim
+    sleep(Duration::from_secs(2));
+    let claimed_balance = client.get_token_balance(&reward_token_account);
+    client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            None,
+            false,
+        )
+        .unwrap();
+    assert!(client.get_token_balance(&reward_token_account) > claimed_balance);
+    assert_eq!(
+        client
+            .get_settings(&authority.pubkey(), collection_id)
+            .staking
+            .unwrap()
+            .staked_count,
+        0
+    );
+
+    let stake_record = Pda::stake_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    assert_eq!(client.get_token_balance(&token_account.pubkey()), 1);
+    assert!(!client.account_exists(&stake_account));
+    assert!(!client.account_exists(&stake_record));
+
+    // staked again from scratch
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+}
+
+#[test]
+fn claim_rewards_from_vault() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+    let reward_rate = 5;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+
+    // an existing token, funded into a vault owned by the authority PDA
+    let reward_mint = client.create_mint(&authority.pubkey(), 0);
+    let reward_vault = client.create_token_account(&reward_mint, &authority_pda);
+    client.mint_tokens(&authority, &reward_mint, &reward_vault, 1_000_000);
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, reward_rate)
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+
+    sleep(Duration::from_secs(3));
+
+    let reward_token_account = client.create_token_account(&reward_mint, &authority.pubkey());
+
+    // the vault has to be owned by the authority PDA
+    let own_vault = client.create_token_account(&reward_mint, &authority.pubkey());
+    client.mint_tokens(&authority, &reward_mint, &own_vault, 1_000_000);
+    assert!(client
+        .claim_rewards(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            Some(&own_vault)
+        )
+        .is_err());
+
+    client
+        .claim_rewards(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            Some(&reward_vault),
+        )
+        .unwrap();
+
+    let claimed = client.get_stake_record(&mint.pubkey());
+    let amount = (claimed.claimed_at - claimed.staked_at) as u64 * reward_rate;
+    assert!(amount > 0);
+    assert_eq!(client.get_token_balance(&reward_token_account), amount);
+    assert_eq!(client.get_token_balance(&reward_vault), 1_000_000 - amount);
+}
+
+#[test]
+fn unstake_from_empty_vault() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+
+    // the vault is never funded
+    let reward_mint = client.create_mint(&authority.pubkey(), 0);
+    let reward_vault = client.create_token_account(&reward_mint, &authority_pda);
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, 5)
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+
+    sleep(Duration::from_secs(2));
+
+    let reward_token_account = client.create_token_account(&reward_mint, &authority.pubkey());
+    assert!(client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            Some(&reward_vault),
+            false,
+        )
+        .is_err());
+
+    // forfeiting the rewards still returns the NFT
+    client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            Some(&reward_vault),
+            true,
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&token_account.pubkey()), 1);
+    assert_eq!(client.get_token_balance(&reward_token_account), 0);
+    assert!(
+        !client.account_exists(&Pda::stake_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0)
+    );
+    assert_eq!(
+        client
+            .get_settings(&authority.pubkey(), collection_id)
+            .staking
+            .unwrap()
+            .staked_count,
+        0
+    );
+}
+
+#[test]
+fn staking_changes_are_not_retroactive() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+    let reward_rate = 1_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+    let reward_mint = client.create_mint(&authority_pda, 0);
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, reward_rate)
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+
+    // the reward mint is fixed while NFTs are staked
+    let other_mint = client.create_mint(&authority_pda, 0);
+    assert!(client
+        .configure_staking(&authority, collection_id, &other_mint, reward_rate)
+        .is_err());
+
+    sleep(Duration::from_secs(3));
+
+    // stopping the rewards keeps what accrued at the old rate
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, 0)
+        .unwrap();
+    let staking = client
+        .get_settings(&authority.pubkey(), collection_id)
+        .staking
+        .unwrap();
+    let stake_record = client.get_stake_record(&mint.pubkey());
+    let accrued = staking.reward_per_nft - stake_record.reward_checkpoint;
+    assert!(accrued > 0);
+
+    sleep(Duration::from_secs(2));
+
+    let reward_token_account = client.create_token_account(&reward_mint, &authority.pubkey());
+    client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            None,
+            false,
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&reward_token_account), accrued);
+
+    // with nothing staked the reward mint can change
+    client
+        .configure_staking(&authority, collection_id, &other_mint, reward_rate)
+        .unwrap();
+}
+
+#[test]
+fn membership_badges_are_not_staked() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+
+    let args = InitializeMeepArgs {
+        collection_id,
+        settings: get_random_settings(),
+        pda_authority: false,
+        royalty_vault: false,
+        payment_mint: None,
+        membership: true,
+    };
+    client
+        .initialize_meep_with_args(&authority, &secondary_creator, &args)
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let reward_mint = client.create_mint(&authority.pubkey(), 0);
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, 1)
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    assert!(client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey()
+        )
+        .is_err());
+}
+
+#[test]
+fn stake_prefunded_accounts() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &get_random_settings(),
+        )
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let authority_pda = Pda::authority_pubkey_with_bump(&meep::ID, &settings).0;
+    let reward_mint = client.create_mint(&authority_pda, 6);
+    client
+        .configure_staking(&authority, collection_id, &reward_mint, 1)
+        .unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &token_account,
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    // lamports sent to the stake accounts of the mint don't block staking it
+    let stake_record = Pda::stake_record_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    let stake_account = Pda::stake_account_pubkey_with_bump(&meep::ID, &mint.pubkey()).0;
+    client.transfer(&authority, &stake_record, 1);
+    client.transfer(&authority, &stake_account, 10_000_000);
+
+    client
+        .stake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&stake_account), 1);
+    assert_eq!(
+        client.get_stake_record(&mint.pubkey()).staker,
+        authority.pubkey()
+    );
+
+    let reward_token_account = client.create_token_account(&reward_mint, &authority.pubkey());
+    client
+        .unstake(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &mint.pubkey(),
+            &token_account.pubkey(),
+            &reward_token_account,
+            &reward_mint,
+            None,
+            false,
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&token_account.pubkey()), 1);
+}
@@ -0,0 +1,97 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::utils::Pda;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn treasury() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+
+    let mut args = get_random_settings();
+    args.treasury_mint_fee = 1_000_000;
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(&authority, collection_id, &secondary_creator, &args)
+        .unwrap();
+
+    let settings = Pda::settings_pubkey_with_bump(&meep::ID, &authority.pubkey(), collection_id).0;
+    let treasury = Pda::treasury_pubkey_with_bump(&meep::ID, &settings).0;
+
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &Keypair::new(),
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+    assert_eq!(client.get_balance(&treasury), args.treasury_mint_fee);
+
+    // anyone can deposit lamports
+    let depositor = Keypair::new();
+    client.airdrop(&depositor, lamports);
+    client
+        .deposit(
+            &authority.pubkey(),
+            collection_id,
+            &depositor,
+            None,
+            5_000_000,
+        )
+        .unwrap();
+    assert_eq!(
+        client.get_balance(&treasury),
+        args.treasury_mint_fee + 5_000_000
+    );
+
+    // and tokens into token accounts owned by the treasury
+    let mint_authority = Keypair::new();
+    let mint = client.create_mint(&mint_authority.pubkey(), 6);
+    let source = client.create_token_account(&mint, &depositor.pubkey());
+    let treasury_token_account = client.create_token_account(&mint, &treasury);
+    let not_treasury_token_account = client.create_token_account(&mint, &depositor.pubkey());
+    client.mint_tokens(&mint_authority, &mint, &source, 100);
+
+    assert!(client
+        .deposit(
+            &authority.pubkey(),
+            collection_id,
+            &depositor,
+            Some((&source, &not_treasury_token_account)),
+            40
+        )
+        .is_err());
+
+    client
+        .deposit(
+            &authority.pubkey(),
+            collection_id,
+            &depositor,
+            Some((&source, &treasury_token_account)),
+            40,
+        )
+        .unwrap();
+    assert_eq!(client.get_token_balance(&treasury_token_account), 40);
+    assert_eq!(client.get_token_balance(&source), 60);
+
+    // withdrawals need the governance PDA signature
+    assert!(client
+        .withdraw(
+            &authority.pubkey(),
+            collection_id,
+            &authority,
+            &authority.pubkey(),
+            1
+        )
+        .is_err());
+}
@@ -0,0 +1,128 @@
+use common::{get_random_mint_args, get_random_settings, rpc_client::MeepRpcClient};
+use meep::{instruction::UpdateNftMetadataArgs, utils::Pda};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[allow(dead_code)]
+mod common;
+
+#[test]
+fn update_metadata() {
+    let client = MeepRpcClient::new();
+    let lamports = 1_000_000_000;
+
+    let authority = Keypair::new();
+    let collection_id = 0;
+    let secondary_creator = Keypair::new();
+    let initialize_args = get_random_settings();
+
+    client.airdrop(&authority, lamports);
+    client
+        .initialize_meep(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &initialize_args,
+        )
+        .unwrap();
+
+    let mint = Keypair::new();
+    let mut mint_args = get_random_mint_args();
+    mint_args.is_mutable = true;
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &mint,
+            &Keypair::new(),
+            &mint_args,
+        )
+        .unwrap();
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.is_mutable, true);
+
+    let args = UpdateNftMetadataArgs {
+        token_name: Some("Fixed_name".to_string()),
+        uri: Some("https://test.com/fixed".to_string()),
+        seller_fee_basis_points: Some(250),
+        lock: false,
+    };
+
+    // metadata of another NFT is rejected without deriving the address
+    let other_mint = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &other_mint,
+            &Keypair::new(),
+            &mint_args,
+        )
+        .unwrap();
+    assert!(client
+        .update_nft_metadata_account(
+            &authority,
+            collection_id,
+            &mint.pubkey(),
+            &Pda::metadata_pubkey(&other_mint.pubkey()),
+            &args
+        )
+        .is_err());
+
+    // only the authority can update
+    assert!(client
+        .update_nft_metadata(&secondary_creator, collection_id, &mint.pubkey(), &args)
+        .is_err());
+
+    client
+        .update_nft_metadata(&authority, collection_id, &mint.pubkey(), &args)
+        .unwrap();
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.update_authority, authority.pubkey());
+    assert_eq!(metadata.data.name, "Fixed_name");
+    assert_eq!(metadata.data.symbol, mint_args.token_symbol);
+    assert_eq!(metadata.data.uri, "https://test.com/fixed");
+    assert_eq!(metadata.data.seller_fee_basis_points, 250);
+
+    let lock = UpdateNftMetadataArgs {
+        token_name: None,
+        uri: None,
+        seller_fee_basis_points: None,
+        lock: true,
+    };
+    client
+        .update_nft_metadata(&authority, collection_id, &mint.pubkey(), &lock)
+        .unwrap();
+
+    let metadata = client.get_metadata(&mint.pubkey());
+    assert_eq!(metadata.data.name, "Fixed_name");
+    assert_eq!(
+        metadata.update_authority,
+        Pda::locked_update_authority_with_bump(&meep::ID).0
+    );
+
+    // locked for good
+    assert!(client
+        .update_nft_metadata(&authority, collection_id, &mint.pubkey(), &args)
+        .is_err());
+
+    // immutable metadata
+    let immutable_mint = Keypair::new();
+    client
+        .mint_nft(
+            &authority,
+            collection_id,
+            &secondary_creator,
+            &immutable_mint,
+            &Keypair::new(),
+            &get_random_mint_args(),
+        )
+        .unwrap();
+
+    assert!(client
+        .update_nft_metadata(&authority, collection_id, &immutable_mint.pubkey(), &args)
+        .is_err());
+}
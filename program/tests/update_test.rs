@@ -10,23 +10,28 @@ fn update_test() {
 
     for _ in 0..10 {
         let authority = Keypair::new();
+        let collection_id = 0;
         let secondary_creator = Keypair::new();
         let settings = get_random_settings();
 
         let lamports = 1_000_000_000;
         client.airdrop(&authority, lamports);
 
-        assert!(client.update_settings(&authority, &settings).is_err());
+        assert!(client
+            .update_settings(&authority, collection_id, &settings)
+            .is_err());
 
         client
-            .initialize_meep(&authority, &secondary_creator, &settings)
+            .initialize_meep(&authority, collection_id, &secondary_creator, &settings)
             .unwrap();
 
         let new_settings = get_random_settings();
 
-        client.update_settings(&authority, &new_settings).unwrap();
+        client
+            .update_settings(&authority, collection_id, &new_settings)
+            .unwrap();
 
-        let on_chain_settings = client.get_settings(&authority.pubkey());
+        let on_chain_settings = client.get_settings(&authority.pubkey(), collection_id);
 
         assert_eq!(on_chain_settings.authority, authority.pubkey());
         assert_eq!(
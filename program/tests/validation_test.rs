@@ -0,0 +1,44 @@
+use meep::{error::MeepError, instruction::MintNftArgs};
+use metaplex_token_metadata::state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
+fn mint_args() -> MintNftArgs {
+    MintNftArgs {
+        seller_fee_basis_points: 500,
+        token_name: "Token_name".to_string(),
+        token_symbol: "TS".to_string(),
+        uri: "https://test.com/1".to_string(),
+        is_mutable: false,
+        mint_record_bump: 0,
+    }
+}
+
+#[test]
+fn mint_args_validation() {
+    assert!(mint_args().validate().is_ok());
+
+    let limits = MintNftArgs {
+        seller_fee_basis_points: 10000,
+        token_name: "n".repeat(MAX_NAME_LENGTH),
+        token_symbol: "s".repeat(MAX_SYMBOL_LENGTH),
+        uri: "u".repeat(MAX_URI_LENGTH),
+        is_mutable: true,
+        mint_record_bump: 0,
+    };
+    assert!(limits.validate().is_ok());
+
+    let mut args = mint_args();
+    args.token_name = "n".repeat(MAX_NAME_LENGTH + 1);
+    assert!(matches!(args.validate(), Err(MeepError::NameTooLong)));
+
+    let mut args = mint_args();
+    args.token_symbol = "s".repeat(MAX_SYMBOL_LENGTH + 1);
+    assert!(matches!(args.validate(), Err(MeepError::SymbolTooLong)));
+
+    let mut args = mint_args();
+    args.uri = "u".repeat(MAX_URI_LENGTH + 1);
+    assert!(matches!(args.validate(), Err(MeepError::UriTooLong)));
+
+    let mut args = mint_args();
+    args.seller_fee_basis_points = 10001;
+    assert!(matches!(args.validate(), Err(MeepError::InvalidSellerFee)));
+}
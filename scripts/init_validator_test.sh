@@ -3,8 +3,9 @@
 cargo build-bpf
 
 BASEDIR=$(dirname "$0")
+TOKEN_METADATA_SO=${TOKEN_METADATA_SO:-"$BASEDIR/../../metaplex/rust/target/deploy/metaplex_token_metadata.so"}
 
 solana-test-validator --reset \
     --bpf-program 5Hu2bnTxd1mPXNHqMzFfB5SUFEvYW7GG3nPSQ1VWvTK "$BASEDIR/../target/deploy/meep.so" \
-    --bpf-program metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s "$BASEDIR/../../metaplex/rust/target/deploy/metaplex_token_metadata.so" \
+    --bpf-program metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s "$TOKEN_METADATA_SO" \
     --ledger /tmp/test-ledger